@@ -63,6 +63,16 @@ lazy_static! {
 // require a max string length at time of implementation.
 pub const DEFAULT_MAX_STRING_LENGTH: u16 = 4000;
 
+// The number of rows a static cursor keeps in memory before writing
+// the rest of the result set to a temporary file.
+pub const DEFAULT_STATIC_CURSOR_SPILL_THRESHOLD: usize = 10000;
+
+// The prefix of the cursor names generated by the driver. Applications cannot
+// assign cursor names starting with it.
+pub const GENERATED_CURSOR_NAME_PREFIX: &str = "SQL_CUR";
+// The maximum length of a cursor name, reported as SQL_MAX_CURSOR_NAME_LEN.
+pub const MAX_CURSOR_NAME_LEN: u16 = 128;
+
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct OdbcState<'a> {
     pub odbc_2_state: &'a str,
@@ -105,6 +115,10 @@ pub const INVALID_DRIVER_COMPLETION: OdbcState<'static> = OdbcState {
     odbc_2_state: "S1110",
     odbc_3_state: "HY110",
 };
+pub const DATA_SOURCE_NOT_FOUND: OdbcState<'static> = OdbcState {
+    odbc_2_state: "IM002",
+    odbc_3_state: "IM002",
+};
 pub const NO_DSN_OR_DRIVER: OdbcState<'static> = OdbcState {
     odbc_2_state: "IM007",
     odbc_3_state: "IM007",
@@ -138,6 +152,10 @@ pub const INVALID_DESCRIPTOR_INDEX: OdbcState<'static> = OdbcState {
 // S1093 is a DM error for SQLBindParameter, but not always for SQLDescribeParam.
 // If we implement the latter, we will need to add that error here.
 
+pub const COUNT_FIELD_INCORRECT: OdbcState<'static> = OdbcState {
+    odbc_2_state: "07001",
+    odbc_3_state: "07002",
+};
 pub const INVALID_COLUMN_NUMBER: OdbcState<'static> = OdbcState {
     odbc_2_state: "07009",
     odbc_3_state: "07009",
@@ -154,10 +172,30 @@ pub const INVALID_CURSOR_STATE: OdbcState<'static> = OdbcState {
     odbc_2_state: "24000",
     odbc_3_state: "24000",
 };
+pub const INVALID_CURSOR_NAME: OdbcState<'static> = OdbcState {
+    odbc_2_state: "34000",
+    odbc_3_state: "34000",
+};
+pub const DUPLICATE_CURSOR_NAME: OdbcState<'static> = OdbcState {
+    odbc_2_state: "3C000",
+    odbc_3_state: "3C000",
+};
+pub const INVALID_RETRIEVAL_CODE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1103",
+    odbc_3_state: "HY103",
+};
 pub const FUNCTION_SEQUENCE_ERROR: OdbcState<'static> = OdbcState {
     odbc_2_state: "S1010",
     odbc_3_state: "HY010",
 };
+pub const NON_CHARACTER_DATA_IN_PIECES: OdbcState<'static> = OdbcState {
+    odbc_2_state: "22003",
+    odbc_3_state: "HY019",
+};
+pub const INVALID_STRING_OR_BUFFER_LENGTH: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1090",
+    odbc_3_state: "HY090",
+};
 pub const INVALID_FIELD_DESCRIPTOR: OdbcState<'static> = OdbcState {
     odbc_2_state: "S1091",
     odbc_3_state: "HY091",
@@ -166,6 +204,10 @@ pub const INVALID_ATTRIBUTE_OR_OPTION_IDENTIFIER: OdbcState<'static> = OdbcState
     odbc_2_state: "S1092",
     odbc_3_state: "HY092",
 };
+pub const INVALID_PARAMETER_TYPE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1105",
+    odbc_3_state: "HY105",
+};
 pub const FETCH_TYPE_OUT_OF_RANGE: OdbcState<'static> = OdbcState {
     odbc_2_state: "S1106",
     odbc_3_state: "HY106",
@@ -190,11 +232,76 @@ pub const CONNECTION_NOT_OPEN: OdbcState<'static> = OdbcState {
     odbc_2_state: "08003",
     odbc_3_state: "08003",
 };
+pub const FETCH_BEFORE_START: OdbcState<'static> = OdbcState {
+    odbc_2_state: "01S06",
+    odbc_3_state: "01S06",
+};
+pub const INVALID_BOOKMARK_VALUE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1111",
+    odbc_3_state: "HY111",
+};
+pub const ASSOCIATED_STATEMENT_NOT_PREPARED: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1010",
+    odbc_3_state: "HY007",
+};
+pub const CANNOT_MODIFY_IRD: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1000",
+    odbc_3_state: "HY016",
+};
+pub const INVALID_USE_OF_AUTOMATIC_DESCRIPTOR: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1000",
+    odbc_3_state: "HY017",
+};
+pub const SYNTAX_ERROR: OdbcState<'static> = OdbcState {
+    odbc_2_state: "37000",
+    odbc_3_state: "42000",
+};
+pub const COLUMN_TYPE_OUT_OF_RANGE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1097",
+    odbc_3_state: "HY097",
+};
+pub const SCOPE_TYPE_OUT_OF_RANGE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1098",
+    odbc_3_state: "HY098",
+};
+pub const NULLABLE_TYPE_OUT_OF_RANGE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1099",
+    odbc_3_state: "HY099",
+};
+pub const UNIQUENESS_OPTION_OUT_OF_RANGE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1100",
+    odbc_3_state: "HY100",
+};
+pub const ACCURACY_OPTION_OUT_OF_RANGE: OdbcState<'static> = OdbcState {
+    odbc_2_state: "S1101",
+    odbc_3_state: "HY101",
+};
 
 pub const SQL_ALL_TABLE_TYPES: &str = "%";
 pub const SQL_ALL_CATALOGS: &str = "%";
 pub const SQL_ALL_SCHEMAS: &str = "%";
 
+// SQLStatistics options and statistics types
+pub const SQL_INDEX_UNIQUE: i16 = 0;
+pub const SQL_INDEX_ALL: i16 = 1;
+pub const SQL_QUICK: i16 = 0;
+pub const SQL_ENSURE: i16 = 1;
+pub const SQL_TABLE_STAT: i16 = 0;
+pub const SQL_INDEX_HASHED: i16 = 2;
+pub const SQL_INDEX_OTHER: i16 = 3;
+
+// SQLSpecialColumns identifier types, scopes and pseudo column types
+pub const SQL_BEST_ROWID: i16 = 1;
+pub const SQL_ROWVER: i16 = 2;
+pub const SQL_SCOPE_CURROW: i16 = 0;
+pub const SQL_SCOPE_TRANSACTION: i16 = 1;
+pub const SQL_SCOPE_SESSION: i16 = 2;
+pub const SQL_PC_NOT_PSEUDO: i16 = 1;
+
+// SQLForeignKeys rules and deferrability
+pub const SQL_NO_ACTION: i16 = 3;
+pub const SQL_NOT_DEFERRABLE: i16 = 7;
+
 pub const SQL_CB_NULL: u16 = 0x0000;
 pub const MAX_COLUMNS_U16_ZERO: u16 = 0x0000;
 pub const SQL_CL_START: u16 = 0x0001;
@@ -205,17 +312,32 @@ pub const COLUMN_ALIAS_INFO_Y: &str = "Y";
 pub const SQL_GB_GROUP_BY_CONTAINS_SELECT: u16 = 0x0002;
 pub const SQL_CB_PRESERVE: u16 = 2;
 pub const SQL_CA1_NEXT: u32 = 0x00000001;
+pub const SQL_CA1_ABSOLUTE: u32 = 0x00000002;
+pub const SQL_CA1_RELATIVE: u32 = 0x00000004;
+pub const SQL_CA1_BOOKMARK: u32 = 0x00000008;
+pub const MONGO_STATIC_CA1_SUPPORT: u32 =
+    SQL_CA1_NEXT | SQL_CA1_ABSOLUTE | SQL_CA1_RELATIVE | SQL_CA1_BOOKMARK;
+pub const SQL_BP_SCROLL: u32 = 0x00000040;
 pub const SQL_CA2_READ_ONLY_CONCURRENCY: u32 = 0x00000001;
-#[allow(unused)]
 pub const SQL_CA2_MAX_ROWS_SELECT: u32 = 0x00000080;
+pub const SQL_CA2_MAX_ROWS_CATALOG: u32 = 0x00000800;
 pub const SQL_CA2_CRC_EXACT: u32 = 0x00001000;
-pub const MONGO_CA2_SUPPORT: u32 = SQL_CA2_CRC_EXACT | SQL_CA2_READ_ONLY_CONCURRENCY;
+pub const MONGO_CA2_SUPPORT: u32 = SQL_CA2_CRC_EXACT
+    | SQL_CA2_READ_ONLY_CONCURRENCY
+    | SQL_CA2_MAX_ROWS_SELECT
+    | SQL_CA2_MAX_ROWS_CATALOG;
+// A static cursor only knows its row count once every row is buffered, so it is not exact.
+pub const MONGO_STATIC_CA2_SUPPORT: u32 =
+    SQL_CA2_READ_ONLY_CONCURRENCY | SQL_CA2_MAX_ROWS_SELECT | SQL_CA2_MAX_ROWS_CATALOG;
 pub const SQL_SO_FORWARD_ONLY: u32 = 0x00000001;
 pub const SQL_SO_STATIC: u32 = 0x00000010;
 pub const MONGO_SO_SUPPORT: u32 = SQL_SO_FORWARD_ONLY | SQL_SO_STATIC;
 pub const SQL_TXN_SERIALIZABLE: u32 = 0x00000008;
 pub const SQL_SCCO_READ_ONLY: u32 = 0x00000001;
 pub const SQL_LCK_NO_CHANGE: u32 = 0x00000001;
+pub const SQL_PARC_NO_BATCH: u32 = 2;
+pub const SQL_PAS_BATCH: u32 = 1;
+pub const SQL_AM_STATEMENT: u32 = 2;
 
 // SQL_CONVERT_FUNCTIONS bitmask
 pub const SQL_FN_CVT_CAST: u32 = 0x00000002;
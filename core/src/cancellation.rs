@@ -0,0 +1,76 @@
+use crate::err::{Error, Result};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use tokio::sync::Notify;
+
+// Cancellation is shared between a statement and the MongoStatement it executes, so that
+// SQLCancel can interrupt the statement while it runs its aggregation or reads its result set.
+// Clones refer to the same cancellation state.
+#[derive(Debug, Default, Clone)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl Cancellation {
+    // Cancel the statement, waking up any operation waiting on the server.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    // Clear a previous cancellation, before the statement starts a new operation.
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    // Return Error::QueryCancelled if the statement was cancelled.
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::QueryCancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Complete once the statement is cancelled.
+    pub(crate) async fn cancelled(&self) {
+        loop {
+            // The Notified future receives the notifications sent after it is created, so a
+            // cancellation between the check and the await is not missed.
+            let notified = self.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::Cancellation;
+    use crate::Error;
+
+    #[test]
+    fn cancel_and_reset() {
+        let cancellation = Cancellation::default();
+        assert!(cancellation.check().is_ok());
+
+        cancellation.clone().cancel();
+        assert!(matches!(cancellation.check(), Err(Error::QueryCancelled)));
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(cancellation.cancelled());
+
+        cancellation.reset();
+        assert!(!cancellation.is_cancelled());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    foreign_keys::{deserialize_foreign_keys, ForeignKey},
     json_schema::{
         simplified::{Atomic, ObjectSchema, Schema},
         BsonTypeName,
@@ -159,6 +160,12 @@ pub struct SqlGetSchemaResponse {
     pub schema: VersionedJsonSchema,
     #[serde(rename = "selectOrder")]
     pub select_order: Option<Vec<Vec<String>>>,
+    #[serde(
+        rename = "foreignKeys",
+        default,
+        deserialize_with = "deserialize_foreign_keys"
+    )]
+    pub foreign_keys: Vec<ForeignKey>,
 }
 
 // Auxiliary struct representing part of the response for a sqlGetResultSchema
@@ -176,6 +183,15 @@ pub struct ResultSetSchema {
     pub schema: crate::json_schema::Schema,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub select_order: Option<Vec<Vec<String>>>,
+    // The foreign keys declared next to the schema of a collection, in its __sql_schemas
+    // document or its sqlGetSchema response.
+    #[serde(
+        rename = "foreignKeys",
+        default,
+        deserialize_with = "deserialize_foreign_keys",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub foreign_keys: Vec<ForeignKey>,
 }
 
 impl ResultSetSchema {
@@ -192,6 +208,7 @@ impl From<SqlGetSchemaResponse> for ResultSetSchema {
         Self {
             schema: sql_get_schema_response.schema.json_schema,
             select_order: sql_get_schema_response.select_order,
+            foreign_keys: sql_get_schema_response.foreign_keys,
         }
     }
 }
@@ -427,6 +444,7 @@ mod unit {
         #[test]
         fn top_level_schema_not_object() {
             let input = ResultSetSchema {
+                foreign_keys: vec![],
                 schema: Schema {
                     bson_type: Some(BsonType::Single(BsonTypeName::Int)),
                     ..Default::default()
@@ -446,6 +464,7 @@ mod unit {
         #[test]
         fn null_columns_are_sql_unknown_without_simple_types() {
             let input = ResultSetSchema {
+                foreign_keys: vec![],
                 schema: Schema {
                     bson_type: Some(BsonType::Single(BsonTypeName::Object)),
                     properties: Some(map! {
@@ -477,6 +496,7 @@ mod unit {
         #[test]
         fn null_columns_are_sql_wvarchar_with_simple_types() {
             let input = ResultSetSchema {
+                foreign_keys: vec![],
                 schema: Schema {
                     bson_type: Some(BsonType::Single(BsonTypeName::Object)),
                     properties: Some(map! {
@@ -508,6 +528,7 @@ mod unit {
         #[test]
         fn property_schema_not_object() {
             let input = ResultSetSchema {
+                foreign_keys: vec![],
                 schema: Schema {
                     bson_type: Some(BsonType::Single(BsonTypeName::Object)),
                     properties: Some(map! {
@@ -533,6 +554,7 @@ mod unit {
         #[test]
         fn fields_sorted_alphabetical_no_select_order() {
             let input = ResultSetSchema {
+                foreign_keys: vec![],
                 schema: Schema {
                     bson_type: Some(BsonType::Single(BsonTypeName::Object)),
                     properties: Some(map! {
@@ -590,6 +612,7 @@ mod unit {
         #[test]
         fn fields_sorted_out_of_order_select_order() {
             let input = ResultSetSchema {
+                foreign_keys: vec![],
                 schema: Schema {
                     bson_type: Some(BsonType::Single(BsonTypeName::Object)),
                     properties: Some(map! {
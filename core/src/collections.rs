@@ -97,6 +97,10 @@ pub struct MongoCollections {
     collections_for_db_list: Vec<CollectionsForDb>,
     collection_name_filter: Option<Regex>,
     table_types_filter: Option<Vec<CollectionType>>,
+    // The maximum number of collections to return, or 0 to return all of them.
+    max_rows: u64,
+    // The number of collections returned so far.
+    rows: u64,
 }
 
 // Statement related to a SQLColumns call.
@@ -105,6 +109,7 @@ impl MongoCollections {
     // (tables) names filters.
     // The query timeout comes from the statement attribute SQL_ATTR_QUERY_TIMEOUT. If there is a
     // timeout, the query must finish before the timeout or an error is returned.
+    // The maximum number of collections comes from the statement attribute SQL_ATTR_MAX_ROWS.
     pub fn list_tables(
         mongo_connection: &MongoConnection,
         _query_timeout: Option<i32>,
@@ -112,6 +117,7 @@ impl MongoCollections {
         collection_name_filter: &str,
         table_type: &str,
         accept_search_patterns: bool,
+        max_rows: u64,
     ) -> Self {
         let databases = mongo_connection.runtime.block_on(async {
             future::join_all(
@@ -158,6 +164,8 @@ impl MongoCollections {
             collections_for_db_list: databases,
             collection_name_filter: to_name_regex(collection_name_filter),
             table_types_filter: table_type_filter_to_vec(table_type),
+            max_rows,
+            rows: 0,
         }
     }
 
@@ -177,6 +185,8 @@ impl MongoCollections {
             collections_for_db_list: Vec::new(),
             table_types_filter: None,
             collection_name_filter: None,
+            max_rows: 0,
+            rows: 0,
         }
     }
 }
@@ -185,8 +195,12 @@ impl MongoStatement for MongoCollections {
     // Move the cursor to the next CollectionSpecification.
     // When cursor is exhausted move to next database in list
     // Return true if moving was successful, false otherwise.
+    // No more collections are returned once max_rows is reached, if max_rows is not 0.
     #[allow(clippy::blocks_in_conditions)]
     fn next(&mut self, _: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        if self.max_rows > 0 && self.rows >= self.max_rows {
+            return Ok((false, vec![]));
+        }
         if self.current_database_index.is_none() {
             if self.collections_for_db_list.is_empty() {
                 return Ok((false, vec![]));
@@ -231,6 +245,7 @@ impl MongoStatement for MongoCollections {
                             self.current_collection_index =
                                 self.current_collection_index.map(|val| val + 1);
                             self.current_collection = Some(collection.clone());
+                            self.rows += 1;
                             return Ok((true, vec![]));
                         } else {
                             // collection doens't match the filter, advance the collection index
@@ -430,6 +445,43 @@ mod unit {
         );
     }
 
+    #[test]
+    fn max_rows() {
+        use crate::{
+            collections::{CollectionsForDb, MongoCollections, MongoODBCCollectionSpecification},
+            stmt::MongoStatement,
+        };
+        use mongodb::results::CollectionType;
+
+        let mut collections = MongoCollections::empty();
+        collections.collections_for_db_list = vec![CollectionsForDb {
+            database_name: "db".to_string(),
+            collection_list: ["a", "b", "c"]
+                .iter()
+                .map(|name| {
+                    MongoODBCCollectionSpecification::new(
+                        name.to_string(),
+                        CollectionType::Collection,
+                    )
+                })
+                .collect(),
+        }];
+        collections.max_rows = 2;
+
+        assert!(collections.next(None).unwrap().0);
+        assert!(collections.next(None).unwrap().0);
+        assert!(!collections.next(None).unwrap().0);
+        assert_eq!(
+            "b",
+            collections
+                .current_collection
+                .as_ref()
+                .unwrap()
+                .name
+                .as_str()
+        );
+    }
+
     #[cfg(test)]
     mod table_type {
         use crate::util::table_type_filter_to_vec;
@@ -323,6 +323,24 @@ impl MongoConnection {
         })
     }
 
+    /// Lists the names of the databases the user is authorized to use, excluding admin.
+    pub fn list_database_names(&self) -> Result<Vec<String>> {
+        let database_names = self
+            .runtime
+            .block_on(async {
+                self.client
+                    .list_database_names()
+                    .authorized_databases(true)
+                    .await
+            })
+            .map_err(Error::QueryExecutionFailed)?;
+        // MHOUSE-7119 - admin database and empty strings are showing in list_database_names
+        Ok(database_names
+            .into_iter()
+            .filter(|db_name| !db_name.is_empty() && db_name != "admin")
+            .collect())
+    }
+
     /// cancels all queries for a given statement id
     pub fn cancel_queries_for_statement(&self, statement_id: Bson) -> Result<bool> {
         // because there are so many awaits in this function, the bulk of the function is wrapped in a block_on
@@ -205,12 +205,14 @@ impl MongoDatabases {
     // All columns except the TABLE_CAT column contain NULLs.
     // The query timeout comes from the statement attribute SQL_ATTR_QUERY_TIMEOUT. If there is a
     // timeout, the query must finish before the timeout or an error is returned.
+    // The maximum number of databases comes from the statement attribute SQL_ATTR_MAX_ROWS.
     pub fn list_all_catalogs(
         mongo_connection: &MongoConnection,
         _query_timeout: Option<i32>,
+        max_rows: u64,
     ) -> Self {
         let _guard = mongo_connection.runtime.enter();
-        let database_names: Vec<String> = mongo_connection
+        let mut database_names: Vec<String> = mongo_connection
             .runtime
             .block_on(async {
                 mongo_connection
@@ -224,6 +226,9 @@ impl MongoDatabases {
             .filter(|&db_name| !db_name.is_empty() && !db_name.eq("admin"))
             .map(|s| s.to_string())
             .collect();
+        if max_rows > 0 {
+            database_names.truncate(usize::try_from(max_rows).unwrap_or(usize::MAX));
+        }
 
         MongoDatabases {
             database_names,
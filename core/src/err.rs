@@ -1,7 +1,7 @@
 use constants::{
-    OdbcState, FUNCTION_SEQUENCE_ERROR, GENERAL_ERROR, INVALID_CURSOR_STATE,
-    INVALID_DESCRIPTOR_INDEX, NO_DSN_OR_DRIVER, OPERATION_CANCELLED, TIMEOUT_EXPIRED,
-    UNABLE_TO_CONNECT,
+    OdbcState, COUNT_FIELD_INCORRECT, FUNCTION_SEQUENCE_ERROR, GENERAL_ERROR, INVALID_CURSOR_STATE,
+    INVALID_DESCRIPTOR_INDEX, NO_DSN_OR_DRIVER, OPERATION_CANCELLED, RESTRICTED_DATATYPE,
+    SYNTAX_ERROR, TIMEOUT_EXPIRED, UNABLE_TO_CONNECT,
 };
 use mongodb::error::{ErrorKind, WriteFailure};
 use thiserror::Error;
@@ -90,6 +90,14 @@ pub enum Error {
     MultipleSchemaDocumentsReturned(usize),
     #[error("The buildInfo command failed with the following error: `{0}`")]
     BuildInfoCmdExecutionFailed(mongodb::error::Error),
+    #[error("The query has {0} parameter marker(s), but {1} parameter value(s) were supplied")]
+    ParameterCountMismatch(usize, usize),
+    #[error("Parameter values of type {0} cannot be used in a query")]
+    UnsupportedParameterType(String),
+    #[error("Buffering static cursor rows on disk failed with error: {0}")]
+    StaticCursorSpill(String),
+    #[error("Invalid escape sequence: {0}")]
+    InvalidEscapeSequence(String),
 }
 
 impl Error {
@@ -134,9 +142,13 @@ impl Error {
             | Error::BsonDocumentToCommandResponseDeserialization(_)
             | Error::NoSchemaInformationReturned
             | Error::MultipleSchemaDocumentsReturned(_)
-            | Error::BuildInfoCmdExecutionFailed(_) => GENERAL_ERROR,
+            | Error::BuildInfoCmdExecutionFailed(_)
+            | Error::StaticCursorSpill(_) => GENERAL_ERROR,
             Error::StatementNotExecuted => FUNCTION_SEQUENCE_ERROR,
             Error::QueryCancelled => OPERATION_CANCELLED,
+            Error::ParameterCountMismatch(_, _) => COUNT_FIELD_INCORRECT,
+            Error::UnsupportedParameterType(_) => RESTRICTED_DATATYPE,
+            Error::InvalidEscapeSequence(_) => SYNTAX_ERROR,
         }
     }
 
@@ -188,7 +200,11 @@ impl Error {
             | Error::BsonDocumentToCommandResponseDeserialization(_)
             | Error::NoSchemaInformationReturned
             | Error::MultipleSchemaDocumentsReturned(_)
-            | Error::BuildInfoCmdExecutionFailed(_) => 0,
+            | Error::BuildInfoCmdExecutionFailed(_)
+            | Error::ParameterCountMismatch(_, _)
+            | Error::UnsupportedParameterType(_)
+            | Error::StaticCursorSpill(_)
+            | Error::InvalidEscapeSequence(_) => 0,
         }
     }
 }
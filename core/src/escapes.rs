@@ -0,0 +1,318 @@
+use crate::{err::Result, Error};
+
+// The ODBC escape sequences the driver rewrites, see
+// https://learn.microsoft.com/en-us/sql/odbc/reference/develop-app/escape-sequences-in-odbc.
+// A `{` that does not start one of them, like the `{` of a MongoSQL document
+// literal, is copied as is.
+const ESCAPE_KEYWORDS: &[&str] = &[
+    "fn", "d", "t", "ts", "oj", "escape", "call", "interval", "guid",
+];
+
+// Scanner walks the characters of a query, copying the text that is not part
+// of an escape sequence. Literals, quoted identifiers and comments are copied
+// without looking for escape sequences in them.
+struct Scanner {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Scanner {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    // Copies the rest of the current literal, quoted identifier, or comment
+    // to `out`, including its closing delimiter.
+    fn copy_until(&mut self, out: &mut String, end: &str, doubled_escapes: bool) {
+        let end: Vec<char> = end.chars().collect();
+        while self.pos < self.chars.len() {
+            if self.chars[self.pos..].starts_with(&end) {
+                // A doubled quote is an escaped quote and keeps us in the literal.
+                if doubled_escapes && self.peek_at(1) == Some(end[0]) {
+                    out.extend(&end);
+                    out.extend(&end);
+                    self.pos += 2;
+                    continue;
+                }
+                out.extend(&end);
+                self.pos += end.len();
+                return;
+            }
+            out.push(self.chars[self.pos]);
+            self.pos += 1;
+        }
+    }
+
+    // Returns the escape keyword starting at the current position, if any.
+    fn escape_keyword(&self) -> Option<String> {
+        let mut offset = 0;
+        while self.peek_at(offset).is_some_and(char::is_whitespace) {
+            offset += 1;
+        }
+        if self.peek_at(offset) == Some('?') {
+            return Some("?".to_string());
+        }
+        let keyword: String = self.chars[self.pos + offset..]
+            .iter()
+            .take_while(|c| c.is_ascii_alphabetic())
+            .collect::<String>()
+            .to_lowercase();
+        let next = self.peek_at(offset + keyword.len());
+        (ESCAPE_KEYWORDS.contains(&keyword.as_str())
+            && next.is_some_and(|c| c.is_whitespace() || c == '\'' || c == '}'))
+        .then_some(keyword)
+    }
+
+    // Rewrites the text up to the end of the query or, in an escape sequence,
+    // up to its closing `}`.
+    fn rewrite(&mut self, in_escape: bool) -> Result<String> {
+        let mut out = String::new();
+        // The number of open `{` that are not escape sequences.
+        let mut depth = 0;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            match c {
+                '\'' | '"' | '`' => {
+                    out.push(c);
+                    self.copy_until(&mut out, &c.to_string(), true);
+                }
+                '-' if self.peek() == Some('-') => {
+                    out.push_str("--");
+                    self.pos += 1;
+                    self.copy_until(&mut out, "\n", false);
+                }
+                '/' if self.peek() == Some('*') => {
+                    out.push_str("/*");
+                    self.pos += 1;
+                    self.copy_until(&mut out, "*/", false);
+                }
+                '{' => match self.escape_keyword() {
+                    Some(keyword) => out.push_str(&self.rewrite_escape(&keyword)?),
+                    None => {
+                        depth += 1;
+                        out.push(c);
+                    }
+                },
+                '}' if depth > 0 => {
+                    depth -= 1;
+                    out.push(c);
+                }
+                '}' if in_escape => return Ok(out),
+                _ => out.push(c),
+            }
+        }
+        if in_escape {
+            return Err(Error::InvalidEscapeSequence(
+                "missing closing '}'".to_string(),
+            ));
+        }
+        Ok(out)
+    }
+
+    // Rewrites the escape sequence after its opening `{`.
+    fn rewrite_escape(&mut self, keyword: &str) -> Result<String> {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.pos += keyword.len();
+        let body = self.rewrite(true)?;
+        let body = body.trim();
+        match keyword {
+            "fn" => rewrite_scalar_function(body),
+            "d" => Ok(timestamp_literal(&format!(
+                "{}T00:00:00Z",
+                string_literal(keyword, body)?
+            ))),
+            "ts" => Ok(timestamp_literal(&format!(
+                "{}Z",
+                string_literal(keyword, body)?.replacen(' ', "T", 1)
+            ))),
+            "oj" => Ok(body.to_string()),
+            "escape" => Ok(format!("ESCAPE {body}")),
+            _ => Err(Error::InvalidEscapeSequence(format!(
+                "{{{keyword}}} escape sequences are not supported"
+            ))),
+        }
+    }
+}
+
+// Returns the value of the string literal of a date or timestamp escape.
+fn string_literal<'a>(keyword: &str, body: &'a str) -> Result<&'a str> {
+    body.strip_prefix('\'')
+        .and_then(|body| body.strip_suffix('\''))
+        .filter(|value| !value.contains('\''))
+        .ok_or_else(|| {
+            Error::InvalidEscapeSequence(format!("{{{keyword} {body}}} is not a valid literal"))
+        })
+}
+
+// MongoSQL has no date type, so dates are timestamps at midnight UTC.
+fn timestamp_literal(value: &str) -> String {
+    format!("CAST('{value}' AS TIMESTAMP)")
+}
+
+// Splits the arguments of a function call on the commas that are not nested
+// in parentheses, literals, or quoted identifiers.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut split = vec![];
+    let mut depth = 0;
+    let mut quote = None;
+    let mut last = 0;
+    for (offset, c) in arguments.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                split.push(arguments[last..offset].trim());
+                last = offset + 1;
+            }
+            _ => {}
+        }
+    }
+    let argument = arguments[last..].trim();
+    if !argument.is_empty() || !split.is_empty() {
+        split.push(argument);
+    }
+    split
+}
+
+// Rewrites the body of a {fn ...} escape. ODBC scalar functions without a
+// MongoSQL counterpart of a different name are kept as they are.
+fn rewrite_scalar_function(body: &str) -> Result<String> {
+    let invalid = || Error::InvalidEscapeSequence(format!("{{fn {body}}} is not a function call"));
+    let open = body.find('(').ok_or_else(invalid)?;
+    let arguments = body[open + 1..]
+        .trim_end()
+        .strip_suffix(')')
+        .ok_or_else(invalid)?;
+    let name = body[..open].trim();
+    let args = split_arguments(arguments);
+    let extract = |unit: &str| format!("EXTRACT({unit} FROM {arguments})");
+    // The interval of TIMESTAMPADD and TIMESTAMPDIFF is a SQL_TSI_ keyword.
+    let date_function = |function: &str| match args.as_slice() {
+        [interval, rest @ ..] => Ok(format!(
+            "{function}({}, {})",
+            interval
+                .to_uppercase()
+                .strip_prefix("SQL_TSI_")
+                .unwrap_or(interval),
+            rest.join(", ")
+        )),
+        [] => Err(invalid()),
+    };
+    Ok(match name.to_uppercase().as_str() {
+        "UCASE" => format!("UPPER({arguments})"),
+        "LCASE" => format!("LOWER({arguments})"),
+        "LENGTH" | "CHARACTER_LENGTH" => format!("CHAR_LENGTH({arguments})"),
+        "CONCAT" => format!("({})", args.join(" || ")),
+        "CEILING" => format!("CEIL({arguments})"),
+        "POWER" => format!("POW({arguments})"),
+        "NOW" | "CURRENT_TIMESTAMP" => "CURRENT_TIMESTAMP".to_string(),
+        unit @ ("YEAR" | "MONTH" | "WEEK" | "HOUR" | "MINUTE" | "SECOND") => extract(unit),
+        "DAYOFMONTH" => extract("DAY"),
+        "DAYOFWEEK" => extract("DAY_OF_WEEK"),
+        "DAYOFYEAR" => extract("DAY_OF_YEAR"),
+        "TIMESTAMPADD" => date_function("DATEADD")?,
+        "TIMESTAMPDIFF" => date_function("DATEDIFF")?,
+        _ => body.to_string(),
+    })
+}
+
+/// Rewrites the ODBC escape sequences in `query` to MongoSQL: scalar
+/// functions, date and timestamp literals, outer joins, and LIKE escape
+/// characters. Procedure calls, time, interval and guid literals are not
+/// supported by MongoSQL.
+pub fn rewrite_escape_sequences(query: &str) -> Result<String> {
+    Scanner {
+        chars: query.chars().collect(),
+        pos: 0,
+    }
+    .rewrite(false)
+}
+
+#[cfg(test)]
+mod unit {
+    use super::rewrite_escape_sequences;
+    use crate::Error;
+
+    fn rewrite(query: &str) -> String {
+        rewrite_escape_sequences(query).unwrap()
+    }
+
+    #[test]
+    fn queries_without_escapes_are_unchanged() {
+        let query = "select {'a': 1} from foo where b = '{fn x}' -- {d '2024-01-01'}";
+        assert_eq!(query, rewrite(query));
+    }
+
+    #[test]
+    fn scalar_functions() {
+        assert_eq!(
+            "select UPPER(a), LOWER(b) from foo",
+            rewrite("select {fn UCASE(a)}, {FN lcase(b)} from foo")
+        );
+        assert_eq!(
+            "select (a || 'x,y' || UPPER(b)) from foo",
+            rewrite("select {fn CONCAT(a, 'x,y', {fn UCASE(b)})} from foo")
+        );
+        assert_eq!(
+            "select EXTRACT(YEAR FROM a), DATEADD(DAY, 1, a) from foo",
+            rewrite("select {fn YEAR(a)}, {fn TIMESTAMPADD(SQL_TSI_DAY, 1, a)} from foo")
+        );
+        assert_eq!(
+            "select ABS(a), CURRENT_TIMESTAMP from foo",
+            rewrite("select {fn ABS(a)}, {fn NOW()} from foo")
+        );
+    }
+
+    #[test]
+    fn date_and_timestamp_literals() {
+        assert_eq!(
+            "select * from foo where a > CAST('2024-01-01T00:00:00Z' AS TIMESTAMP)",
+            rewrite("select * from foo where a > {d '2024-01-01'}")
+        );
+        assert_eq!(
+            "select * from foo where a > CAST('2024-01-01T10:11:12.5Z' AS TIMESTAMP)",
+            rewrite("select * from foo where a > {ts '2024-01-01 10:11:12.5'}")
+        );
+    }
+
+    #[test]
+    fn outer_joins_and_like_escapes() {
+        assert_eq!(
+            "select * from foo left outer join bar on foo.a = bar.a",
+            rewrite("select * from {oj foo left outer join bar on foo.a = bar.a}")
+        );
+        assert_eq!(
+            r"select * from foo where a like 'x\_%' ESCAPE '\'",
+            rewrite(r"select * from foo where a like 'x\_%' {escape '\'}")
+        );
+    }
+
+    #[test]
+    fn unsupported_and_invalid_escapes() {
+        for query in [
+            "{call proc(1)}",
+            "{?= call proc(1)}",
+            "select {t '10:11:12'}",
+            "select {d 2024}",
+            "select {fn UCASE}",
+            "select {fn UCASE(a)",
+        ] {
+            assert!(
+                matches!(
+                    rewrite_escape_sequences(query),
+                    Err(Error::InvalidEscapeSequence(_))
+                ),
+                "{query}"
+            );
+        }
+    }
+}
@@ -441,6 +441,10 @@ pub struct MongoFields {
     max_string_length: Option<u16>,
     /// Whether this mongofield should map to odbc 3 types or not
     odbc_3_types: bool,
+    // The maximum number of fields to return, or 0 to return all of them.
+    max_rows: u64,
+    // The number of fields returned so far.
+    rows: u64,
 }
 
 // Statement related to a SQLTables call.
@@ -460,6 +464,7 @@ impl MongoFields {
     // (tables) names filters.
     // The query timeout comes from the statement attribute SQL_ATTR_QUERY_TIMEOUT. If there is a
     // timeout, the query must finish before the timeout or an error is returned.
+    // The maximum number of fields comes from the statement attribute SQL_ATTR_MAX_ROWS.
     #[allow(clippy::too_many_arguments)]
     pub fn list_columns(
         mongo_connection: &MongoConnection,
@@ -470,6 +475,7 @@ impl MongoFields {
         type_mode: TypeMode,
         max_string_length: Option<u16>,
         odbc_3_types: bool,
+        max_rows: u64,
     ) -> Self {
         let dbs = db_name.map_or_else(
             || {
@@ -503,6 +509,8 @@ impl MongoFields {
             type_mode,
             max_string_length,
             odbc_3_types,
+            max_rows,
+            rows: 0,
         }
     }
 
@@ -518,6 +526,8 @@ impl MongoFields {
             type_mode: TypeMode::Standard,
             max_string_length: None,
             odbc_3_types: true,
+            max_rows: 0,
+            rows: 0,
         }
     }
 
@@ -545,63 +555,20 @@ impl MongoFields {
                             continue;
                         }
 
-                        let db = mongo_connection.client.database(&self.current_db_name);
-
-                        let current_col_metadata_response: ResultSetSchema = if mongo_connection
-                            .cluster_type
-                            == MongoClusterType::AtlasDataFederation
+                        let current_col_metadata_response = match get_collection_schema(
+                            mongo_connection,
+                            &self.current_db_name,
+                            &collection_name,
+                        )
+                        .await
                         {
-                            let get_schema_cmd = doc! {"sqlGetSchema": &collection_name};
-
-                            let sql_get_schema_response: Result<SqlGetSchemaResponse> =
-                                mongodb::bson::from_document(
-                                    db.run_command(get_schema_cmd).await.unwrap(),
-                                )
-                                .map_err(|e| {
-                                    Error::CollectionDeserialization(collection_name.clone(), e)
-                                });
-
-                            if let Err(error) = sql_get_schema_response {
-                                // If there is an Error while deserializing the schema, we won't show any columns for it
+                            Ok(result_set_schema) => result_set_schema,
+                            // If there is an Error while deserializing the schema, we won't show any columns for it
+                            Err(error @ Error::CollectionDeserialization(..)) => {
                                 warnings.push(error);
                                 continue;
                             }
-
-                            sql_get_schema_response.unwrap().into()
-                        } else if mongo_connection.cluster_type == MongoClusterType::Enterprise {
-                            let schema_collection =
-                                db.collection::<Document>(SQL_SCHEMAS_COLLECTION);
-
-                            let schema_doc: Document = mongo_connection
-                                .runtime
-                                .block_on(async {
-                                    schema_collection
-                                        .find_one(doc! {
-                                            "_id": &collection_name
-                                        })
-                                        .await
-                                        .map_err(Error::QueryExecutionFailed)
-                                })?
-                                .ok_or(Error::SchemaDocumentNotFoundInSchemaCollection(vec![
-                                    collection_name.clone(),
-                                ]))?;
-
-                            let result_set_schema: Result<ResultSetSchema> =
-                                ResultSetSchema::from_sql_schemas_document(&schema_doc).map_err(
-                                    |e| {
-                                        Error::CollectionDeserialization(collection_name.clone(), e)
-                                    },
-                                );
-
-                            if let Err(error) = result_set_schema {
-                                // If there is an Error while deserializing the schema, we won't show any columns for it
-                                warnings.push(error);
-                                continue;
-                            }
-
-                            result_set_schema.unwrap()
-                        } else {
-                            unreachable!()
+                            Err(error) => return Err(error),
                         };
 
                         match current_col_metadata_response.process_collection_metadata(
@@ -655,12 +622,12 @@ impl MongoFields {
             }
         })
     }
-}
 
-impl MongoStatement for MongoFields {
-    // Move the cursor to the next document and update the current row.
-    // Return true if moving was successful, false otherwise.
-    fn next(&mut self, mongo_connection: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+    // Move to the next field matching the filters, in any collection.
+    fn next_field(
+        &mut self,
+        mongo_connection: Option<&MongoConnection>,
+    ) -> Result<(bool, Vec<Error>)> {
         match self.field_name_filter.as_ref() {
             None => {
                 self.current_field_for_collection += 1;
@@ -701,6 +668,60 @@ impl MongoStatement for MongoFields {
             }
         }
     }
+}
+
+// Get the schema of a collection, from sqlGetSchema on Atlas Data Federation or from the
+// __sql_schemas collection on Enterprise.
+// Returns Error::CollectionDeserialization if the schema cannot be deserialized.
+pub(crate) async fn get_collection_schema(
+    mongo_connection: &MongoConnection,
+    db_name: &str,
+    collection_name: &str,
+) -> Result<ResultSetSchema> {
+    let db = mongo_connection.client.database(db_name);
+    match mongo_connection.cluster_type {
+        MongoClusterType::AtlasDataFederation => {
+            let get_schema_cmd = doc! {"sqlGetSchema": collection_name};
+            let sql_get_schema_response: SqlGetSchemaResponse = mongodb::bson::from_document(
+                db.run_command(get_schema_cmd)
+                    .await
+                    .map_err(Error::QueryExecutionFailed)?,
+            )
+            .map_err(|e| Error::CollectionDeserialization(collection_name.to_string(), e))?;
+            Ok(sql_get_schema_response.into())
+        }
+        MongoClusterType::Enterprise => {
+            let schema_doc: Document = db
+                .collection::<Document>(SQL_SCHEMAS_COLLECTION)
+                .find_one(doc! {
+                    "_id": collection_name
+                })
+                .await
+                .map_err(Error::QueryExecutionFailed)?
+                .ok_or(Error::SchemaDocumentNotFoundInSchemaCollection(vec![
+                    collection_name.to_string(),
+                ]))?;
+            ResultSetSchema::from_sql_schemas_document(&schema_doc)
+                .map_err(|e| Error::CollectionDeserialization(collection_name.to_string(), e))
+        }
+        _ => unreachable!(),
+    }
+}
+
+impl MongoStatement for MongoFields {
+    // Move the cursor to the next document and update the current row.
+    // Return true if moving was successful, false otherwise.
+    // No more fields are returned once max_rows is reached, if max_rows is not 0.
+    fn next(&mut self, mongo_connection: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        if self.max_rows > 0 && self.rows >= self.max_rows {
+            return Ok((false, vec![]));
+        }
+        let next = self.next_field(mongo_connection)?;
+        if next.0 {
+            self.rows += 1;
+        }
+        Ok(next)
+    }
 
     // Get the BSON value for the cell at the given colIndex on the current row.
     // Fails if the first row as not been retrieved (next must be called at least once before getValue).
@@ -1,130 +1,479 @@
-use crate::{col_metadata::MongoColMetadata, stmt::EmptyStatement, BsonTypeInfo};
+use crate::{
+    col_metadata::MongoColMetadata,
+    conn::MongoConnection,
+    err::{Error, Result},
+    fields::get_collection_schema,
+    stmt::MongoStatement,
+    BsonTypeInfo,
+};
+use constants::{SQL_NOT_DEFERRABLE, SQL_NO_ACTION};
 use definitions::Nullability;
+use mongodb::bson::{doc, Bson};
 use once_cell::sync::OnceCell;
+use serde::{Deserialize, Deserializer, Serialize};
 
 static FK_METADATA: OnceCell<Vec<MongoColMetadata>> = OnceCell::new();
 
-pub struct MongoForeignKeys {}
+// The name of the index backing the primary key of a collection, see MongoPrimaryKeys.
+const PK_NAME: &str = "_id_";
 
+// A foreign key declared in the `foreignKeys` array of the schema of a collection, e.g.
+//
+//   {
+//     name: "orders_customer",
+//     columns: ["customerId"],
+//     references: { collection: "customers", columns: ["_id"] }
+//   }
+//
+// The referenced collection is in the same database, and the referenced columns default to _id.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ForeignKey {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub references: ForeignKeyReference,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ForeignKeyReference {
+    pub collection: String,
+    #[serde(default = "ForeignKeyReference::primary_key")]
+    pub columns: Vec<String>,
+}
+
+impl ForeignKeyReference {
+    fn primary_key() -> Vec<String> {
+        vec!["_id".to_string()]
+    }
+}
+
+// Deserialize the foreignKeys array of a schema. A malformed array must not prevent using the
+// schema, so it is logged and treated as declaring no foreign keys.
+pub(crate) fn deserialize_foreign_keys<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<ForeignKey>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let foreign_keys = Bson::deserialize(deserializer)?;
+    Ok(
+        mongodb::bson::from_bson(foreign_keys).unwrap_or_else(|error| {
+            log::error!("Ignoring the invalid foreign keys of a schema: {error}");
+            vec![]
+        }),
+    )
+}
+
+// One column of a foreign key.
+#[derive(Debug, Clone, PartialEq)]
+struct ForeignKeyRow {
+    pk_table: String,
+    pk_column: String,
+    fk_table: String,
+    fk_column: String,
+    key_seq: i32,
+    fk_name: Option<String>,
+    pk_name: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MongoForeignKeys {
+    database_name: String,
+    rows: Vec<ForeignKeyRow>,
+    // The index of the current row.
+    current: Option<usize>,
+}
+
+// Statement related to a SQLForeignKeys call.
 impl MongoForeignKeys {
-    pub fn empty(max_string_length: Option<u16>) -> EmptyStatement {
-        EmptyStatement {
-            resultset_metadata: FK_METADATA.get_or_init(|| {
-                vec![
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "PKTABLE_CAT".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "PKTABLE_SCHEM".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "PKTABLE_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "PKCOLUMN_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "FKTABLE_CAT".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "FKTABLE_SCHEM".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "FKTABLE_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "FKCOLUMN_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "KEY_SEQ".to_string(),
-                        BsonTypeInfo::INT,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "UPDATE_RULE".to_string(),
-                        BsonTypeInfo::INT,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "DELETE_RULE".to_string(),
-                        BsonTypeInfo::INT,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "FK_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "PK_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "DEFERRABILITY".to_string(),
-                        BsonTypeInfo::INT,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                ]
-            }),
+    // Create a new MongoStatement to list the foreign keys declared in the schemas of the
+    // collections of the given database.
+    // If fk_collection is not empty, only its foreign keys are listed. If pk_collection is not
+    // empty, only the foreign keys referencing it are listed.
+    // The maximum number of rows comes from the statement attribute SQL_ATTR_MAX_ROWS.
+    pub fn list_foreign_keys(
+        mongo_connection: &MongoConnection,
+        db_name: &str,
+        pk_collection: &str,
+        fk_collection: &str,
+        max_rows: u64,
+    ) -> Result<Self> {
+        let db = mongo_connection.client.database(db_name);
+        let rows = mongo_connection.runtime.block_on(async {
+            let fk_collections = match fk_collection.is_empty() {
+                false => vec![fk_collection.to_string()],
+                true => db
+                    .list_collection_names()
+                    .authorized_collections(true)
+                    .await
+                    .map_err(Error::QueryExecutionFailed)?,
+            };
+            let mut rows = vec![];
+            for collection in fk_collections {
+                match get_collection_schema(mongo_connection, db_name, &collection).await {
+                    Ok(schema) => rows.extend(foreign_key_rows(
+                        &collection,
+                        &schema.foreign_keys,
+                        pk_collection,
+                    )),
+                    Err(error @ Error::QueryExecutionFailed(_)) => return Err(error),
+                    // Collections without a valid schema have no foreign keys.
+                    Err(_) => {}
+                }
+            }
+            Ok(rows)
+        })?;
+        let mut foreign_keys = MongoForeignKeys::new(db_name, rows, fk_collection.is_empty());
+        if max_rows > 0 {
+            foreign_keys
+                .rows
+                .truncate(usize::try_from(max_rows).unwrap_or(usize::MAX));
+        }
+        Ok(foreign_keys)
+    }
+
+    // The rows are sorted by foreign key table when listing the foreign keys referencing a
+    // collection, and by primary key table otherwise.
+    fn new(db_name: &str, mut rows: Vec<ForeignKeyRow>, by_fk_table: bool) -> Self {
+        rows.sort_by(|a, b| {
+            let key = |row: &ForeignKeyRow| {
+                (
+                    match by_fk_table {
+                        true => row.fk_table.clone(),
+                        false => row.pk_table.clone(),
+                    },
+                    row.fk_name.clone(),
+                    row.key_seq,
+                )
+            };
+            key(a).cmp(&key(b))
+        });
+        MongoForeignKeys {
+            database_name: db_name.to_string(),
+            rows,
+            current: None,
+        }
+    }
+
+    pub fn empty() -> MongoForeignKeys {
+        MongoForeignKeys {
+            database_name: "".to_string(),
+            rows: Vec::new(),
+            current: None,
+        }
+    }
+}
+
+// Map the foreign keys of a collection to one row per column, keeping only the foreign keys
+// referencing pk_collection if it is not empty.
+fn foreign_key_rows(
+    fk_collection: &str,
+    foreign_keys: &[ForeignKey],
+    pk_collection: &str,
+) -> Vec<ForeignKeyRow> {
+    foreign_keys
+        .iter()
+        .filter(|foreign_key| {
+            pk_collection.is_empty() || foreign_key.references.collection == pk_collection
+        })
+        .filter(|foreign_key| {
+            let valid = !foreign_key.columns.is_empty()
+                && foreign_key.columns.len() == foreign_key.references.columns.len();
+            if !valid {
+                log::error!(
+                    "Foreign key {:?} of collection {fk_collection} does not reference as many columns as it has",
+                    foreign_key.name
+                );
+            }
+            valid
+        })
+        .flat_map(|foreign_key| {
+            // A foreign key referencing _id references the primary key of the collection.
+            let pk_name = (foreign_key.references.columns == ForeignKeyReference::primary_key())
+                .then(|| PK_NAME.to_string());
+            foreign_key
+                .columns
+                .iter()
+                .zip(foreign_key.references.columns.iter())
+                .enumerate()
+                .map(move |(i, (fk_column, pk_column))| ForeignKeyRow {
+                    pk_table: foreign_key.references.collection.clone(),
+                    pk_column: pk_column.clone(),
+                    fk_table: fk_collection.to_string(),
+                    fk_column: fk_column.clone(),
+                    key_seq: i32::try_from(i).unwrap_or(i32::MAX) + 1,
+                    fk_name: foreign_key.name.clone(),
+                    pk_name: pk_name.clone(),
+                })
+        })
+        .collect()
+}
+
+impl MongoStatement for MongoForeignKeys {
+    // Move the cursor to the next column of a foreign key.
+    // Return true if moving was successful, false otherwise.
+    fn next(&mut self, _: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        let next = self.current.map_or(0, |current| current + 1);
+        self.current = Some(next.min(self.rows.len()));
+        Ok((next < self.rows.len(), vec![]))
+    }
+
+    // Get the BSON value for the given colIndex on the current row.
+    // Fails if the first row as not been retrieved (next must be called at least once before getValue).
+    fn get_value(&self, col_index: u16, _: Option<u16>) -> Result<Option<Bson>> {
+        let row = self
+            .current
+            .and_then(|current| self.rows.get(current))
+            .ok_or(Error::InvalidCursorState)?;
+        Ok(Some(match col_index {
+            // PKTABLE_CAT
+            1 => Bson::String(self.database_name.clone()),
+            // PKTABLE_SCHEM
+            2 => Bson::Null,
+            // PKTABLE_NAME
+            3 => Bson::String(row.pk_table.clone()),
+            // PKCOLUMN_NAME
+            4 => Bson::String(row.pk_column.clone()),
+            // FKTABLE_CAT
+            5 => Bson::String(self.database_name.clone()),
+            // FKTABLE_SCHEM
+            6 => Bson::Null,
+            // FKTABLE_NAME
+            7 => Bson::String(row.fk_table.clone()),
+            // FKCOLUMN_NAME
+            8 => Bson::String(row.fk_column.clone()),
+            // KEY_SEQ
+            9 => Bson::Int32(row.key_seq),
+            // UPDATE_RULE and DELETE_RULE: MongoDB does not enforce foreign keys.
+            10 | 11 => Bson::Int32(i32::from(SQL_NO_ACTION)),
+            // FK_NAME
+            12 => row.fk_name.clone().map_or(Bson::Null, Bson::String),
+            // PK_NAME
+            13 => row.pk_name.clone().map_or(Bson::Null, Bson::String),
+            // DEFERRABILITY
+            14 => Bson::Int32(i32::from(SQL_NOT_DEFERRABLE)),
+            _ => return Err(Error::ColIndexOutOfBounds(col_index)),
+        }))
+    }
+
+    fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+        FK_METADATA.get_or_init(|| {
+            vec![
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "PKTABLE_CAT".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "PKTABLE_SCHEM".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "PKTABLE_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "PKCOLUMN_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "FKTABLE_CAT".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "FKTABLE_SCHEM".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "FKTABLE_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "FKCOLUMN_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "KEY_SEQ".to_string(),
+                    BsonTypeInfo::INT,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "UPDATE_RULE".to_string(),
+                    BsonTypeInfo::INT,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "DELETE_RULE".to_string(),
+                    BsonTypeInfo::INT,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "FK_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "PK_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "DEFERRABILITY".to_string(),
+                    BsonTypeInfo::INT,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::{foreign_key_rows, MongoForeignKeys};
+    use crate::{col_metadata::ResultSetSchema, stmt::MongoStatement};
+    use mongodb::bson::{doc, Bson};
+
+    fn rows(foreign_keys: &mut MongoForeignKeys) -> Vec<Vec<Bson>> {
+        let mut rows = vec![];
+        while foreign_keys.next(None).unwrap().0 {
+            rows.push(
+                [3, 4, 7, 8, 9, 12, 13]
+                    .into_iter()
+                    .map(|col| foreign_keys.get_value(col, None).unwrap().unwrap())
+                    .collect(),
+            );
+        }
+        rows
+    }
+
+    #[test]
+    fn metadata_size() {
+        assert_eq!(
+            14,
+            MongoForeignKeys::empty().get_resultset_metadata(None).len()
+        );
+    }
+
+    #[test]
+    fn invalid_foreign_keys_are_ignored() {
+        for foreign_keys in [
+            Bson::from("orders_customer"),
+            Bson::Array(vec![Bson::from(doc! {"columns": ["customerId"]})]),
+        ] {
+            let schema = ResultSetSchema::from_sql_schemas_document(&doc! {
+                "_id": "orders",
+                "type": "collection",
+                "schema": {"bsonType": "object", "properties": {}},
+                "foreignKeys": foreign_keys,
+            })
+            .unwrap();
+            assert!(schema.foreign_keys.is_empty());
+            assert!(schema.schema.properties.is_some());
         }
     }
+
+    #[test]
+    fn foreign_keys_from_sql_schemas_document() {
+        let schema = ResultSetSchema::from_sql_schemas_document(&doc! {
+            "_id": "orders",
+            "type": "collection",
+            "schema": {"bsonType": "object", "properties": {}},
+            "foreignKeys": [
+                {"name": "orders_customer", "columns": ["customerId"], "references": {"collection": "customers"}},
+                {"columns": ["sku", "warehouse"], "references": {"collection": "stock", "columns": ["sku", "warehouse"]}},
+                {"columns": ["invalid"], "references": {"collection": "customers", "columns": []}},
+            ],
+        })
+        .unwrap();
+
+        let fk_rows = foreign_key_rows("orders", &schema.foreign_keys, "");
+        let row = |pk_table: &str,
+                   pk_column: &str,
+                   fk_column: &str,
+                   key_seq,
+                   fk_name: Bson,
+                   pk_name: Bson| {
+            vec![
+                Bson::from(pk_table),
+                Bson::from(pk_column),
+                Bson::from("orders"),
+                Bson::from(fk_column),
+                Bson::Int32(key_seq),
+                fk_name,
+                pk_name,
+            ]
+        };
+        let customers = row(
+            "customers",
+            "_id",
+            "customerId",
+            1,
+            Bson::from("orders_customer"),
+            Bson::from("_id_"),
+        );
+        assert_eq!(
+            vec![
+                customers.clone(),
+                row("stock", "sku", "sku", 1, Bson::Null, Bson::Null),
+                row("stock", "warehouse", "warehouse", 2, Bson::Null, Bson::Null),
+            ],
+            rows(&mut MongoForeignKeys::new("db", fk_rows, false))
+        );
+
+        // Looking up the foreign keys referencing a collection.
+        let fk_rows = foreign_key_rows("orders", &schema.foreign_keys, "customers");
+        assert_eq!(
+            vec![customers],
+            rows(&mut MongoForeignKeys::new("db", fk_rows, true))
+        );
+    }
 }
@@ -5,6 +5,8 @@ pub use bson_type_info::TypeMode;
 pub use bson_type_info::MAX_STRING_SIZE;
 pub use bson_type_info::SQL_SEARCHABLE;
 
+mod cancellation;
+pub use cancellation::Cancellation;
 mod collections;
 pub use collections::MongoCollections;
 mod conn;
@@ -24,9 +26,19 @@ mod query;
 pub use query::MongoQuery;
 pub mod mock_query;
 mod stmt;
-pub use stmt::MongoStatement;
+pub use stmt::{MongoStatement, ScrollResult};
+mod static_cursor;
+pub use static_cursor::MongoStaticCursor;
+pub mod escapes;
 pub mod odbc_uri;
+pub mod parameters;
 mod primary_keys;
+mod privileges;
+pub use privileges::MongoPrivileges;
+mod special_columns;
+pub use special_columns::MongoSpecialColumns;
+mod statistics;
+pub use statistics::MongoStatistics;
 mod type_info;
 pub use type_info::MongoTypesInfo;
 pub mod util;
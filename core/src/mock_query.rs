@@ -56,6 +56,13 @@ impl MongoStatement for MongoQuery {
         &self.resultset_metadata
     }
 
+    // The row count is known once the cursor moved past the last Document.
+    fn get_row_count(&self) -> Option<usize> {
+        self.current
+            .filter(|current| *current >= self.resultset.len())
+            .map(|_| self.resultset.len())
+    }
+
     // Close the cursor by setting the current value and cursor to None.
     fn close_cursor(&mut self) {
         self.current = None;
@@ -0,0 +1,283 @@
+use crate::{err::Result, Error};
+use mongodb::bson::Bson;
+
+const PARAMETER_MARKER: char = '?';
+const STATEMENT_SEPARATOR: char = ';';
+
+// The lexical contexts a parameter marker or a statement separator can appear
+// in. They are only meaningful in `Code`; a `?` or a `;` inside a literal, a
+// quoted identifier or a comment is just text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context {
+    Code,
+    SingleQuoted,
+    DoubleQuoted,
+    BacktickQuoted,
+    LineComment,
+    BlockComment,
+}
+
+// Returns the byte offsets of every `target` character in `query`, skipping
+// string literals, quoted identifiers, and comments. Quotes are escaped by
+// doubling them, so `'it''s'` is a single literal.
+fn code_char_offsets(query: &str, target: char) -> Vec<usize> {
+    let mut offsets = vec![];
+    let mut context = Context::Code;
+    let mut chars = query.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        context = match (context, c) {
+            (Context::Code, c) if c == target => {
+                offsets.push(offset);
+                Context::Code
+            }
+            (Context::Code, '\'') => Context::SingleQuoted,
+            (Context::Code, '"') => Context::DoubleQuoted,
+            (Context::Code, '`') => Context::BacktickQuoted,
+            (Context::Code, '-') if chars.next_if(|&(_, n)| n == '-').is_some() => {
+                Context::LineComment
+            }
+            (Context::Code, '/') if chars.next_if(|&(_, n)| n == '*').is_some() => {
+                Context::BlockComment
+            }
+            (Context::SingleQuoted, '\'')
+            | (Context::DoubleQuoted, '"')
+            | (Context::BacktickQuoted, '`') => {
+                // A doubled quote is an escaped quote and keeps us in the literal.
+                if chars.next_if(|&(_, n)| n == c).is_some() {
+                    context
+                } else {
+                    Context::Code
+                }
+            }
+            (Context::LineComment, '\n') => Context::Code,
+            (Context::BlockComment, '*') if chars.next_if(|&(_, n)| n == '/').is_some() => {
+                Context::Code
+            }
+            (context, _) => context,
+        };
+    }
+    offsets
+}
+
+// Returns the byte offsets of every parameter marker in `query`.
+fn parameter_marker_offsets(query: &str) -> Vec<usize> {
+    code_char_offsets(query, PARAMETER_MARKER)
+}
+
+/// Splits a batch of `;` separated statements into its statements, in order.
+/// Separators inside literals, quoted identifiers, and comments are ignored,
+/// and empty statements are dropped.
+pub fn split_statements(query: &str) -> Vec<&str> {
+    let mut statements = vec![];
+    let mut last = 0;
+    for offset in code_char_offsets(query, STATEMENT_SEPARATOR)
+        .into_iter()
+        .chain([query.len()])
+    {
+        let statement = query[last..offset].trim();
+        if !statement.is_empty() {
+            statements.push(statement);
+        }
+        last = offset + STATEMENT_SEPARATOR.len_utf8();
+    }
+    statements
+}
+
+/// Returns the number of `?` parameter markers in `query`.
+pub fn count_parameter_markers(query: &str) -> usize {
+    parameter_marker_offsets(query).len()
+}
+
+/// Replaces each `?` parameter marker in `query` with the SQL literal for the
+/// corresponding value in `parameters`, in order. Values are rendered as
+/// literals rather than spliced in as text, so a string value can never
+/// change the structure of the query.
+pub fn substitute_parameters(query: &str, parameters: &[Bson]) -> Result<String> {
+    let offsets = parameter_marker_offsets(query);
+    if offsets.len() != parameters.len() {
+        return Err(Error::ParameterCountMismatch(
+            offsets.len(),
+            parameters.len(),
+        ));
+    }
+    let mut substituted = String::with_capacity(query.len());
+    let mut last = 0;
+    for (offset, parameter) in offsets.into_iter().zip(parameters) {
+        substituted.push_str(&query[last..offset]);
+        substituted.push_str(&to_sql_literal(parameter)?);
+        last = offset + PARAMETER_MARKER.len_utf8();
+    }
+    substituted.push_str(&query[last..]);
+    Ok(substituted)
+}
+
+/// Renders a BSON value as a MongoSQL literal of the same type.
+pub fn to_sql_literal(value: &Bson) -> Result<String> {
+    Ok(match value {
+        Bson::Null | Bson::Undefined => "NULL".to_string(),
+        Bson::Boolean(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+        // Negative numbers are parenthesized so that substituting into
+        // `a - ?` cannot produce the comment introducer `--`.
+        Bson::Int32(i) if *i < 0 => format!("({i})"),
+        Bson::Int32(i) => i.to_string(),
+        Bson::Int64(i) if *i < 0 => format!("CAST(({i}) AS LONG)"),
+        Bson::Int64(i) => format!("CAST({i} AS LONG)"),
+        Bson::Double(d) if d.is_nan() => "CAST('NaN' AS DOUBLE)".to_string(),
+        Bson::Double(d) if d.is_infinite() && *d > 0.0 => "CAST('Infinity' AS DOUBLE)".to_string(),
+        Bson::Double(d) if d.is_infinite() => "CAST('-Infinity' AS DOUBLE)".to_string(),
+        // The Debug representation always includes a decimal point or an
+        // exponent, so the literal is parsed as a double rather than an int.
+        Bson::Double(d) if d.is_sign_negative() => format!("({d:?})"),
+        Bson::Double(d) => format!("{d:?}"),
+        Bson::String(s) => quote_string(s),
+        // Binary data is written as a cast of its base64 encoding, as in extended JSON.
+        Bson::Binary(binary) => format!(
+            "CAST({} AS BINDATA)",
+            quote_string(
+                Bson::Binary(binary.clone()).into_relaxed_extjson()["$binary"]["base64"]
+                    .as_str()
+                    .unwrap_or_default()
+            )
+        ),
+        Bson::DateTime(dt) => format!(
+            "CAST({} AS TIMESTAMP)",
+            quote_string(
+                &dt.try_to_rfc3339_string()
+                    .map_err(|_| Error::UnsupportedParameterType(format!("{dt:?}")))?
+            )
+        ),
+        _ => {
+            return Err(Error::UnsupportedParameterType(format!(
+                "{:?}",
+                value.element_type()
+            )))
+        }
+    })
+}
+
+fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod unit {
+    use super::{count_parameter_markers, split_statements, substitute_parameters, to_sql_literal};
+    use crate::Error;
+    use mongodb::bson::{spec::BinarySubtype, Binary, Bson, DateTime};
+
+    #[test]
+    fn count_markers_in_code() {
+        assert_eq!(0, count_parameter_markers("select * from foo"));
+        assert_eq!(
+            2,
+            count_parameter_markers("select * from foo where a = ? and b > ?")
+        );
+    }
+
+    #[test]
+    fn markers_in_literals_and_comments_are_ignored() {
+        assert_eq!(
+            1,
+            count_parameter_markers("select '?', \"?\", `?` /* ? */ from foo -- ?\n where a = ?")
+        );
+        assert_eq!(
+            1,
+            count_parameter_markers("select 'it''s ?' from foo where a = ?")
+        );
+        assert_eq!(0, count_parameter_markers("select `a``?` from foo"));
+    }
+
+    #[test]
+    fn split_batch() {
+        assert_eq!(
+            vec!["select a from foo", "select ';' from bar -- ;", "select 1"],
+            split_statements("select a from foo; select ';' from bar -- ;\n; ;select 1;")
+        );
+        assert_eq!(
+            vec!["select a from foo"],
+            split_statements("select a from foo")
+        );
+        assert!(split_statements(" ; ").is_empty());
+    }
+
+    #[test]
+    fn substitute_in_order() {
+        assert_eq!(
+            "select * from foo where a = 1 and b = 'x'",
+            substitute_parameters(
+                "select * from foo where a = ? and b = ?",
+                &[Bson::Int32(1), Bson::String("x".to_string())]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn substitute_leaves_quoted_markers_alone() {
+        assert_eq!(
+            "select '?' from foo where a = NULL",
+            substitute_parameters("select '?' from foo where a = ?", &[Bson::Null]).unwrap()
+        );
+    }
+
+    #[test]
+    fn substitute_wrong_count_fails() {
+        assert!(matches!(
+            substitute_parameters("select ? from foo", &[]),
+            Err(Error::ParameterCountMismatch(1, 0))
+        ));
+    }
+
+    #[test]
+    fn string_literals_are_escaped() {
+        assert_eq!(
+            "'x'' or ''1''=''1'",
+            to_sql_literal(&Bson::String("x' or '1'='1".to_string())).unwrap()
+        );
+    }
+
+    #[test]
+    fn numeric_literals_keep_their_type() {
+        assert_eq!("(-1)", to_sql_literal(&Bson::Int32(-1)).unwrap());
+        assert_eq!("CAST(7 AS LONG)", to_sql_literal(&Bson::Int64(7)).unwrap());
+        assert_eq!("2.0", to_sql_literal(&Bson::Double(2.0)).unwrap());
+        assert_eq!("(-0.5)", to_sql_literal(&Bson::Double(-0.5)).unwrap());
+        assert_eq!("(-0.0)", to_sql_literal(&Bson::Double(-0.0)).unwrap());
+        assert_eq!(
+            "select a -(-0.0) from foo",
+            substitute_parameters("select a -? from foo", &[Bson::Double(-0.0)]).unwrap()
+        );
+        assert_eq!(
+            "CAST('NaN' AS DOUBLE)",
+            to_sql_literal(&Bson::Double(f64::NAN)).unwrap()
+        );
+    }
+
+    #[test]
+    fn datetime_literal() {
+        assert_eq!(
+            "CAST('1970-01-01T00:00:00Z' AS TIMESTAMP)",
+            to_sql_literal(&Bson::DateTime(DateTime::from_millis(0))).unwrap()
+        );
+    }
+
+    #[test]
+    fn binary_literal() {
+        assert_eq!(
+            "CAST('BQYq' AS BINDATA)",
+            to_sql_literal(&Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: vec![5, 6, 42],
+            }))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn unsupported_literal_type() {
+        assert!(matches!(
+            to_sql_literal(&Bson::Array(vec![])),
+            Err(Error::UnsupportedParameterType(_))
+        ));
+    }
+}
@@ -1,66 +1,226 @@
-use crate::{col_metadata::MongoColMetadata, stmt::EmptyStatement, BsonTypeInfo};
+use crate::{
+    col_metadata::MongoColMetadata,
+    conn::MongoConnection,
+    err::{Error, Result},
+    fields::get_collection_schema,
+    stmt::MongoStatement,
+    BsonTypeInfo, TypeMode,
+};
 use definitions::Nullability;
+use mongodb::bson::{doc, Bson};
 use once_cell::sync::OnceCell;
 
 static PK_METADATA: OnceCell<Vec<MongoColMetadata>> = OnceCell::new();
 
-pub struct MongoPrimaryKeys {}
+// The primary key of a collection is its _id field, which is backed by the _id_ index.
+const PK_COLUMN_NAME: &str = "_id";
+const PK_NAME: &str = "_id_";
 
+#[derive(Debug)]
+pub struct MongoPrimaryKeys {
+    database_name: String,
+    // The collections with an _id field in their schema, sorted by name.
+    collections: Vec<String>,
+    // The index of the current collection.
+    current: Option<usize>,
+}
+
+// Statement related to a SQLPrimaryKeys call.
 impl MongoPrimaryKeys {
-    pub fn empty(max_string_length: Option<u16>) -> EmptyStatement {
-        EmptyStatement {
-            resultset_metadata: PK_METADATA.get_or_init(|| {
+    // Create a new MongoStatement to list the primary keys of the collections of the given
+    // database, or only of the given collection if collection_name is not empty.
+    // Views and collections whose schema does not have an _id field have no primary key.
+    // The maximum number of primary keys comes from the statement attribute SQL_ATTR_MAX_ROWS.
+    pub fn list_primary_keys(
+        mongo_connection: &MongoConnection,
+        db_name: &str,
+        collection_name: &str,
+        max_rows: u64,
+    ) -> Result<Self> {
+        let db = mongo_connection.client.database(db_name);
+        // Views have no primary key.
+        let mut filter = doc! { "type": "collection" };
+        if !collection_name.is_empty() {
+            filter.insert("name", collection_name);
+        }
+        let mut collections = mongo_connection.runtime.block_on(async {
+            let names = db
+                .list_collection_names()
+                .filter(filter)
+                .authorized_collections(true)
+                .await
+                .map_err(Error::QueryExecutionFailed)?;
+            let mut collections = vec![];
+            for collection in names {
+                // Collections without a schema are skipped, like in SQLColumns.
+                let has_id = get_collection_schema(mongo_connection, db_name, &collection)
+                    .await
+                    .and_then(|schema| {
+                        schema.process_collection_metadata(
+                            db_name,
+                            &collection,
+                            TypeMode::Standard,
+                            None,
+                        )
+                    })
+                    .is_ok_and(|columns| {
+                        columns
+                            .iter()
+                            .any(|column| column.col_name == PK_COLUMN_NAME)
+                    });
+                if has_id {
+                    collections.push(collection);
+                }
+            }
+            Ok::<_, Error>(collections)
+        })?;
+        collections.sort();
+        if max_rows > 0 {
+            collections.truncate(usize::try_from(max_rows).unwrap_or(usize::MAX));
+        }
+        Ok(MongoPrimaryKeys {
+            database_name: db_name.to_string(),
+            collections,
+            current: None,
+        })
+    }
+
+    pub fn empty() -> MongoPrimaryKeys {
+        MongoPrimaryKeys {
+            database_name: "".to_string(),
+            collections: Vec::new(),
+            current: None,
+        }
+    }
+}
+
+impl MongoStatement for MongoPrimaryKeys {
+    // Move the cursor to the primary key of the next collection.
+    // Return true if moving was successful, false otherwise.
+    fn next(&mut self, _: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        let next = self.current.map_or(0, |current| current + 1);
+        self.current = Some(next.min(self.collections.len()));
+        Ok((next < self.collections.len(), vec![]))
+    }
+
+    // Get the BSON value for the given colIndex on the current primary key.
+    // Fails if the first row as not been retrieved (next must be called at least once before getValue).
+    fn get_value(&self, col_index: u16, _: Option<u16>) -> Result<Option<Bson>> {
+        let collection = self
+            .current
+            .and_then(|current| self.collections.get(current))
+            .ok_or(Error::InvalidCursorState)?;
+        Ok(Some(match col_index {
+            // TABLE_CAT
+            1 => Bson::String(self.database_name.clone()),
+            // TABLE_SCHEM
+            2 => Bson::Null,
+            // TABLE_NAME
+            3 => Bson::String(collection.clone()),
+            // COLUMN_NAME
+            4 => Bson::String(PK_COLUMN_NAME.to_string()),
+            // KEY_SEQ
+            5 => Bson::Int32(1),
+            // PK_NAME
+            6 => Bson::String(PK_NAME.to_string()),
+            _ => return Err(Error::ColIndexOutOfBounds(col_index)),
+        }))
+    }
+
+    fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+        PK_METADATA.get_or_init(|| {
+            vec![
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "TABLE_CAT".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "TABLE_SCHEM".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NULLABLE,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "TABLE_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "COLUMN_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "KEY_SEQ".to_string(),
+                    BsonTypeInfo::INT,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    "PK_NAME".to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    Nullability::SQL_NO_NULLS,
+                ),
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::MongoPrimaryKeys;
+    use crate::stmt::MongoStatement;
+    use mongodb::bson::Bson;
+
+    #[test]
+    fn metadata_size() {
+        assert_eq!(
+            6,
+            MongoPrimaryKeys::empty().get_resultset_metadata(None).len()
+        );
+    }
+
+    #[test]
+    fn primary_key_per_collection() {
+        let mut primary_keys = MongoPrimaryKeys {
+            database_name: "db".to_string(),
+            collections: vec!["bar".to_string(), "foo".to_string()],
+            current: None,
+        };
+        for collection in ["bar", "foo"] {
+            assert!(primary_keys.next(None).unwrap().0);
+            assert_eq!(
                 vec![
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "TABLE_CAT".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "TABLE_SCHEM".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NULLABLE,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "TABLE_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "COLUMN_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "KEY_SEQ".to_string(),
-                        BsonTypeInfo::INT,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                    MongoColMetadata::new_metadata_from_bson_type_info_default(
-                        "",
-                        "".to_string(),
-                        "PK_NAME".to_string(),
-                        BsonTypeInfo::STRING,
-                        max_string_length,
-                        Nullability::SQL_NO_NULLS,
-                    ),
-                ]
-            }),
+                    Bson::String("db".to_string()),
+                    Bson::Null,
+                    Bson::String(collection.to_string()),
+                    Bson::String("_id".to_string()),
+                    Bson::Int32(1),
+                    Bson::String("_id_".to_string()),
+                ],
+                (1..=6)
+                    .map(|col| primary_keys.get_value(col, None).unwrap().unwrap())
+                    .collect::<Vec<Bson>>()
+            );
         }
+        assert!(!primary_keys.next(None).unwrap().0);
+        assert!(primary_keys.get_value(1, None).is_err());
     }
 }
@@ -0,0 +1,453 @@
+use crate::{
+    col_metadata::MongoColMetadata,
+    conn::MongoConnection,
+    err::{Error, Result},
+    fields::get_collection_schema,
+    stmt::MongoStatement,
+    util::is_match,
+    BsonTypeInfo, TypeMode,
+};
+use definitions::Nullability;
+use mongodb::bson::{doc, Bson, Document};
+use once_cell::sync::OnceCell;
+
+static TABLE_PRIVILEGES_METADATA: OnceCell<Vec<MongoColMetadata>> = OnceCell::new();
+static COLUMN_PRIVILEGES_METADATA: OnceCell<Vec<MongoColMetadata>> = OnceCell::new();
+
+// The driver is read-only, so the only privilege it reports is SELECT, which MongoDB grants with
+// the find action.
+const SELECT: &str = "SELECT";
+const FIND_ACTION: &str = "find";
+
+// One row of the SQLTablePrivileges or SQLColumnPrivileges result sets.
+#[derive(Debug, Clone, PartialEq)]
+struct PrivilegeRow {
+    catalog: String,
+    table: String,
+    // None for table privileges.
+    column: Option<String>,
+    grantee: String,
+}
+
+// The users authenticated on the connection and their privileges.
+#[derive(Debug, Default)]
+struct UserPrivileges {
+    grantees: Vec<String>,
+    privileges: Vec<Document>,
+}
+
+impl UserPrivileges {
+    // Read the privileges of the authenticated users from connectionStatus. If the server does
+    // not return them, read the privileges of their roles from rolesInfo.
+    async fn get(mongo_connection: &MongoConnection) -> Result<Self> {
+        let admin = mongo_connection.client.database("admin");
+        let connection_status = admin
+            .run_command(doc! { "connectionStatus": 1, "showPrivileges": true })
+            .await
+            .map_err(Error::QueryExecutionFailed)?;
+        let auth_info = connection_status
+            .get_document("authInfo")
+            .cloned()
+            .unwrap_or_default();
+        let documents = |key: &str| -> Vec<Document> {
+            auth_info
+                .get_array(key)
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(Bson::as_document)
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let grantees = documents("authenticatedUsers")
+            .iter()
+            .filter_map(|user| user.get_str("user").ok().map(str::to_string))
+            .collect();
+        let privileges = match auth_info.get_array("authenticatedUserPrivileges") {
+            Ok(_) => documents("authenticatedUserPrivileges"),
+            Err(_) => {
+                let mut privileges = vec![];
+                for role in documents("authenticatedUserRoles") {
+                    let (Ok(role_name), Ok(role_db)) = (role.get_str("role"), role.get_str("db"))
+                    else {
+                        continue;
+                    };
+                    let roles_info = mongo_connection
+                        .client
+                        .database(role_db)
+                        .run_command(doc! {
+                            "rolesInfo": { "role": role_name, "db": role_db },
+                            "showPrivileges": true,
+                        })
+                        .await;
+                    // A user can only read the privileges of its roles if it has viewRole.
+                    let Ok(roles_info) = roles_info else {
+                        log::error!("Error getting the privileges of role {role_name}@{role_db}");
+                        continue;
+                    };
+                    privileges.extend(
+                        roles_info
+                            .get_array("roles")
+                            .into_iter()
+                            .flatten()
+                            .filter_map(Bson::as_document)
+                            .filter_map(|role| role.get_array("inheritedPrivileges").ok())
+                            .flatten()
+                            .filter_map(Bson::as_document)
+                            .cloned(),
+                    );
+                }
+                privileges
+            }
+        };
+        Ok(UserPrivileges {
+            grantees,
+            privileges,
+        })
+    }
+
+    // Return the privilege rows of the users that can find documents in the collection.
+    fn rows(
+        &self,
+        db_name: &str,
+        collection_name: &str,
+        column: Option<&str>,
+    ) -> Vec<PrivilegeRow> {
+        match self
+            .privileges
+            .iter()
+            .any(|privilege| grants_select(privilege, db_name, collection_name))
+        {
+            true => self
+                .grantees
+                .iter()
+                .map(|grantee| PrivilegeRow {
+                    catalog: db_name.to_string(),
+                    table: collection_name.to_string(),
+                    column: column.map(str::to_string),
+                    grantee: grantee.clone(),
+                })
+                .collect(),
+            false => vec![],
+        }
+    }
+}
+
+// Whether the privilege allows the find action on the collection. See
+// https://www.mongodb.com/docs/manual/reference/resource-document/.
+fn grants_select(privilege: &Document, db_name: &str, collection_name: &str) -> bool {
+    let finds = privilege.get_array("actions").is_ok_and(|actions| {
+        actions
+            .iter()
+            .any(|action| action.as_str() == Some(FIND_ACTION))
+    });
+    let Ok(resource) = privilege.get_document("resource") else {
+        return false;
+    };
+    let on_collection = match (resource.get_str("db"), resource.get_str("collection")) {
+        _ if resource.get_bool("anyResource") == Ok(true) => true,
+        // An empty db or collection matches every database or every non-system collection.
+        (Ok(db), Ok(collection)) => {
+            (db.is_empty() || db == db_name)
+                && (collection == collection_name
+                    || (collection.is_empty() && !collection_name.starts_with("system.")))
+        }
+        _ => false,
+    };
+    finds && on_collection
+}
+
+#[derive(Debug)]
+pub struct MongoPrivileges {
+    rows: Vec<PrivilegeRow>,
+    // The index of the current row.
+    current: Option<usize>,
+    // Whether the result set is the one of SQLColumnPrivileges, with a COLUMN_NAME column.
+    columns: bool,
+}
+
+// Statement related to SQLTablePrivileges and SQLColumnPrivileges calls.
+impl MongoPrivileges {
+    // Create a new MongoStatement to list the SELECT privileges of the authenticated users on the
+    // collections of the given database whose names match collection_name_filter.
+    // The maximum number of rows comes from the statement attribute SQL_ATTR_MAX_ROWS.
+    pub fn list_table_privileges(
+        mongo_connection: &MongoConnection,
+        db_name: &str,
+        collection_name_filter: &str,
+        max_rows: u64,
+    ) -> Result<Self> {
+        let rows = mongo_connection.runtime.block_on(async {
+            let user_privileges = UserPrivileges::get(mongo_connection).await?;
+            let mut collections = list_collection_names(mongo_connection, db_name).await?;
+            collections.retain(|collection| is_match(collection, collection_name_filter, true));
+            collections.sort();
+            Ok::<_, Error>(
+                collections
+                    .iter()
+                    .flat_map(|collection| user_privileges.rows(db_name, collection, None))
+                    .collect(),
+            )
+        })?;
+        Ok(MongoPrivileges::new(rows, false, max_rows))
+    }
+
+    // Create a new MongoStatement to list the SELECT privileges of the authenticated users on the
+    // fields of the given collection whose names match column_name_filter. A privilege on a
+    // collection is a privilege on all its fields.
+    // The maximum number of rows comes from the statement attribute SQL_ATTR_MAX_ROWS.
+    pub fn list_column_privileges(
+        mongo_connection: &MongoConnection,
+        db_name: &str,
+        collection_name: &str,
+        column_name_filter: &str,
+        type_mode: TypeMode,
+        max_rows: u64,
+    ) -> Result<Self> {
+        let rows = mongo_connection.runtime.block_on(async {
+            let user_privileges = UserPrivileges::get(mongo_connection).await?;
+            let columns = match get_collection_schema(mongo_connection, db_name, collection_name)
+                .await
+                .and_then(|schema| {
+                    schema.process_collection_metadata(db_name, collection_name, type_mode, None)
+                }) {
+                Ok(columns) => columns,
+                Err(error @ Error::QueryExecutionFailed(_)) => return Err(error),
+                // A collection without a valid schema has no columns, like in SQLColumns.
+                Err(_) => vec![],
+            };
+            Ok(columns
+                .iter()
+                .filter(|column| is_match(&column.col_name, column_name_filter, true))
+                .flat_map(|column| {
+                    user_privileges.rows(db_name, collection_name, Some(&column.col_name))
+                })
+                .collect())
+        })?;
+        Ok(MongoPrivileges::new(rows, true, max_rows))
+    }
+
+    fn new(mut rows: Vec<PrivilegeRow>, columns: bool, max_rows: u64) -> Self {
+        if max_rows > 0 {
+            rows.truncate(usize::try_from(max_rows).unwrap_or(usize::MAX));
+        }
+        MongoPrivileges {
+            rows,
+            current: None,
+            columns,
+        }
+    }
+
+    pub fn empty(columns: bool) -> MongoPrivileges {
+        MongoPrivileges::new(vec![], columns, 0)
+    }
+}
+
+async fn list_collection_names(
+    mongo_connection: &MongoConnection,
+    db_name: &str,
+) -> Result<Vec<String>> {
+    mongo_connection
+        .client
+        .database(db_name)
+        .list_collection_names()
+        .authorized_collections(true)
+        .await
+        .map_err(Error::QueryExecutionFailed)
+}
+
+impl MongoStatement for MongoPrivileges {
+    // Move the cursor to the next privilege.
+    // Return true if moving was successful, false otherwise.
+    fn next(&mut self, _: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        let next = self.current.map_or(0, |current| current + 1);
+        self.current = Some(next.min(self.rows.len()));
+        Ok((next < self.rows.len(), vec![]))
+    }
+
+    // Get the BSON value for the given colIndex on the current row.
+    // Fails if the first row as not been retrieved (next must be called at least once before getValue).
+    fn get_value(&self, col_index: u16, _: Option<u16>) -> Result<Option<Bson>> {
+        let row = self
+            .current
+            .and_then(|current| self.rows.get(current))
+            .ok_or(Error::InvalidCursorState)?;
+        // The columns after TABLE_NAME are shifted by COLUMN_NAME in SQLColumnPrivileges.
+        let shifted_index = match (self.columns, col_index) {
+            (true, 4) => return Ok(Some(Bson::String(row.column.clone().unwrap_or_default()))),
+            (true, 5..) => col_index - 1,
+            _ => col_index,
+        };
+        Ok(Some(match shifted_index {
+            // TABLE_CAT
+            1 => Bson::String(row.catalog.clone()),
+            // TABLE_SCHEM
+            2 => Bson::Null,
+            // TABLE_NAME
+            3 => Bson::String(row.table.clone()),
+            // GRANTOR: privileges are granted through roles.
+            4 => Bson::Null,
+            // GRANTEE
+            5 => Bson::String(row.grantee.clone()),
+            // PRIVILEGE
+            6 => Bson::String(SELECT.to_string()),
+            // IS_GRANTABLE
+            7 => Bson::Null,
+            _ => return Err(Error::ColIndexOutOfBounds(col_index)),
+        }))
+    }
+
+    fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+        let metadata = |columns: bool| {
+            [
+                ("TABLE_CAT", Nullability::SQL_NULLABLE),
+                ("TABLE_SCHEM", Nullability::SQL_NULLABLE),
+                ("TABLE_NAME", Nullability::SQL_NO_NULLS),
+                ("COLUMN_NAME", Nullability::SQL_NO_NULLS),
+                ("GRANTOR", Nullability::SQL_NULLABLE),
+                ("GRANTEE", Nullability::SQL_NO_NULLS),
+                ("PRIVILEGE", Nullability::SQL_NO_NULLS),
+                ("IS_GRANTABLE", Nullability::SQL_NULLABLE),
+            ]
+            .into_iter()
+            .filter(|(col_name, _)| columns || *col_name != "COLUMN_NAME")
+            .map(|(col_name, nullability)| {
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    col_name.to_string(),
+                    BsonTypeInfo::STRING,
+                    max_string_length,
+                    nullability,
+                )
+            })
+            .collect()
+        };
+        match self.columns {
+            false => TABLE_PRIVILEGES_METADATA.get_or_init(|| metadata(false)),
+            true => COLUMN_PRIVILEGES_METADATA.get_or_init(|| metadata(true)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::{grants_select, MongoPrivileges, UserPrivileges};
+    use crate::stmt::MongoStatement;
+    use mongodb::bson::{doc, Bson};
+
+    #[test]
+    fn metadata_size() {
+        assert_eq!(
+            7,
+            MongoPrivileges::empty(false)
+                .get_resultset_metadata(None)
+                .len()
+        );
+        assert_eq!(
+            8,
+            MongoPrivileges::empty(true)
+                .get_resultset_metadata(None)
+                .len()
+        );
+    }
+
+    #[test]
+    fn find_action_grants_select() {
+        let privilege = |resource| doc! {"resource": resource, "actions": ["find", "listIndexes"]};
+        assert!(grants_select(
+            &privilege(doc! {"db": "db", "collection": "coll"}),
+            "db",
+            "coll"
+        ));
+        assert!(grants_select(
+            &privilege(doc! {"db": "db", "collection": ""}),
+            "db",
+            "coll"
+        ));
+        assert!(grants_select(
+            &privilege(doc! {"db": "", "collection": "coll"}),
+            "db",
+            "coll"
+        ));
+        assert!(grants_select(
+            &privilege(doc! {"anyResource": true}),
+            "db",
+            "system.views"
+        ));
+        assert!(!grants_select(
+            &privilege(doc! {"db": "db", "collection": ""}),
+            "db",
+            "system.views"
+        ));
+        assert!(!grants_select(
+            &privilege(doc! {"db": "other", "collection": ""}),
+            "db",
+            "coll"
+        ));
+        assert!(!grants_select(
+            &privilege(doc! {"cluster": true}),
+            "db",
+            "coll"
+        ));
+        assert!(!grants_select(
+            &doc! {"resource": {"db": "db", "collection": ""}, "actions": ["insert"]},
+            "db",
+            "coll"
+        ));
+    }
+
+    #[test]
+    fn privilege_rows() {
+        let user_privileges = UserPrivileges {
+            grantees: vec!["alice".to_string(), "bob".to_string()],
+            privileges: vec![
+                doc! {"resource": {"db": "db", "collection": "coll"}, "actions": ["find"]},
+            ],
+        };
+        assert!(user_privileges.rows("db", "other", None).is_empty());
+
+        let mut table_privileges =
+            MongoPrivileges::new(user_privileges.rows("db", "coll", None), false, 1);
+        assert!(table_privileges.next(None).unwrap().0);
+        assert_eq!(
+            vec![
+                Bson::from("db"),
+                Bson::Null,
+                Bson::from("coll"),
+                Bson::Null,
+                Bson::from("alice"),
+                Bson::from("SELECT"),
+                Bson::Null,
+            ],
+            (1..=7)
+                .map(|col| table_privileges.get_value(col, None).unwrap().unwrap())
+                .collect::<Vec<Bson>>()
+        );
+        // SQL_ATTR_MAX_ROWS is 1.
+        assert!(!table_privileges.next(None).unwrap().0);
+
+        let mut column_privileges =
+            MongoPrivileges::new(user_privileges.rows("db", "coll", Some("a")), true, 0);
+        assert!(column_privileges.next(None).unwrap().0);
+        assert!(column_privileges.next(None).unwrap().0);
+        assert_eq!(
+            vec![
+                Bson::from("db"),
+                Bson::Null,
+                Bson::from("coll"),
+                Bson::from("a"),
+                Bson::Null,
+                Bson::from("bob"),
+                Bson::from("SELECT"),
+                Bson::Null,
+            ],
+            (1..=8)
+                .map(|col| column_privileges.get_value(col, None).unwrap().unwrap())
+                .collect::<Vec<Bson>>()
+        );
+        assert!(column_privileges.get_value(9, None).is_err());
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
         TranslateCommandResponse,
     },
     stmt::MongoStatement,
-    Error, TypeMode,
+    Cancellation, Error, TypeMode,
 };
 use constants::SQL_SCHEMAS_COLLECTION;
 use futures::TryStreamExt;
@@ -38,6 +38,26 @@ pub struct MongoQuery {
     pub pipeline: Vec<Document>,
     // The query timeout
     pub query_timeout: Option<u32>,
+    // Interrupts the aggregation and the getMore commands when the statement is cancelled.
+    cancellation: Cancellation,
+    // The number of rows read from the cursor, and whether the cursor reached the end of the
+    // result set.
+    rows_read: usize,
+    exhausted: bool,
+    // The schemas of the collections the query reads, used to translate the query again when it
+    // is rebound. Only used in Enterprise mode.
+    schema_catalog: Option<Document>,
+}
+
+// Returns true if the error is the server interrupting a command killed by SQLCancel.
+fn is_interrupted(e: &mongodb::error::Error) -> bool {
+    matches!(
+        *e.kind,
+        ErrorKind::Command(CommandError {
+            code: 11601, // interrupted
+            ..
+        })
+    )
 }
 
 impl MongoQuery {
@@ -53,13 +73,12 @@ impl MongoQuery {
         }
     }
 
-    fn translate_sql(
-        sql_query: &str,
+    fn get_schema_catalog(
         current_db: &String,
         namespaces: BTreeSet<Namespace>,
         client: &MongoConnection,
         db: &Database,
-    ) -> Result<TranslateCommandResponse> {
+    ) -> Result<Document> {
         let schema_collection = db.collection::<Document>(SQL_SCHEMAS_COLLECTION);
 
         let collection_names = namespaces
@@ -145,19 +164,48 @@ impl MongoQuery {
             ));
         }
 
+        Ok(schema_catalog_doc)
+    }
+
+    fn translate_sql(
+        sql_query: &str,
+        current_db: &String,
+        schema_catalog: Document,
+    ) -> Result<(Vec<Document>, Option<String>, ResultSetSchema)> {
         let command = Translate::new(
             sql_query.to_string(),
             current_db.to_string(),
-            schema_catalog_doc,
+            schema_catalog,
         );
 
         let command_response = libmongosqltranslate_run_command(command)?;
 
-        if let CommandResponse::Translate(response) = command_response {
-            Ok(response)
-        } else {
-            unreachable!()
+        let mongosql_translation: TranslateCommandResponse =
+            if let CommandResponse::Translate(response) = command_response {
+                response
+            } else {
+                unreachable!()
+            };
+
+        let mut pipeline: Vec<Document> = Vec::new();
+
+        for bson_doc in mongosql_translation
+            .pipeline
+            .as_array()
+            .ok_or(Error::TranslationPipelineNotArray)?
+            .iter()
+        {
+            match bson_doc.as_document() {
+                None => return Err(Error::TranslationPipelineArrayContainsNonDocument),
+                Some(doc) => pipeline.push(doc.to_owned()),
+            }
         }
+
+        Ok((
+            pipeline,
+            mongosql_translation.target_collection,
+            mongosql_translation.result_set_schema,
+        ))
     }
 
     // Create a MongoQuery with only the resultset_metadata.
@@ -172,6 +220,7 @@ impl MongoQuery {
         let working_db = current_db.as_ref().ok_or(Error::NoDatabase)?;
         let db = client.client.database(working_db);
 
+        let mut schema_catalog = None;
         let (pipeline, current_collection, result_set_schema) = match client.cluster_type {
             MongoClusterType::AtlasDataFederation => {
                 // 1. Run the sqlGetResultSchema command to get the result set
@@ -208,28 +257,10 @@ impl MongoQuery {
                     Self::get_sql_query_namespaces(query, working_db)?;
 
                 // Translate sql
-                let mongosql_translation =
-                    Self::translate_sql(query, working_db, namespaces, client, &db)?;
-
-                let mut pipeline: Vec<Document> = Vec::new();
-
-                for bson_doc in mongosql_translation
-                    .pipeline
-                    .as_array()
-                    .ok_or(Error::TranslationPipelineNotArray)?
-                    .iter()
-                {
-                    match bson_doc.as_document() {
-                        None => return Err(Error::TranslationPipelineArrayContainsNonDocument),
-                        Some(doc) => pipeline.push(doc.to_owned()),
-                    }
-                }
-
-                (
-                    pipeline,
-                    mongosql_translation.target_collection,
-                    mongosql_translation.result_set_schema,
-                )
+                let catalog = Self::get_schema_catalog(working_db, namespaces, client, &db)?;
+                let translation = Self::translate_sql(query, working_db, catalog.clone())?;
+                schema_catalog = Some(catalog);
+                translation
             }
             MongoClusterType::Community | MongoClusterType::UnknownTarget => {
                 // On connection, these types should get caught and throw an error.
@@ -248,8 +279,56 @@ impl MongoQuery {
             current_collection,
             pipeline,
             query_timeout,
+            cancellation: Cancellation::default(),
+            rows_read: 0,
+            exhausted: false,
+            schema_catalog,
         })
     }
+
+    // Create a MongoQuery for `query` with the result set metadata of this query. `query` must
+    // only differ from the prepared query by the values of its literals, like the query of another
+    // parameter set, so that it can be translated without fetching the schemas again.
+    pub fn rebind(&self, query: &str) -> Result<Self> {
+        let (pipeline, current_collection) = match self.schema_catalog.as_ref() {
+            Some(schema_catalog) => {
+                let working_db = self.current_db.as_ref().ok_or(Error::NoDatabase)?;
+                let (pipeline, current_collection, _) =
+                    Self::translate_sql(query, working_db, schema_catalog.clone())?;
+                (pipeline, current_collection)
+            }
+            None => (
+                vec![doc! {"$sql": {
+                    "statement": query,
+                }}],
+                None,
+            ),
+        };
+
+        Ok(self.with_pipeline(pipeline, current_collection))
+    }
+
+    // Create a MongoQuery for the same query as this one, which can be executed separately. Unlike
+    // rebind, the query is not translated again.
+    pub fn unexecuted_copy(&self) -> Self {
+        self.with_pipeline(self.pipeline.clone(), self.current_collection.clone())
+    }
+
+    fn with_pipeline(&self, pipeline: Vec<Document>, current_collection: Option<String>) -> Self {
+        Self {
+            resultset_cursor: None,
+            resultset_metadata: self.resultset_metadata.clone(),
+            current: None,
+            current_db: self.current_db.clone(),
+            current_collection,
+            pipeline,
+            query_timeout: self.query_timeout,
+            cancellation: Cancellation::default(),
+            rows_read: 0,
+            exhausted: false,
+            schema_catalog: self.schema_catalog.clone(),
+        }
+    }
 }
 
 impl MongoStatement for MongoQuery {
@@ -257,17 +336,32 @@ impl MongoStatement for MongoQuery {
     // Return true if moving was successful, false otherwise.
     // This method deserializes the current row and stores it in self.
     fn next(&mut self, connection: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        self.cancellation.check()?;
+        let cancellation = &self.cancellation;
         let guard = connection.unwrap().runtime.enter();
         let res = self
             .resultset_cursor
             .as_mut()
             .map_or(Err(Error::StatementNotExecuted), |c| {
-                connection
-                    .unwrap()
-                    .runtime
-                    .block_on(async { c.advance().await.map_err(Error::QueryCursorUpdate) })
-            })?;
+                // Advancing the cursor may run a getMore, which stops waiting for the server
+                // as soon as the statement is cancelled.
+                connection.unwrap().runtime.block_on(async {
+                    tokio::select! {
+                        res = c.advance() => res.map_err(|e| if is_interrupted(&e) {
+                            Error::QueryCancelled
+                        } else {
+                            Error::QueryCursorUpdate(e)
+                        }),
+                        _ = cancellation.cancelled() => Err(Error::QueryCancelled),
+                    }
+                })
+            });
         drop(guard);
+        // A cancelled cursor may be in the middle of a getMore, so it is closed.
+        if let Err(Error::QueryCancelled) = res {
+            self.close_cursor();
+        }
+        let res = res?;
         // Cursor::advance must return Ok(true) before Cursor::deserialize_current can be invoked.
         // Calling Cursor::deserialize_current after Cursor::advance does not return true or without
         // calling Cursor::advance at all may result in a panic
@@ -279,8 +373,10 @@ impl MongoStatement for MongoQuery {
                     .deserialize_current()
                     .map_err(Error::QueryCursorUpdate)?,
             );
+            self.rows_read += 1;
         } else {
             self.current = None;
+            self.exhausted = true;
         }
 
         Ok((res, vec![]))
@@ -307,21 +403,28 @@ impl MongoStatement for MongoQuery {
     // Execute the $sql aggregation for the query and initialize the result set
     // cursor. If there is a timeout, the query must finish before the timeout
     // or an error is returned.
+    // If max_rows is not 0, the server returns at most max_rows rows.
     fn execute(
         &mut self,
         connection: &MongoConnection,
         stmt_id: Bson,
         rowset_size: u32,
+        max_rows: u64,
     ) -> Result<bool> {
         let current_db = self.current_db.as_ref().ok_or(Error::NoDatabase)?;
         let db = connection.client.database(current_db);
 
+        let mut pipeline = self.pipeline.to_owned();
+        if max_rows > 0 {
+            pipeline.push(doc! {"$limit": i64::try_from(max_rows).unwrap_or(i64::MAX)});
+        }
+
         let collection;
         let mut aggregate = if let Some(c_name) = self.current_collection.as_ref() {
             collection = db.collection::<Document>(c_name);
-            collection.aggregate(self.pipeline.to_owned())
+            collection.aggregate(pipeline)
         } else {
-            db.aggregate(self.pipeline.to_owned())
+            db.aggregate(pipeline)
         };
 
         aggregate = aggregate.comment(stmt_id);
@@ -340,20 +443,27 @@ impl MongoStatement for MongoQuery {
 
         // handle an error coming back from execution; if it was cancelled, throw a specific error to
         // denote this to the program, otherwise return a generic query execution error
-        let map_query_error = |e: mongodb::error::Error| match *e.kind {
-            ErrorKind::Command(CommandError {
-                code: 11601, // interrupted
-                ..
-            }) => Error::QueryCancelled,
-            _ => Error::QueryExecutionFailed(e),
+        let map_query_error = |e: mongodb::error::Error| {
+            if is_interrupted(&e) {
+                Error::QueryCancelled
+            } else {
+                Error::QueryExecutionFailed(e)
+            }
         };
 
+        self.cancellation.check()?;
+        let cancellation = &self.cancellation;
         let _guard = connection.runtime.enter();
-        let cursor: Cursor<Document> = connection
-            .runtime
-            .block_on(async { aggregate.await.map_err(map_query_error) })?;
+        let cursor: Cursor<Document> = connection.runtime.block_on(async {
+            tokio::select! {
+                res = aggregate => res.map_err(map_query_error),
+                _ = cancellation.cancelled() => Err(Error::QueryCancelled),
+            }
+        })?;
 
         self.resultset_cursor = Some(cursor);
+        self.rows_read = 0;
+        self.exhausted = false;
         Ok(true)
     }
 
@@ -362,4 +472,12 @@ impl MongoStatement for MongoQuery {
         self.current = None;
         self.resultset_cursor = None;
     }
+
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    fn get_row_count(&self) -> Option<usize> {
+        self.exhausted.then_some(self.rows_read)
+    }
 }
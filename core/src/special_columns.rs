@@ -0,0 +1,234 @@
+use crate::{
+    col_metadata::MongoColMetadata,
+    conn::MongoConnection,
+    err::{Error, Result},
+    fields::get_collection_schema,
+    stmt::MongoStatement,
+    BsonTypeInfo, MongoFields, TypeMode,
+};
+use constants::{SQL_PC_NOT_PSEUDO, SQL_SCOPE_SESSION};
+use definitions::Nullability;
+use mongodb::bson::Bson;
+use once_cell::sync::OnceCell;
+
+static SPECIAL_COLUMNS_METADATA: OnceCell<Vec<MongoColMetadata>> = OnceCell::new();
+
+#[derive(Debug)]
+pub struct MongoSpecialColumns {
+    // The metadata of the _id field, if the collection has one that can identify its rows.
+    id_metadata: Option<MongoColMetadata>,
+    // Whether next has already returned the _id row.
+    returned: bool,
+    odbc_3_types: bool,
+}
+
+// Statement related to a SQLSpecialColumns call.
+impl MongoSpecialColumns {
+    // Create a new MongoStatement returning _id as the best row identifier of the collection,
+    // when the schema of the collection has an _id field.
+    // A nullable _id is only returned if allow_nullable is true.
+    #[allow(clippy::too_many_arguments)]
+    pub fn best_row_id(
+        mongo_connection: &MongoConnection,
+        db_name: &str,
+        collection_name: &str,
+        allow_nullable: bool,
+        type_mode: TypeMode,
+        max_string_length: Option<u16>,
+        odbc_3_types: bool,
+    ) -> Result<Self> {
+        let schema = mongo_connection.runtime.block_on(get_collection_schema(
+            mongo_connection,
+            db_name,
+            collection_name,
+        ));
+        let columns = match schema {
+            Ok(schema) => schema.process_collection_metadata(
+                db_name,
+                collection_name,
+                type_mode,
+                max_string_length,
+            )?,
+            // A collection without a schema has no known _id.
+            Err(Error::SchemaDocumentNotFoundInSchemaCollection(_)) => vec![],
+            Err(error) => return Err(error),
+        };
+        Ok(MongoSpecialColumns {
+            id_metadata: columns.into_iter().find(|column| {
+                column.col_name == "_id"
+                    && (allow_nullable || column.nullability == Nullability::SQL_NO_NULLS)
+            }),
+            returned: false,
+            odbc_3_types,
+        })
+    }
+
+    // Statement for SQL_ROWVER: no column is automatically updated when a document changes.
+    pub fn empty() -> MongoSpecialColumns {
+        MongoSpecialColumns {
+            id_metadata: None,
+            returned: false,
+            odbc_3_types: true,
+        }
+    }
+}
+
+impl MongoStatement for MongoSpecialColumns {
+    // Move the cursor to the _id row, if there is one.
+    // Return true if moving was successful, false otherwise.
+    fn next(&mut self, _: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        let next = !self.returned && self.id_metadata.is_some();
+        self.returned = true;
+        Ok((next, vec![]))
+    }
+
+    // Get the BSON value for the given colIndex on the _id row.
+    // Fails if the first row as not been retrieved (next must be called at least once before getValue).
+    fn get_value(&self, col_index: u16, _: Option<u16>) -> Result<Option<Bson>> {
+        let id = self
+            .id_metadata
+            .as_ref()
+            .filter(|_| self.returned)
+            .ok_or(Error::InvalidCursorState)?;
+        Ok(Some(match col_index {
+            // SCOPE: _id identifies a document for as long as it exists.
+            1 => Bson::Int32(i32::from(SQL_SCOPE_SESSION)),
+            // COLUMN_NAME
+            2 => Bson::String(id.col_name.clone()),
+            // DATA_TYPE
+            3 => Bson::Int32(
+                MongoFields::map_type_for_odbc_version(self.odbc_3_types, id.sql_type) as i32,
+            ),
+            // TYPE_NAME
+            4 => Bson::String(id.type_name.clone()),
+            // COLUMN_SIZE
+            5 => id
+                .column_size
+                .map_or(Bson::Int32(definitions::SQL_NO_TOTAL), |size| {
+                    Bson::Int32(i32::from(size))
+                }),
+            // BUFFER_LENGTH
+            6 => id
+                .transfer_octet_length
+                .map_or(Bson::Int32(definitions::SQL_NO_TOTAL), |length| {
+                    Bson::Int32(i32::from(length))
+                }),
+            // DECIMAL_DIGITS
+            7 => id
+                .decimal_digits
+                .map_or(Bson::Null, |digits| Bson::Int32(i32::from(digits))),
+            // PSEUDO_COLUMN
+            8 => Bson::Int32(i32::from(SQL_PC_NOT_PSEUDO)),
+            _ => return Err(Error::ColIndexOutOfBounds(col_index)),
+        }))
+    }
+
+    fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+        SPECIAL_COLUMNS_METADATA.get_or_init(|| {
+            [
+                ("SCOPE", BsonTypeInfo::INT, Nullability::SQL_NULLABLE),
+                (
+                    "COLUMN_NAME",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                ("DATA_TYPE", BsonTypeInfo::INT, Nullability::SQL_NO_NULLS),
+                ("TYPE_NAME", BsonTypeInfo::STRING, Nullability::SQL_NO_NULLS),
+                ("COLUMN_SIZE", BsonTypeInfo::INT, Nullability::SQL_NULLABLE),
+                (
+                    "BUFFER_LENGTH",
+                    BsonTypeInfo::INT,
+                    Nullability::SQL_NULLABLE,
+                ),
+                (
+                    "DECIMAL_DIGITS",
+                    BsonTypeInfo::INT,
+                    Nullability::SQL_NULLABLE,
+                ),
+                (
+                    "PSEUDO_COLUMN",
+                    BsonTypeInfo::INT,
+                    Nullability::SQL_NULLABLE,
+                ),
+            ]
+            .into_iter()
+            .map(|(col_name, type_info, nullability)| {
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    col_name.to_string(),
+                    type_info,
+                    max_string_length,
+                    nullability,
+                )
+            })
+            .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::MongoSpecialColumns;
+    use crate::{
+        json_schema::{
+            simplified::{Atomic, Schema},
+            BsonTypeName,
+        },
+        stmt::MongoStatement,
+        MongoColMetadata, TypeMode,
+    };
+    use definitions::{Nullability, SqlDataType};
+    use mongodb::bson::Bson;
+
+    #[test]
+    fn metadata_size() {
+        assert_eq!(
+            8,
+            MongoSpecialColumns::empty()
+                .get_resultset_metadata(None)
+                .len()
+        );
+    }
+
+    #[test]
+    fn rowver_is_empty() {
+        let mut special_columns = MongoSpecialColumns::empty();
+        assert!(!special_columns.next(None).unwrap().0);
+        assert!(special_columns.get_value(2, None).is_err());
+    }
+
+    #[test]
+    fn best_row_id() {
+        let mut special_columns = MongoSpecialColumns {
+            id_metadata: Some(MongoColMetadata::new(
+                "db",
+                "coll".to_string(),
+                "_id".to_string(),
+                Schema::Atomic(Atomic::Scalar(BsonTypeName::ObjectId)),
+                Nullability::SQL_NO_NULLS,
+                TypeMode::Standard,
+                None,
+            )),
+            returned: false,
+            odbc_3_types: true,
+        };
+        assert!(special_columns.next(None).unwrap().0);
+        assert_eq!(
+            vec![
+                Bson::Int32(2),
+                Bson::String("_id".to_string()),
+                Bson::Int32(SqlDataType::SQL_UNKNOWN_TYPE as i32),
+                Bson::String("objectId".to_string()),
+                Bson::Int32(24),
+                Bson::Int32(24),
+                Bson::Null,
+                Bson::Int32(1),
+            ],
+            (1..=8)
+                .map(|col| special_columns.get_value(col, None).unwrap().unwrap())
+                .collect::<Vec<Bson>>()
+        );
+        assert!(!special_columns.next(None).unwrap().0);
+    }
+}
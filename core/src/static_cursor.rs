@@ -0,0 +1,539 @@
+use crate::{
+    col_metadata::MongoColMetadata,
+    conn::MongoConnection,
+    err::{Error, Result},
+    stmt::{MongoStatement, ScrollResult},
+    Cancellation,
+};
+use definitions::FetchOrientation;
+use mongodb::bson::{Bson, Document};
+use std::{
+    fs::File,
+    io::{BufReader, Seek, SeekFrom},
+};
+
+// A position of the cursor. Rows are numbered from 1, as per the ODBC spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    BeforeStart,
+    Row(usize),
+    AfterEnd,
+}
+
+// MongoStaticCursor makes the result set of another statement scrollable. Rows are read from the
+// underlying statement the first time the cursor reaches them and buffered, so that the cursor
+// can move back to them. Each buffered row is a Document keyed by column index, where a missing
+// key is a missing value.
+#[derive(Debug)]
+pub struct MongoStaticCursor {
+    // The statement whose result set is buffered.
+    source: Box<dyn MongoStatement>,
+    // The maximum string length used to read the result set metadata of source.
+    max_string_length: Option<u16>,
+    // The number of rows kept in memory. Any further rows are written to spill_file.
+    spill_threshold: usize,
+    // The first spill_threshold rows of the result set.
+    rows: Vec<Document>,
+    // The temporary file holding the rows past spill_threshold, and the offset of each of them.
+    spill_file: Option<File>,
+    spill_offsets: Vec<u64>,
+    // Whether every row of source has been buffered.
+    exhausted: bool,
+    // Warnings raised while buffering rows, returned by the next call to next.
+    warnings: Vec<Error>,
+    // The current row and its values.
+    position: Position,
+    current: Option<Document>,
+    // The first row of the current rowset.
+    rowset_start: Position,
+}
+
+impl MongoStaticCursor {
+    pub fn new(
+        source: Box<dyn MongoStatement>,
+        max_string_length: Option<u16>,
+        spill_threshold: usize,
+    ) -> Self {
+        MongoStaticCursor {
+            source,
+            max_string_length,
+            spill_threshold,
+            rows: vec![],
+            spill_file: None,
+            spill_offsets: vec![],
+            exhausted: false,
+            warnings: vec![],
+            position: Position::BeforeStart,
+            current: None,
+            rowset_start: Position::BeforeStart,
+        }
+    }
+
+    // Returns whether some rows of the result set were written to disk.
+    pub fn has_spilled(&self) -> bool {
+        !self.spill_offsets.is_empty()
+    }
+
+    // Drops every buffered row and moves the cursor back before the start of the result set.
+    fn reset(&mut self) {
+        self.rows = vec![];
+        self.spill_file = None;
+        self.spill_offsets = vec![];
+        self.exhausted = false;
+        self.warnings = vec![];
+        self.position = Position::BeforeStart;
+        self.current = None;
+        self.rowset_start = Position::BeforeStart;
+    }
+
+    fn buffered_rows(&self) -> usize {
+        self.rows.len() + self.spill_offsets.len()
+    }
+
+    // Reads rows from source until `row` rows are buffered or source has no more rows.
+    fn buffer_rows(&mut self, connection: Option<&MongoConnection>, row: usize) -> Result<()> {
+        while !self.exhausted && self.buffered_rows() < row {
+            let (has_next, mut warnings) = self.source.next(connection)?;
+            self.warnings.append(&mut warnings);
+            if !has_next {
+                self.exhausted = true;
+                break;
+            }
+            let column_count = self
+                .source
+                .get_resultset_metadata(self.max_string_length)
+                .len();
+            let mut values = Document::new();
+            for col_index in 1..=column_count {
+                let col_index =
+                    u16::try_from(col_index).map_err(|_| Error::ColIndexOutOfBounds(u16::MAX))?;
+                if let Some(value) = self.source.get_value(col_index, self.max_string_length)? {
+                    values.insert(col_index.to_string(), value);
+                }
+            }
+            self.store_row(values)?;
+        }
+        Ok(())
+    }
+
+    fn store_row(&mut self, values: Document) -> Result<()> {
+        if self.rows.len() < self.spill_threshold {
+            self.rows.push(values);
+            return Ok(());
+        }
+        let spill_file = match self.spill_file.as_mut() {
+            Some(file) => file,
+            None => self
+                .spill_file
+                .insert(tempfile::tempfile().map_err(|e| Error::StaticCursorSpill(e.to_string()))?),
+        };
+        let offset = spill_file
+            .seek(SeekFrom::End(0))
+            .map_err(|e| Error::StaticCursorSpill(e.to_string()))?;
+        values
+            .to_writer(spill_file)
+            .map_err(|e| Error::StaticCursorSpill(e.to_string()))?;
+        self.spill_offsets.push(offset);
+        Ok(())
+    }
+
+    // Returns the values of a buffered row.
+    fn load_row(&mut self, row: usize) -> Result<Document> {
+        if row <= self.rows.len() {
+            return Ok(self.rows[row - 1].clone());
+        }
+        let offset = self.spill_offsets[row - self.rows.len() - 1];
+        let spill_file = self.spill_file.as_mut().ok_or(Error::InvalidCursorState)?;
+        spill_file
+            .seek(SeekFrom::Start(offset))
+            .map_err(|e| Error::StaticCursorSpill(e.to_string()))?;
+        Document::from_reader(BufReader::new(spill_file))
+            .map_err(|e| Error::StaticCursorSpill(e.to_string()))
+    }
+
+    // Returns the number of rows in the result set, buffering all of them.
+    fn row_count(&mut self, connection: Option<&MongoConnection>) -> Result<usize> {
+        self.buffer_rows(connection, usize::MAX)?;
+        Ok(self.buffered_rows())
+    }
+
+    // Returns the start of the rowset for SQL_FETCH_ABSOLUTE with the given offset, and whether it
+    // had to be moved to the first row.
+    fn absolute_start(
+        &mut self,
+        connection: Option<&MongoConnection>,
+        offset: isize,
+        rowset_size: usize,
+    ) -> Result<(Position, bool)> {
+        Ok(match offset {
+            0 => (Position::BeforeStart, false),
+            1.. => (Position::Row(offset.unsigned_abs()), false),
+            _ => {
+                let last = self.row_count(connection)?;
+                let distance = offset.unsigned_abs();
+                if distance <= last {
+                    (Position::Row(last - distance + 1), false)
+                } else if distance > rowset_size {
+                    (Position::BeforeStart, false)
+                } else {
+                    (Position::Row(1), true)
+                }
+            }
+        })
+    }
+}
+
+impl MongoStatement for MongoStaticCursor {
+    // Move the cursor to the next row, reading it from source if it was not buffered yet.
+    // Return true if moving was successful, false otherwise.
+    fn next(&mut self, connection: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        let row = match self.position {
+            Position::BeforeStart => 1,
+            Position::Row(row) => row + 1,
+            Position::AfterEnd => return Ok((false, std::mem::take(&mut self.warnings))),
+        };
+        self.buffer_rows(connection, row)?;
+        if row <= self.buffered_rows() {
+            self.current = Some(self.load_row(row)?);
+            self.position = Position::Row(row);
+        } else {
+            self.current = None;
+            self.position = Position::AfterEnd;
+        }
+        Ok((
+            self.position != Position::AfterEnd,
+            std::mem::take(&mut self.warnings),
+        ))
+    }
+
+    // Get the BSON value for the cell at the given colIndex on the current row.
+    // Fails if the cursor is not on a row.
+    fn get_value(&self, col_index: u16, max_string_length: Option<u16>) -> Result<Option<Bson>> {
+        let current = self.current.as_ref().ok_or(Error::InvalidCursorState)?;
+        self.get_col_metadata(col_index, max_string_length)?;
+        Ok(current.get(col_index.to_string()).cloned())
+    }
+
+    fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+        self.source.get_resultset_metadata(max_string_length)
+    }
+
+    // The row count is known once every row of source is buffered.
+    fn get_row_count(&self) -> Option<usize> {
+        self.exhausted.then(|| self.buffered_rows())
+    }
+
+    // Get the number of the current row, which stays valid as the cursor scrolls.
+    fn get_bookmark(&self) -> Result<usize> {
+        match self.position {
+            Position::Row(row) if self.current.is_some() => Ok(row),
+            _ => Err(Error::InvalidCursorState),
+        }
+    }
+
+    // Execute source again. The rows of the previous execution are dropped.
+    fn execute(
+        &mut self,
+        connection: &MongoConnection,
+        stmt_id: Bson,
+        rowset_size: u32,
+        max_rows: u64,
+    ) -> Result<bool> {
+        self.reset();
+        self.source
+            .execute(connection, stmt_id, rowset_size, max_rows)
+    }
+
+    // Close the cursor of source and drop every buffered row.
+    fn close_cursor(&mut self) {
+        self.source.close_cursor();
+        self.reset();
+    }
+
+    // Rows are read from source, so it is the statement that is cancelled.
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.source.set_cancellation(cancellation);
+    }
+
+    // Compute the first row of the new rowset following the cursor positioning rules of
+    // SQLFetchScroll, and move the cursor right before it.
+    fn scroll(
+        &mut self,
+        connection: Option<&MongoConnection>,
+        orientation: FetchOrientation,
+        offset: isize,
+        rowset_size: usize,
+    ) -> Result<ScrollResult> {
+        let rowset_size = rowset_size.max(1);
+        let (start, moved_to_first_row) = match orientation {
+            FetchOrientation::SQL_FETCH_NEXT => match self.rowset_start {
+                Position::BeforeStart => (Position::Row(1), false),
+                Position::Row(start) => (Position::Row(start + rowset_size), false),
+                Position::AfterEnd => (Position::AfterEnd, false),
+            },
+            FetchOrientation::SQL_FETCH_PRIOR => match self.rowset_start {
+                Position::BeforeStart | Position::Row(1) => (Position::BeforeStart, false),
+                Position::Row(start) if start <= rowset_size => (Position::Row(1), true),
+                Position::Row(start) => (Position::Row(start - rowset_size), false),
+                Position::AfterEnd => {
+                    let last = self.row_count(connection)?;
+                    if last < rowset_size {
+                        (Position::Row(1), true)
+                    } else {
+                        (Position::Row(last - rowset_size + 1), false)
+                    }
+                }
+            },
+            FetchOrientation::SQL_FETCH_RELATIVE => match self.rowset_start {
+                Position::BeforeStart if offset > 0 => {
+                    self.absolute_start(connection, offset, rowset_size)?
+                }
+                Position::AfterEnd if offset < 0 => {
+                    self.absolute_start(connection, offset, rowset_size)?
+                }
+                Position::BeforeStart => (Position::BeforeStart, false),
+                Position::AfterEnd => (Position::AfterEnd, false),
+                Position::Row(start) => match start.checked_add_signed(offset) {
+                    Some(start) if start >= 1 => (Position::Row(start), false),
+                    _ if offset.unsigned_abs() > rowset_size => (Position::BeforeStart, false),
+                    _ => (Position::Row(1), true),
+                },
+            },
+            FetchOrientation::SQL_FETCH_ABSOLUTE => {
+                self.absolute_start(connection, offset, rowset_size)?
+            }
+            // The offset of SQL_FETCH_BOOKMARK is the bookmarked row plus the fetch offset.
+            FetchOrientation::SQL_FETCH_BOOKMARK => match offset {
+                1.. => (Position::Row(offset.unsigned_abs()), false),
+                _ => (Position::BeforeStart, false),
+            },
+            FetchOrientation::SQL_FETCH_FIRST => (Position::Row(1), false),
+            FetchOrientation::SQL_FETCH_LAST => {
+                let last = self.row_count(connection)?;
+                (Position::Row(last.saturating_sub(rowset_size) + 1), false)
+            }
+            FetchOrientation::SQL_FETCH_FIRST_USER | FetchOrientation::SQL_FETCH_FIRST_SYSTEM => {
+                return Err(Error::UnsupportedOperation("scroll"))
+            }
+        };
+
+        // A rowset starting past the last row is after the end of the result set.
+        let start = match start {
+            Position::Row(row) => {
+                self.buffer_rows(connection, row)?;
+                if row <= self.buffered_rows() {
+                    Position::Row(row)
+                } else {
+                    Position::AfterEnd
+                }
+            }
+            position => position,
+        };
+
+        self.rowset_start = start;
+        self.current = None;
+        match start {
+            Position::Row(row) => {
+                self.position = if row == 1 {
+                    Position::BeforeStart
+                } else {
+                    Position::Row(row - 1)
+                };
+                Ok(if moved_to_first_row {
+                    ScrollResult::RowsetStartsAtFirstRow
+                } else {
+                    ScrollResult::Rowset
+                })
+            }
+            position => {
+                self.position = position;
+                Ok(ScrollResult::NoData)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::MongoStaticCursor;
+    use crate::{
+        col_metadata::MongoColMetadata,
+        json_schema::{
+            simplified::{Atomic, Schema},
+            BsonTypeName,
+        },
+        mock_query::MongoQuery,
+        stmt::{MongoStatement, ScrollResult},
+        TypeMode,
+    };
+    use definitions::{FetchOrientation, Nullability};
+    use mongodb::bson::{doc, Bson};
+
+    fn static_cursor(row_count: i32, spill_threshold: usize) -> MongoStaticCursor {
+        let metadata = vec![MongoColMetadata::new(
+            "",
+            "foo".to_string(),
+            "a".to_string(),
+            Schema::Atomic(Atomic::Scalar(BsonTypeName::Int)),
+            Nullability::SQL_NULLABLE,
+            TypeMode::Standard,
+            None,
+        )];
+        let rows = (1..=row_count).map(|a| doc! {"foo": {"a": a}}).collect();
+        MongoStaticCursor::new(
+            Box::new(MongoQuery::new(rows, metadata)),
+            None,
+            spill_threshold,
+        )
+    }
+
+    // Scrolls to a new rowset and returns the values of its rows.
+    fn fetch(
+        cursor: &mut MongoStaticCursor,
+        orientation: FetchOrientation,
+        offset: isize,
+        rowset_size: usize,
+    ) -> (ScrollResult, Vec<i32>) {
+        let result = cursor
+            .scroll(None, orientation, offset, rowset_size)
+            .unwrap();
+        let mut values = vec![];
+        if result != ScrollResult::NoData {
+            for _ in 0..rowset_size {
+                if !cursor.next(None).unwrap().0 {
+                    break;
+                }
+                match cursor.get_value(1, None).unwrap() {
+                    Some(Bson::Int32(a)) => values.push(a),
+                    value => panic!("unexpected value {value:?}"),
+                }
+            }
+        }
+        (result, values)
+    }
+
+    #[test]
+    fn next_reads_every_row() {
+        let mut cursor = static_cursor(3, 10);
+        for expected in 1..=3 {
+            assert!(cursor.next(None).unwrap().0);
+            assert_eq!(
+                Some(Bson::Int32(expected)),
+                cursor.get_value(1, None).unwrap()
+            );
+        }
+        assert_eq!(None, cursor.get_row_count());
+        assert!(!cursor.next(None).unwrap().0);
+        assert!(cursor.get_value(1, None).is_err());
+        assert_eq!(Some(3), cursor.get_row_count());
+    }
+
+    #[test]
+    fn scroll_positioning() {
+        use FetchOrientation::*;
+        let mut cursor = static_cursor(10, 100);
+        assert_eq!(
+            (ScrollResult::Rowset, vec![8, 9, 10]),
+            fetch(&mut cursor, SQL_FETCH_LAST, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![5, 6, 7]),
+            fetch(&mut cursor, SQL_FETCH_PRIOR, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![3, 4, 5]),
+            fetch(&mut cursor, SQL_FETCH_RELATIVE, -2, 3)
+        );
+        assert_eq!(
+            (ScrollResult::RowsetStartsAtFirstRow, vec![1, 2, 3]),
+            fetch(&mut cursor, SQL_FETCH_PRIOR, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::NoData, vec![]),
+            fetch(&mut cursor, SQL_FETCH_PRIOR, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![1, 2, 3]),
+            fetch(&mut cursor, SQL_FETCH_NEXT, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![9, 10]),
+            fetch(&mut cursor, SQL_FETCH_ABSOLUTE, -2, 3)
+        );
+        assert_eq!(
+            (ScrollResult::NoData, vec![]),
+            fetch(&mut cursor, SQL_FETCH_NEXT, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![6]),
+            fetch(&mut cursor, SQL_FETCH_ABSOLUTE, 6, 1)
+        );
+        assert_eq!(
+            (ScrollResult::NoData, vec![]),
+            fetch(&mut cursor, SQL_FETCH_ABSOLUTE, 11, 1)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![2, 3]),
+            fetch(&mut cursor, SQL_FETCH_ABSOLUTE, 2, 2)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![1, 2, 3]),
+            fetch(&mut cursor, SQL_FETCH_FIRST, 0, 3)
+        );
+        assert_eq!(
+            (ScrollResult::Rowset, vec![7, 8]),
+            fetch(&mut cursor, SQL_FETCH_BOOKMARK, 7, 2)
+        );
+        assert_eq!(
+            (ScrollResult::NoData, vec![]),
+            fetch(&mut cursor, SQL_FETCH_BOOKMARK, 0, 2)
+        );
+    }
+
+    #[test]
+    fn bookmarks_are_row_numbers() {
+        let mut cursor = static_cursor(5, 2);
+        assert!(cursor.get_bookmark().is_err());
+        assert_eq!(
+            ScrollResult::Rowset,
+            cursor
+                .scroll(None, FetchOrientation::SQL_FETCH_ABSOLUTE, 4, 1)
+                .unwrap()
+        );
+        assert!(cursor.next(None).unwrap().0);
+        assert_eq!(4, cursor.get_bookmark().unwrap());
+        assert!(MongoQuery::new(vec![], vec![]).get_bookmark().is_err());
+    }
+
+    #[test]
+    fn rows_past_threshold_are_spilled_to_disk() {
+        let mut cursor = static_cursor(5, 2);
+        assert_eq!(
+            (ScrollResult::Rowset, vec![4, 5]),
+            fetch(&mut cursor, FetchOrientation::SQL_FETCH_LAST, 0, 2)
+        );
+        assert!(cursor.has_spilled());
+        assert_eq!(
+            (ScrollResult::Rowset, vec![1, 2, 3, 4, 5]),
+            fetch(&mut cursor, FetchOrientation::SQL_FETCH_FIRST, 0, 5)
+        );
+
+        cursor.close_cursor();
+        assert!(!cursor.has_spilled());
+        assert!(!cursor.next(None).unwrap().0);
+    }
+
+    #[test]
+    fn forward_only_statements_cannot_scroll() {
+        let mut query = MongoQuery::new(vec![], vec![]);
+        assert_eq!(
+            ScrollResult::Rowset,
+            query
+                .scroll(None, FetchOrientation::SQL_FETCH_NEXT, 0, 1)
+                .unwrap()
+        );
+        assert!(query
+            .scroll(None, FetchOrientation::SQL_FETCH_LAST, 0, 1)
+            .is_err());
+    }
+}
@@ -0,0 +1,457 @@
+use crate::{
+    col_metadata::MongoColMetadata,
+    conn::MongoConnection,
+    err::{Error, Result},
+    stmt::MongoStatement,
+    BsonTypeInfo,
+};
+use constants::{SQL_INDEX_HASHED, SQL_INDEX_OTHER, SQL_TABLE_STAT};
+use definitions::Nullability;
+use futures::TryStreamExt;
+use mongodb::bson::{doc, Bson, Document};
+use once_cell::sync::OnceCell;
+
+static STATISTICS_METADATA: OnceCell<Vec<MongoColMetadata>> = OnceCell::new();
+
+// One row of the SQLStatistics result set: the statistics of the collection, or one column of
+// one of its indexes.
+#[derive(Debug, Clone, PartialEq)]
+struct StatisticsRow {
+    // NULL for the statistics of the collection.
+    non_unique: Option<bool>,
+    index_name: Option<String>,
+    stat_type: i16,
+    ordinal_position: Option<i32>,
+    column_name: Option<String>,
+    // "A" or "D", or NULL if the index is not sorted on the column.
+    asc_or_desc: Option<&'static str>,
+    cardinality: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct MongoStatistics {
+    database_name: String,
+    collection_name: String,
+    // The rows, ordered by NON_UNIQUE, TYPE, INDEX_NAME, and ORDINAL_POSITION.
+    rows: Vec<StatisticsRow>,
+    // The index of the current row.
+    current: Option<usize>,
+}
+
+// Statement related to a SQLStatistics call.
+impl MongoStatistics {
+    // Create a new MongoStatement to list the indexes of the given collection, from listIndexes.
+    // Only the unique indexes are listed if unique_only is true.
+    // The number of documents of the collection is read with $collStats if ensure is true,
+    // otherwise the CARDINALITY of the collection statistics is NULL.
+    // The maximum number of rows comes from the statement attribute SQL_ATTR_MAX_ROWS.
+    pub fn list_indexes(
+        mongo_connection: &MongoConnection,
+        db_name: &str,
+        collection_name: &str,
+        unique_only: bool,
+        ensure: bool,
+        max_rows: u64,
+    ) -> Self {
+        let db = mongo_connection.client.database(db_name);
+        let (indexes, cardinality) = mongo_connection.runtime.block_on(async {
+            let indexes = db
+                .run_command(doc! { "listIndexes": collection_name })
+                .await
+                .ok()
+                .and_then(|response| {
+                    response
+                        .get_document("cursor")
+                        .and_then(|cursor| cursor.get_array("firstBatch"))
+                        .map(|batch| {
+                            batch
+                                .iter()
+                                .filter_map(Bson::as_document)
+                                .cloned()
+                                .collect::<Vec<Document>>()
+                        })
+                        .ok()
+                })
+                .unwrap_or_else(|| {
+                    log::error!("Error getting indexes for collection {db_name}.{collection_name}");
+                    vec![]
+                });
+            let cardinality = match ensure {
+                true => match db
+                    .collection::<Document>(collection_name)
+                    .aggregate(vec![doc! { "$collStats": { "count": {} } }])
+                    .await
+                {
+                    // A sharded collection has one document per shard.
+                    Ok(cursor) => cursor
+                        .try_collect::<Vec<Document>>()
+                        .await
+                        .map(|stats| {
+                            stats
+                                .iter()
+                                .filter_map(|stat| stat.get("count").and_then(as_i64_lossy))
+                                .sum::<i64>()
+                        })
+                        .ok(),
+                    Err(_) => None,
+                },
+                false => None,
+            };
+            (indexes, cardinality)
+        });
+        let mut statistics =
+            MongoStatistics::new(db_name, collection_name, &indexes, unique_only, cardinality);
+        if max_rows > 0 {
+            statistics
+                .rows
+                .truncate(usize::try_from(max_rows).unwrap_or(usize::MAX));
+        }
+        statistics
+    }
+
+    fn new(
+        db_name: &str,
+        collection_name: &str,
+        indexes: &[Document],
+        unique_only: bool,
+        cardinality: Option<i64>,
+    ) -> Self {
+        let mut rows = vec![StatisticsRow {
+            non_unique: None,
+            index_name: None,
+            stat_type: SQL_TABLE_STAT,
+            ordinal_position: None,
+            column_name: None,
+            asc_or_desc: None,
+            cardinality: cardinality.map(|count| i32::try_from(count).unwrap_or(i32::MAX)),
+        }];
+        let mut index_rows: Vec<StatisticsRow> = indexes
+            .iter()
+            .flat_map(index_to_rows)
+            .filter(|row| !unique_only || row.non_unique == Some(false))
+            .collect();
+        index_rows.sort_by(|a, b| {
+            (a.non_unique, a.stat_type, &a.index_name, a.ordinal_position).cmp(&(
+                b.non_unique,
+                b.stat_type,
+                &b.index_name,
+                b.ordinal_position,
+            ))
+        });
+        rows.extend(index_rows);
+        MongoStatistics {
+            database_name: db_name.to_string(),
+            collection_name: collection_name.to_string(),
+            rows,
+            current: None,
+        }
+    }
+
+    pub fn empty() -> MongoStatistics {
+        MongoStatistics {
+            database_name: "".to_string(),
+            collection_name: "".to_string(),
+            rows: Vec::new(),
+            current: None,
+        }
+    }
+}
+
+// The column of an index key: like MongoFields, a nested field is part of the column of its
+// top-level field.
+fn key_to_column(key: &str) -> Option<&str> {
+    match key.split('.').next() {
+        Some(column) if !column.is_empty() && column != "$**" => Some(column),
+        _ => None,
+    }
+}
+
+// Map an index specification returned by listIndexes to one row per column of the index.
+fn index_to_rows(index: &Document) -> Vec<StatisticsRow> {
+    let index_name = index.get_str("name").unwrap_or_default().to_string();
+    // The _id index is unique, even though its specification does not say so.
+    let non_unique = !(index.get_bool("unique").unwrap_or(false) || index_name == "_id_");
+    let key = index.get_document("key").cloned().unwrap_or_default();
+    let stat_type = match key.values().any(|value| value.as_str() == Some("hashed")) {
+        true => SQL_INDEX_HASHED,
+        false => SQL_INDEX_OTHER,
+    };
+    // The key of a text index does not name the indexed fields, its weights do.
+    let keys: Vec<(String, Option<&'static str>)> = match index.get_document("weights") {
+        Ok(weights) => weights.keys().map(|field| (field.clone(), None)).collect(),
+        Err(_) => key
+            .iter()
+            .map(|(field, order)| {
+                let asc_or_desc = match as_i64_lossy(order) {
+                    Some(order) if order > 0 => Some("A"),
+                    Some(order) if order < 0 => Some("D"),
+                    _ => None,
+                };
+                (field.clone(), asc_or_desc)
+            })
+            .collect(),
+    };
+    let mut rows: Vec<StatisticsRow> = vec![];
+    for (field, asc_or_desc) in keys.iter() {
+        let Some(column) = key_to_column(field) else {
+            continue;
+        };
+        if rows
+            .iter()
+            .any(|row| row.column_name.as_deref() == Some(column))
+        {
+            continue;
+        }
+        rows.push(StatisticsRow {
+            non_unique: Some(non_unique),
+            index_name: Some(index_name.clone()),
+            stat_type,
+            ordinal_position: Some(i32::try_from(rows.len()).unwrap_or(i32::MAX) + 1),
+            column_name: Some(column.to_string()),
+            asc_or_desc: *asc_or_desc,
+            cardinality: None,
+        });
+    }
+    rows
+}
+
+// Index orders and collStats counts may be any numeric type.
+fn as_i64_lossy(value: &Bson) -> Option<i64> {
+    match value {
+        Bson::Int32(i) => Some(i64::from(*i)),
+        Bson::Int64(i) => Some(*i),
+        #[allow(clippy::cast_possible_truncation)]
+        Bson::Double(d) => Some(*d as i64),
+        _ => None,
+    }
+}
+
+impl MongoStatement for MongoStatistics {
+    // Move the cursor to the next row.
+    // Return true if moving was successful, false otherwise.
+    fn next(&mut self, _: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+        let next = self.current.map_or(0, |current| current + 1);
+        self.current = Some(next.min(self.rows.len()));
+        Ok((next < self.rows.len(), vec![]))
+    }
+
+    // Get the BSON value for the given colIndex on the current row.
+    // Fails if the first row as not been retrieved (next must be called at least once before getValue).
+    fn get_value(&self, col_index: u16, _: Option<u16>) -> Result<Option<Bson>> {
+        let row = self
+            .current
+            .and_then(|current| self.rows.get(current))
+            .ok_or(Error::InvalidCursorState)?;
+        let string_or_null = |value: Option<&str>| value.map_or(Bson::Null, Bson::from);
+        Ok(Some(match col_index {
+            // TABLE_CAT
+            1 => Bson::String(self.database_name.clone()),
+            // TABLE_SCHEM
+            2 => Bson::Null,
+            // TABLE_NAME
+            3 => Bson::String(self.collection_name.clone()),
+            // NON_UNIQUE
+            4 => row
+                .non_unique
+                .map_or(Bson::Null, |non_unique| Bson::Int32(i32::from(non_unique))),
+            // INDEX_QUALIFIER
+            5 => Bson::Null,
+            // INDEX_NAME
+            6 => string_or_null(row.index_name.as_deref()),
+            // TYPE
+            7 => Bson::Int32(i32::from(row.stat_type)),
+            // ORDINAL_POSITION
+            8 => row.ordinal_position.map_or(Bson::Null, Bson::Int32),
+            // COLUMN_NAME
+            9 => string_or_null(row.column_name.as_deref()),
+            // ASC_OR_DESC
+            10 => string_or_null(row.asc_or_desc),
+            // CARDINALITY
+            11 => row.cardinality.map_or(Bson::Null, Bson::Int32),
+            // PAGES
+            12 => Bson::Null,
+            // FILTER_CONDITION
+            13 => Bson::Null,
+            _ => return Err(Error::ColIndexOutOfBounds(col_index)),
+        }))
+    }
+
+    fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+        STATISTICS_METADATA.get_or_init(|| {
+            [
+                ("TABLE_CAT", BsonTypeInfo::STRING, Nullability::SQL_NULLABLE),
+                (
+                    "TABLE_SCHEM",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NULLABLE,
+                ),
+                (
+                    "TABLE_NAME",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NO_NULLS,
+                ),
+                ("NON_UNIQUE", BsonTypeInfo::INT, Nullability::SQL_NULLABLE),
+                (
+                    "INDEX_QUALIFIER",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NULLABLE,
+                ),
+                (
+                    "INDEX_NAME",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NULLABLE,
+                ),
+                ("TYPE", BsonTypeInfo::INT, Nullability::SQL_NO_NULLS),
+                (
+                    "ORDINAL_POSITION",
+                    BsonTypeInfo::INT,
+                    Nullability::SQL_NULLABLE,
+                ),
+                (
+                    "COLUMN_NAME",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NULLABLE,
+                ),
+                (
+                    "ASC_OR_DESC",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NULLABLE,
+                ),
+                ("CARDINALITY", BsonTypeInfo::INT, Nullability::SQL_NULLABLE),
+                ("PAGES", BsonTypeInfo::INT, Nullability::SQL_NULLABLE),
+                (
+                    "FILTER_CONDITION",
+                    BsonTypeInfo::STRING,
+                    Nullability::SQL_NULLABLE,
+                ),
+            ]
+            .into_iter()
+            .map(|(col_name, type_info, nullability)| {
+                MongoColMetadata::new_metadata_from_bson_type_info_default(
+                    "",
+                    "".to_string(),
+                    col_name.to_string(),
+                    type_info,
+                    max_string_length,
+                    nullability,
+                )
+            })
+            .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod unit {
+    use super::MongoStatistics;
+    use crate::stmt::MongoStatement;
+    use mongodb::bson::{doc, Bson};
+
+    fn rows(statistics: &mut MongoStatistics) -> Vec<Vec<Bson>> {
+        let mut rows = vec![];
+        while statistics.next(None).unwrap().0 {
+            rows.push(
+                (1..=13)
+                    .map(|col| statistics.get_value(col, None).unwrap().unwrap())
+                    .collect(),
+            );
+        }
+        rows
+    }
+
+    #[test]
+    fn metadata_size() {
+        assert_eq!(
+            13,
+            MongoStatistics::empty().get_resultset_metadata(None).len()
+        );
+    }
+
+    #[test]
+    fn indexes_to_rows() {
+        let indexes = vec![
+            doc! {"v": 2, "key": {"b": -1, "a.x": 1, "a.y": 1}, "name": "b_-1_a.x_1_a.y_1"},
+            doc! {"v": 2, "key": {"_id": 1}, "name": "_id_"},
+            doc! {"v": 2, "key": {"c": "hashed"}, "name": "c_hashed"},
+            doc! {"v": 2, "key": {"d": 1.0}, "name": "d_1", "unique": true},
+            doc! {"v": 2, "key": {"_fts": "text", "_ftsx": 1}, "name": "e_text", "weights": {"e": 1}},
+        ];
+        let row = |non_unique: Option<i32>,
+                   index_name: Option<&str>,
+                   stat_type: i32,
+                   ordinal_position: Option<i32>,
+                   column_name: Option<&str>,
+                   asc_or_desc: Option<&str>,
+                   cardinality: Option<i32>| {
+            vec![
+                Bson::from("db"),
+                Bson::Null,
+                Bson::from("coll"),
+                non_unique.map_or(Bson::Null, Bson::Int32),
+                Bson::Null,
+                index_name.map_or(Bson::Null, Bson::from),
+                Bson::Int32(stat_type),
+                ordinal_position.map_or(Bson::Null, Bson::Int32),
+                column_name.map_or(Bson::Null, Bson::from),
+                asc_or_desc.map_or(Bson::Null, Bson::from),
+                cardinality.map_or(Bson::Null, Bson::Int32),
+                Bson::Null,
+                Bson::Null,
+            ]
+        };
+        let expected_unique = vec![
+            row(None, None, 0, None, None, None, Some(10)),
+            row(
+                Some(0),
+                Some("_id_"),
+                3,
+                Some(1),
+                Some("_id"),
+                Some("A"),
+                None,
+            ),
+            row(Some(0), Some("d_1"), 3, Some(1), Some("d"), Some("A"), None),
+        ];
+        assert_eq!(
+            expected_unique,
+            rows(&mut MongoStatistics::new(
+                "db",
+                "coll",
+                &indexes,
+                true,
+                Some(10)
+            ))
+        );
+
+        let mut expected_all = vec![row(None, None, 0, None, None, None, None)];
+        expected_all.extend(expected_unique[1..].iter().cloned());
+        expected_all.extend([
+            row(Some(1), Some("c_hashed"), 2, Some(1), Some("c"), None, None),
+            row(
+                Some(1),
+                Some("b_-1_a.x_1_a.y_1"),
+                3,
+                Some(1),
+                Some("b"),
+                Some("D"),
+                None,
+            ),
+            row(
+                Some(1),
+                Some("b_-1_a.x_1_a.y_1"),
+                3,
+                Some(2),
+                Some("a"),
+                Some("A"),
+                None,
+            ),
+            row(Some(1), Some("e_text"), 3, Some(1), Some("e"), None, None),
+        ]);
+        assert_eq!(
+            expected_all,
+            rows(&mut MongoStatistics::new(
+                "db", "coll", &indexes, false, None
+            ))
+        );
+    }
+}
@@ -1,10 +1,22 @@
 use crate::{
     err::{Error, Result},
-    MongoColMetadata, MongoConnection,
+    Cancellation, MongoColMetadata, MongoConnection,
 };
+use definitions::FetchOrientation;
 use mongodb::bson::Bson;
 use std::fmt::Debug;
 
+// The outcome of moving a cursor to a new rowset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollResult {
+    // The cursor is positioned before the first row of the new rowset.
+    Rowset,
+    // The new rowset would start before the result set, so it starts at the first row instead.
+    RowsetStartsAtFirstRow,
+    // The new rowset is before the start or after the end of the result set.
+    NoData,
+}
+
 pub trait MongoStatement: Debug {
     // Move the cursor to the next item.
     // Return true if moving was successful, false otherwise.
@@ -27,19 +39,49 @@ pub trait MongoStatement: Debug {
             .get((col_index - 1) as usize)
             .ok_or(Error::ColIndexOutOfBounds(col_index))
     }
-    // Executes a prepared statement.
+    // Executes a prepared statement, returning at most max_rows rows if max_rows is not 0.
     // Only MongoQuery supports this workflow. The other statements don't.
     fn execute(
         &mut self,
         _connection: &MongoConnection,
         _stmt_id: Bson,
         _rowset_size: u32,
+        _max_rows: u64,
     ) -> Result<bool> {
         Err(Error::UnsupportedOperation("execute"))
     }
     // Closes the cursor.
     // Only MongoQuery supports this workflow. The other statements don't.
     fn close_cursor(&mut self) {}
+    // Sets the cancellation checked while executing and moving the cursor.
+    // Only MongoQuery waits on the server for rows. The other statements don't need it.
+    fn set_cancellation(&mut self, _cancellation: Cancellation) {}
+    // Get the number of rows in the result set, once it is known.
+    // The statements count their rows as the cursor moves, so the count is known once the cursor
+    // reached the end of the result set. The catalog statements don't count their rows.
+    fn get_row_count(&self) -> Option<usize> {
+        None
+    }
+    // Get the bookmark of the current row, which is its row number in the result set.
+    // Only the static cursor supports bookmarks.
+    fn get_bookmark(&self) -> Result<usize> {
+        Err(Error::UnsupportedOperation("bookmarks"))
+    }
+    // Positions the cursor before the first row of the rowset selected by orientation and offset,
+    // given the number of rows in a rowset.
+    // Only the static cursor can scroll. The other statements only support moving forward.
+    fn scroll(
+        &mut self,
+        _mongo_connection: Option<&MongoConnection>,
+        orientation: FetchOrientation,
+        _offset: isize,
+        _rowset_size: usize,
+    ) -> Result<ScrollResult> {
+        match orientation {
+            FetchOrientation::SQL_FETCH_NEXT => Ok(ScrollResult::Rowset),
+            _ => Err(Error::UnsupportedOperation("scroll")),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -16,9 +16,14 @@ pub struct MongoTableTypes {
 
 impl MongoTableTypes {
     // Statement for SQLTables("", "", "", SQL_ALL_TABLE_TYPES ).
-    pub fn all_table_types() -> MongoTableTypes {
+    // The maximum number of table types comes from the statement attribute SQL_ATTR_MAX_ROWS.
+    pub fn all_table_types(max_rows: u64) -> MongoTableTypes {
+        let mut table_type = Vec::from(TABLE_TYPES);
+        if max_rows > 0 {
+            table_type.truncate(usize::try_from(max_rows).unwrap_or(usize::MAX));
+        }
         MongoTableTypes {
-            table_type: Vec::from(TABLE_TYPES),
+            table_type,
             current_table_type_index: 0,
         }
     }
@@ -56,3 +61,22 @@ impl MongoStatement for MongoTableTypes {
         DATABASES_METADATA.get_or_init(|| init_databases_metadata(max_string_length))
     }
 }
+
+mod unit {
+    #[test]
+    fn max_rows() {
+        use crate::{stmt::MongoStatement, table_types::MongoTableTypes};
+        let mut table_types = MongoTableTypes::all_table_types(1);
+        assert!(table_types.next(None).unwrap().0);
+        assert_eq!(
+            Some(mongodb::bson::Bson::String("TABLE".to_string())),
+            table_types.get_value(4, None).unwrap()
+        );
+        assert!(!table_types.next(None).unwrap().0);
+
+        let mut table_types = MongoTableTypes::all_table_types(0);
+        assert!(table_types.next(None).unwrap().0);
+        assert!(table_types.next(None).unwrap().0);
+        assert!(!table_types.next(None).unwrap().0);
+    }
+}
@@ -51,9 +51,18 @@ pub fn to_widechar_vec(s: &str) -> Vec<WideChar> {
     widestring::encode_utf16(s.chars()).collect::<Vec<_>>()
 }
 
+///
+/// from_char_vec_lossy converts UTF-8 bytes to a rust String, replacing invalid sequences
+/// with U+FFFD.
+///
+fn from_char_vec_lossy(v: Vec<Char>) -> String {
+    String::from_utf8(v).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+}
+
 ///
 /// input_text_to_string_a converts a u8 cstring to a rust String.
 /// It assumes null termination if the supplied length is negative.
+/// Invalid UTF-8 sequences are replaced with U+FFFD.
 ///
 /// # Safety
 /// This converts raw C-pointers to rust Strings, which requires unsafe operations
@@ -70,7 +79,7 @@ pub unsafe fn input_text_to_string_a(text: *const Char, len: isize) -> String {
                     itr = itr.offset(1);
                 }
             }
-            String::from_utf8_unchecked(dst)
+            from_char_vec_lossy(dst)
         }
         0 => String::new(),
         1 => {
@@ -78,12 +87,29 @@ pub unsafe fn input_text_to_string_a(text: *const Char, len: isize) -> String {
             let mut dst = Vec::with_capacity(len);
             dst.set_len(len);
             copy_nonoverlapping(text, dst.as_mut_ptr(), len);
-            String::from_utf8_unchecked(dst)
+            from_char_vec_lossy(dst)
         }
         _ => unreachable!("input_text_to_string_a: len was neither negative, zero, nor positive."),
     }
 }
 
+///
+/// input_text_to_string_a_allow_null converts a u8 cstring to a rust String.
+/// It assumes null termination if the supplied length is negative.
+///
+/// This function will return an empty string if passed a null pointer.
+///
+/// # Safety
+/// This converts raw C-pointers to rust Strings, which requires unsafe operations
+///
+pub unsafe fn input_text_to_string_a_allow_null(text: *const Char, len: isize) -> String {
+    if text.is_null() {
+        String::new()
+    } else {
+        input_text_to_string_a(text, len)
+    }
+}
+
 ///
 /// input_text_to_string_w converts a u16 cstring to a rust String.
 /// It assumes null termination if the supplied length is negative.
@@ -320,6 +346,14 @@ mod test {
         assert_eq!(expected, test);
     }
 
+    #[test]
+    fn test_input_atext_to_string_invalid_utf8() {
+        // "café" in cp1252, which is not valid UTF-8.
+        let test = b"caf\xe9\0";
+        let test = unsafe { input_text_to_string_a(test.as_ptr(), -1) };
+        assert_eq!("caf\u{FFFD}", test);
+    }
+
     #[test]
     fn test_input_wtext_to_string() {
         let expected = "test";
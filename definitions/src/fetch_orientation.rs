@@ -10,6 +10,7 @@ pub enum FetchOrientation {
     SQL_FETCH_PRIOR = 4,
     SQL_FETCH_ABSOLUTE = 5,
     SQL_FETCH_RELATIVE = 6,
+    SQL_FETCH_BOOKMARK = 8,
     SQL_FETCH_FIRST_USER = 31,
     SQL_FETCH_FIRST_SYSTEM = 32,
 }
@@ -98,7 +98,9 @@ pub enum FreeStmtOption {
     /// option has no effect for the application. `SQLCloseCursor` can also be called to close a
     /// cursor.
     SQL_CLOSE = 0,
-    // SQL_DROP = 1, is deprecated in favour of SQLFreeHandle
+    /// Frees the statement handle. Deprecated in favour of `SQLFreeHandle` with
+    /// `SQL_HANDLE_STMT`, which it is equivalent to.
+    SQL_DROP = 1,
     /// Sets the `SQL_DESC_COUNT` field of the ARD to 0, releasing all column buffers bound by
     /// `SQLBindCol` for the given StatementHandle. This does not unbind the bookmark column; to do
     /// that, the `SQL_DESC_DATA_PTR` field of the ARD for the bookmark column is set to NULL.
@@ -332,7 +334,7 @@ pub enum CursorSensitivity {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
 #[repr(u32)]
 pub enum AsyncEnable {
     SQL_ASYNC_ENABLE_OFF = 0,
@@ -413,6 +415,17 @@ pub enum RowStatus {
     SQL_ROW_SUCCESS_WITH_INFO = 6,
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u16)]
+pub enum ParamStatus {
+    SQL_PARAM_SUCCESS = 0,
+    SQL_PARAM_DIAG_UNAVAILABLE = 1,
+    SQL_PARAM_ERROR = 5,
+    SQL_PARAM_SUCCESS_WITH_INFO = 6,
+    SQL_PARAM_UNUSED = 7,
+}
+
 #[derive(Clone, Copy, Debug, Default, FromPrimitive, PartialEq)]
 #[repr(u32)]
 pub enum AccessMode {
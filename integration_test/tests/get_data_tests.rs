@@ -152,4 +152,39 @@ mod integration {
             }
         }
     }
+
+    #[test]
+    fn exec_direct_batch_returns_each_result_set() {
+        let (env_handle, conn_handle, stmt_handle) =
+            default_setup_connect_and_alloc_stmt(AttrOdbcVersion::SQL_OV_ODBC3);
+
+        unsafe {
+            let query =
+                b"SELECT * FROM integration_test.class; SELECT * FROM integration_test.class\0"
+                    .map(|c| c.into());
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLExecDirectW(stmt_handle, query.as_ptr(), SQL_NTS),
+                "{}",
+                get_sql_diagnostics(HandleType::SQL_HANDLE_STMT, stmt_handle as Handle)
+            );
+
+            // SQLMoreResults moves on to the result set of the next statement of the batch.
+            for expected in [SqlReturn::SUCCESS, SqlReturn::NO_DATA] {
+                let mut fetch_count = 0;
+                while SQLFetch(stmt_handle) == SqlReturn::SUCCESS {
+                    fetch_count += 1;
+                }
+                assert_eq!(5, fetch_count);
+                assert_eq!(
+                    expected,
+                    SQLMoreResults(stmt_handle),
+                    "{}",
+                    get_sql_diagnostics(HandleType::SQL_HANDLE_STMT, stmt_handle as Handle)
+                );
+            }
+
+            disconnect_and_free_dbc_and_env_handles(env_handle, conn_handle);
+        }
+    }
 }
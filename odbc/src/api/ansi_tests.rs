@@ -0,0 +1,267 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{
+            Connection, ConnectionState, Env, EnvState, MongoHandle, Statement, StatementState,
+        },
+        SQLGetConnectAttr, SQLGetCursorName, SQLGetDiagRec, SQLGetInfo, SQLNativeSql,
+        SQLSetConnectAttr, SQLSetCursorName,
+    };
+    use constants::DRIVER_NAME;
+    use cstr::input_text_to_string_a;
+    use definitions::{
+        Char, ConnectionAttribute, HDbc, HStmt, HandleType, InfoType, Integer, SmallInt, SqlReturn,
+        SQL_NTS, SQL_NTS_ISIZE,
+    };
+
+    // Allocates a statement on the connection and registers it in the connection's statements.
+    fn alloc_statement(conn: *mut MongoHandle) -> *mut MongoHandle {
+        let stmt = Box::into_raw(Box::new(MongoHandle::Statement(Statement::with_state(
+            conn,
+            StatementState::Allocated,
+        ))));
+        unsafe {
+            (*conn)
+                .as_connection()
+                .unwrap()
+                .statements
+                .write()
+                .unwrap()
+                .insert(stmt);
+        }
+        stmt
+    }
+
+    #[test]
+    fn cursor_name_lengths_are_in_bytes() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+        let (name, _buffer) = cstr::to_char_ptr("café_cursor");
+
+        let mut buffer: [Char; 64] = [0; 64];
+        let name_length = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetCursorName(stmt as HStmt, name, SQL_NTS as SmallInt)
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetCursorName(stmt as HStmt, buffer.as_mut_ptr(), 64, name_length)
+            );
+            assert_eq!("café_cursor".len(), *name_length as usize);
+            assert_eq!(
+                "café_cursor",
+                input_text_to_string_a(buffer.as_ptr(), SQL_NTS_ISIZE)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_input_is_replaced() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+        // "café_cursor" in cp1252, which is not valid UTF-8.
+        let name = b"caf\xe9_cursor\0";
+
+        let mut buffer: [Char; 64] = [0; 64];
+        let name_length = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetCursorName(stmt as HStmt, name.as_ptr(), SQL_NTS as SmallInt)
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetCursorName(stmt as HStmt, buffer.as_mut_ptr(), 64, name_length)
+            );
+            assert_eq!("caf\u{FFFD}_cursor".len(), *name_length as usize);
+            assert_eq!(
+                "caf\u{FFFD}_cursor",
+                input_text_to_string_a(buffer.as_ptr(), SQL_NTS_ISIZE)
+            );
+        }
+    }
+
+    #[test]
+    fn truncation_is_reported_in_diagnostics() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+        let (name, _buffer) = cstr::to_char_ptr("orders");
+
+        let mut buffer: [Char; 4] = [0; 4];
+        let name_length = &mut 0;
+        let mut state: [Char; 6] = [0; 6];
+        let mut message: [Char; 256] = [0; 256];
+        let message_length = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetCursorName(stmt as HStmt, name, SQL_NTS as SmallInt)
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS_WITH_INFO,
+                SQLGetCursorName(stmt as HStmt, buffer.as_mut_ptr(), 4, name_length)
+            );
+            assert_eq!(
+                "ord",
+                input_text_to_string_a(buffer.as_ptr(), SQL_NTS_ISIZE)
+            );
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetDiagRec(
+                    HandleType::SQL_HANDLE_STMT,
+                    stmt as *mut _,
+                    1,
+                    state.as_mut_ptr(),
+                    &mut 0,
+                    message.as_mut_ptr(),
+                    256,
+                    message_length,
+                )
+            );
+            assert_eq!(
+                "01004",
+                input_text_to_string_a(state.as_ptr(), SQL_NTS_ISIZE)
+            );
+            assert_eq!(
+                "[MongoDB][API] Buffer size \"4\" not large enough for data",
+                input_text_to_string_a(message.as_ptr(), *message_length as isize)
+            );
+        }
+    }
+
+    #[test]
+    fn native_sql_lengths_are_in_bytes() {
+        let conn = &mut MongoHandle::Connection(Connection::with_state(
+            std::ptr::null_mut(),
+            ConnectionState::Connected,
+        ));
+        let query = "SELECT 'é' FROM foo";
+        let (in_statement, _buffer) = cstr::to_char_ptr(query);
+
+        let mut buffer: [Char; 64] = [0; 64];
+        let out_length: &mut Integer = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLNativeSql(
+                    conn as *mut _ as HDbc,
+                    in_statement,
+                    SQL_NTS as Integer,
+                    buffer.as_mut_ptr(),
+                    64,
+                    out_length,
+                )
+            );
+            assert_eq!(query.len(), *out_length as usize);
+            assert_eq!(
+                query,
+                input_text_to_string_a(buffer.as_ptr(), SQL_NTS_ISIZE)
+            );
+        }
+    }
+
+    #[test]
+    fn native_sql_without_buffer_reports_full_length() {
+        let conn = &mut MongoHandle::Connection(Connection::with_state(
+            std::ptr::null_mut(),
+            ConnectionState::Connected,
+        ));
+        let query = "SELECT 'é' FROM foo";
+        let (in_statement, _buffer) = cstr::to_char_ptr(query);
+
+        let out_length: &mut Integer = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLNativeSql(
+                    conn as *mut _ as HDbc,
+                    in_statement,
+                    SQL_NTS as Integer,
+                    std::ptr::null_mut(),
+                    0,
+                    out_length,
+                )
+            );
+            assert_eq!(query.len(), *out_length as usize);
+        }
+    }
+
+    #[test]
+    fn get_info_string() {
+        let conn = &mut MongoHandle::Connection(Connection::with_state(
+            std::ptr::null_mut(),
+            ConnectionState::Connected,
+        ));
+        let mut buffer: [Char; 64] = [0; 64];
+        let out_length: &mut SmallInt = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetInfo(
+                    conn as *mut _ as HDbc,
+                    InfoType::SQL_DRIVER_NAME as u16,
+                    buffer.as_mut_ptr() as *mut _,
+                    64,
+                    out_length,
+                )
+            );
+            assert_eq!(DRIVER_NAME.len(), *out_length as usize);
+            assert_eq!(
+                DRIVER_NAME,
+                input_text_to_string_a(buffer.as_ptr(), SQL_NTS_ISIZE)
+            );
+        }
+    }
+
+    #[test]
+    fn set_and_get_current_catalog() {
+        let conn = &mut MongoHandle::Connection(Connection::with_state(
+            std::ptr::null_mut(),
+            ConnectionState::Connected,
+        ));
+        let (catalog, _buffer) = cstr::to_char_ptr("données");
+
+        let mut buffer: [Char; 64] = [0; 64];
+        let out_length: &mut Integer = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetConnectAttr(
+                    conn as *mut _ as HDbc,
+                    ConnectionAttribute::SQL_ATTR_CURRENT_CATALOG as Integer,
+                    catalog as *mut _,
+                    SQL_NTS as Integer,
+                )
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetConnectAttr(
+                    conn as *mut _ as HDbc,
+                    ConnectionAttribute::SQL_ATTR_CURRENT_CATALOG as Integer,
+                    buffer.as_mut_ptr() as *mut _,
+                    64,
+                    out_length,
+                )
+            );
+            assert_eq!("données".len(), *out_length as usize);
+            assert_eq!(
+                "données",
+                input_text_to_string_a(buffer.as_ptr(), SQL_NTS_ISIZE)
+            );
+        }
+    }
+}
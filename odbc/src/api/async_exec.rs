@@ -0,0 +1,112 @@
+use crate::{
+    add_diag_with_function,
+    api::errors::ODBCError,
+    handles::definitions::{AsyncOperation, MongoHandle},
+};
+use definitions::{AsyncEnable, HStmt, SqlReturn};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    thread,
+};
+
+///
+/// poll_async checks the asynchronous operation of the statement when `function_name` is called.
+/// It returns None when no operation is pending, so the function should start executing.
+/// Otherwise, it returns SQL_STILL_EXECUTING until the worker finishes, then the return code of
+/// the operation, or an error if a different function started the operation.
+///
+pub(crate) fn poll_async(
+    mongo_handle: &mut MongoHandle,
+    function_name: &'static str,
+) -> Option<SqlReturn> {
+    let stmt = mongo_handle.as_statement()?;
+    let pending = match stmt.async_operation.read().unwrap().as_ref() {
+        None => return None,
+        Some(operation) => (operation.function_name, operation.worker.is_finished()),
+    };
+    match pending {
+        (started_by, _) if started_by != function_name => {
+            unsafe {
+                add_diag_with_function!(
+                    mongo_handle,
+                    ODBCError::FunctionSequenceError("an asynchronous function is still executing"),
+                    function_name
+                );
+            }
+            Some(SqlReturn::ERROR)
+        }
+        (_, false) => Some(SqlReturn::STILL_EXECUTING),
+        (_, true) => Some(complete_async(mongo_handle)),
+    }
+}
+
+///
+/// complete_async waits for the asynchronous operation of the statement to finish and returns its
+/// return code, so that the statement can be used by other functions again.
+///
+pub(crate) fn complete_async(mongo_handle: &mut MongoHandle) -> SqlReturn {
+    let operation = match mongo_handle.as_statement() {
+        Some(stmt) => stmt.async_operation.write().unwrap().take(),
+        None => None,
+    };
+    match operation {
+        // The worker catches panics, so joining it only fails if adding the diagnostic panicked.
+        Some(operation) => operation.worker.join().unwrap_or(SqlReturn::ERROR),
+        None => SqlReturn::SUCCESS,
+    }
+}
+
+///
+/// exec_async runs `work` with the statement handle. When SQL_ATTR_ASYNC_ENABLE is on, `work`
+/// runs on a driver-owned worker thread, with a handle sharing the state of the statement, and
+/// SQL_STILL_EXECUTING is returned. Otherwise it runs on the calling thread and its return code is
+/// returned.
+///
+pub(crate) fn exec_async<F>(
+    mongo_handle: &mut MongoHandle,
+    function_name: &'static str,
+    work: F,
+) -> SqlReturn
+where
+    F: FnOnce(HStmt) -> SqlReturn + Send + 'static,
+{
+    let handle: *mut MongoHandle = mongo_handle;
+    let stmt = match mongo_handle.as_statement() {
+        Some(stmt) => stmt,
+        None => return work(handle.cast()),
+    };
+    if stmt.attributes.read().unwrap().async_enable == AsyncEnable::SQL_ASYNC_ENABLE_OFF {
+        return work(handle.cast());
+    }
+
+    // The worker runs with its own handle, sharing the state of the statement rather than using
+    // the application's handle.
+    let statement = stmt.clone();
+    let worker = thread::spawn(move || {
+        let mut statement = MongoHandle::Statement(statement);
+        let handle: *mut MongoHandle = &mut statement;
+        match panic::catch_unwind(AssertUnwindSafe(|| work(handle.cast()))) {
+            Ok(sql_return) => sql_return,
+            Err(err) => {
+                let panic_msg = match err.downcast_ref::<&'static str>() {
+                    Some(msg) => msg.to_string(),
+                    None => match err.downcast_ref::<String>() {
+                        Some(msg) => msg.clone(),
+                        None => format!("{err:?}"),
+                    },
+                };
+                let error = ODBCError::Panic(panic_msg);
+                unsafe {
+                    let mongo_handle = &mut *handle;
+                    add_diag_with_function!(mongo_handle, error.clone(), function_name);
+                }
+                SqlReturn::ERROR
+            }
+        }
+    });
+    *stmt.async_operation.write().unwrap() = Some(AsyncOperation {
+        function_name,
+        worker,
+    });
+    SqlReturn::STILL_EXECUTING
+}
@@ -0,0 +1,228 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{
+            Connection, ConnectionState, Env, EnvState, MongoHandle, Statement, StatementState,
+        },
+        SQLAllocHandle, SQLBindCol, SQLCompleteAsync, SQLFetch, SQLFetchScroll, SQLFreeStmt,
+        SQLGetTypeInfoW, SQLSetStmtAttrW,
+    };
+    use bson::doc;
+    use definitions::{
+        AsyncEnable, CDataType, FetchOrientation, FreeStmtOption, HStmt, Handle, HandleType,
+        Nullability, Pointer, RetCode, SmallInt, SqlDataType, SqlReturn, StatementAttribute,
+    };
+    use mongo_odbc_core::{
+        json_schema::{
+            simplified::{Atomic, Schema},
+            BsonTypeName,
+        },
+        mock_query::MongoQuery,
+        MongoColMetadata, TypeMode,
+    };
+
+    // Polls the function until it is no longer executing asynchronously.
+    fn poll(mut function: impl FnMut() -> SqlReturn) -> SqlReturn {
+        loop {
+            let sql_return = function();
+            if sql_return != SqlReturn::STILL_EXECUTING {
+                return sql_return;
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    fn enable_async(stmt: *mut MongoHandle) {
+        assert_eq!(SqlReturn::SUCCESS, unsafe {
+            SQLSetStmtAttrW(
+                stmt as HStmt,
+                StatementAttribute::SQL_ATTR_ASYNC_ENABLE as i32,
+                AsyncEnable::SQL_ASYNC_ENABLE_ON as usize as Pointer,
+                0,
+            )
+        });
+    }
+
+    fn last_sql_state(stmt: *mut MongoHandle) -> String {
+        unsafe {
+            (*stmt)
+                .as_statement()
+                .unwrap()
+                .errors
+                .read()
+                .unwrap()
+                .last()
+                .unwrap()
+                .get_sql_state()
+                .odbc_3_state
+                .to_string()
+        }
+    }
+
+    #[test]
+    fn test_async_fetch() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            *(*stmt)
+                .as_statement()
+                .unwrap()
+                .mongo_statement
+                .write()
+                .unwrap() = Some(Box::new(MongoQuery::new(
+                (1..=2).map(|x| doc! {"": {"x": x}}).collect(),
+                vec![MongoColMetadata::new(
+                    "",
+                    "".to_string(),
+                    "x".to_string(),
+                    Schema::Atomic(Atomic::Scalar(BsonTypeName::Int)),
+                    Nullability::SQL_NO_NULLS,
+                    TypeMode::Simple,
+                    None,
+                )],
+            )));
+            let mut value = 0i32;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindCol(
+                    stmt as HStmt,
+                    1,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    &mut value as *mut i32 as Pointer,
+                    4,
+                    std::ptr::null_mut(),
+                )
+            );
+            enable_async(stmt);
+
+            // The fetch runs on a worker, and only SQLFetch can be called until it completes.
+            assert_eq!(SqlReturn::STILL_EXECUTING, SQLFetch(stmt as HStmt));
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLFetchScroll(
+                    stmt as HStmt,
+                    FetchOrientation::SQL_FETCH_NEXT as SmallInt,
+                    0
+                )
+            );
+            assert_eq!("HY010", last_sql_state(stmt));
+            assert_eq!(SqlReturn::SUCCESS, poll(|| SQLFetch(stmt as HStmt)));
+            assert_eq!(1, value);
+
+            // SQLCompleteAsync waits for the worker and returns the result of the fetch.
+            assert_eq!(SqlReturn::STILL_EXECUTING, SQLFetch(stmt as HStmt));
+            let mut async_ret_code: RetCode = SqlReturn::ERROR.0;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLCompleteAsync(
+                    HandleType::SQL_HANDLE_STMT,
+                    stmt as *mut _,
+                    &mut async_ret_code
+                )
+            );
+            assert_eq!((SqlReturn::SUCCESS.0, 2), (async_ret_code, value));
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLCompleteAsync(
+                    HandleType::SQL_HANDLE_STMT,
+                    stmt as *mut _,
+                    &mut async_ret_code
+                )
+            );
+            assert_eq!("HY010", last_sql_state(stmt));
+
+            assert_eq!(SqlReturn::STILL_EXECUTING, SQLFetch(stmt as HStmt));
+            assert_eq!(SqlReturn::NO_DATA, poll(|| SQLFetch(stmt as HStmt)));
+        }
+    }
+
+    #[test]
+    fn test_async_catalog_function() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+        enable_async(stmt);
+
+        unsafe {
+            assert_eq!(
+                SqlReturn::STILL_EXECUTING,
+                SQLGetTypeInfoW(stmt as HStmt, SqlDataType::SQL_INTEGER as SmallInt)
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                poll(|| SQLGetTypeInfoW(stmt as HStmt, SqlDataType::SQL_INTEGER as SmallInt))
+            );
+            assert_eq!(SqlReturn::STILL_EXECUTING, SQLFetch(stmt as HStmt));
+            assert_eq!(SqlReturn::SUCCESS, poll(|| SQLFetch(stmt as HStmt)));
+
+            // The diagnostics of the worker are returned by the call that completes the function.
+            assert_eq!(
+                SqlReturn::STILL_EXECUTING,
+                SQLGetTypeInfoW(stmt as HStmt, 1000)
+            );
+            assert_eq!(
+                SqlReturn::ERROR,
+                poll(|| SQLGetTypeInfoW(stmt as HStmt, 1000))
+            );
+            assert_eq!("HY004", last_sql_state(stmt));
+        }
+    }
+
+    #[test]
+    fn test_drop_statement_waits_for_worker() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn: *mut _ =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        unsafe {
+            let mut stmt: Handle = std::ptr::null_mut();
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLAllocHandle(HandleType::SQL_HANDLE_STMT, conn as *mut _, &mut stmt)
+            );
+            *(*stmt.cast::<MongoHandle>())
+                .as_statement()
+                .unwrap()
+                .mongo_statement
+                .write()
+                .unwrap() = Some(Box::new(MongoQuery::new(
+                vec![doc! {"": {"x": 1}}],
+                vec![MongoColMetadata::new(
+                    "",
+                    "".to_string(),
+                    "x".to_string(),
+                    Schema::Atomic(Atomic::Scalar(BsonTypeName::Int)),
+                    Nullability::SQL_NO_NULLS,
+                    TypeMode::Simple,
+                    None,
+                )],
+            )));
+            enable_async(stmt.cast());
+
+            // Dropping the statement while the fetch runs waits for the worker before freeing it.
+            assert_eq!(SqlReturn::STILL_EXECUTING, SQLFetch(stmt as HStmt));
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeStmt(stmt as HStmt, FreeStmtOption::SQL_DROP as SmallInt)
+            );
+            assert!((*conn)
+                .as_connection()
+                .unwrap()
+                .statements
+                .read()
+                .unwrap()
+                .is_empty());
+        }
+    }
+}
@@ -0,0 +1,108 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{
+            Connection, ConnectionState, Env, EnvState, MongoHandle, PendingResult, Statement,
+            StatementState,
+        },
+        SQLBindParameter, SQLExecDirectW, SQLMoreResults, SQLSetStmtAttrW,
+    };
+    use cstr::WideChar;
+    use definitions::{
+        CDataType, Integer, ParamType, Pointer, SmallInt, SqlDataType, SqlReturn,
+        StatementAttribute, SQL_NTS,
+    };
+
+    #[test]
+    fn test_exec_direct_batch_then_more_results() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+        let mut query: Vec<WideChar> =
+            cstr::to_widechar_vec("select * from foo; select * from bar");
+        query.push(0);
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+
+            // There is no connection, so the first statement fails, and the second statement
+            // is never executed.
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLExecDirectW(stmt as *mut _, query.as_ptr(), SQL_NTS as Integer)
+            );
+            assert_eq!(1, s.errors.read().unwrap().len());
+            assert!(s.more_results.read().unwrap().is_empty());
+            assert_eq!(SqlReturn::NO_DATA, SQLMoreResults(stmt as *mut _));
+
+            // SQLMoreResults executes the pending statements of a batch in order.
+            s.more_results.write().unwrap().extend([
+                PendingResult::BatchStatement("select * from foo".to_string()),
+                PendingResult::BatchStatement("select * from bar".to_string()),
+            ]);
+            assert_eq!(SqlReturn::ERROR, SQLMoreResults(stmt as *mut _));
+            assert_eq!(1, s.errors.read().unwrap().len());
+            assert!(s.more_results.read().unwrap().is_empty());
+            assert_eq!(StatementState::Allocated, *s.state.read().unwrap());
+            assert_eq!(SqlReturn::NO_DATA, SQLMoreResults(stmt as *mut _));
+        }
+    }
+
+    #[test]
+    fn test_batch_with_param_array_is_unsupported() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+        let mut query: Vec<WideChar> =
+            cstr::to_widechar_vec("select * from foo where a = ?; select * from bar");
+        query.push(0);
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            let mut values = [1i32, 2];
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindParameter(
+                    stmt as *mut _,
+                    1,
+                    ParamType::SQL_PARAM_INPUT as SmallInt,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    SqlDataType::SQL_INTEGER as SmallInt,
+                    0,
+                    0,
+                    values.as_mut_ptr() as Pointer,
+                    0,
+                    std::ptr::null_mut(),
+                )
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetStmtAttrW(
+                    stmt as *mut _,
+                    StatementAttribute::SQL_ATTR_PARAMSET_SIZE as i32,
+                    2 as Pointer,
+                    0,
+                )
+            );
+
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLExecDirectW(stmt as *mut _, query.as_ptr(), SQL_NTS as Integer)
+            );
+            assert_eq!(
+                "HYC00",
+                s.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+            );
+            assert!(s.more_results.read().unwrap().is_empty());
+        }
+    }
+}
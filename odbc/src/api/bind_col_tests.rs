@@ -28,7 +28,7 @@ mod unit {
         MongoColMetadata, MongoStatement, TypeMode,
     };
     use std::collections::HashMap;
-    use std::ptr::null_mut;
+    use std::{mem::size_of, ptr::null_mut};
 
     // TODO: SQL-2010: Create test coverage for error handling when column binding with rowsets
 
@@ -363,7 +363,16 @@ mod unit {
     }
 
     #[test]
-    fn test_unsupported_ways_to_column_bind() {
+    fn test_row_wise_binding_with_offset() {
+        #[repr(C)]
+        #[derive(Default)]
+        struct Row {
+            num: i32,
+            num_indicator: Len,
+            word: [WideChar; 5],
+            word_indicator: Len,
+        }
+
         // Set up MongoHandle
         let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
         let conn =
@@ -375,59 +384,41 @@ mod unit {
             // Get Statement
             let s = (*stmt).as_statement().unwrap();
 
-            // Set the mongo_statement to have non-empty cursor initially.
-            // Here, we create a MockQuery with nonsense dummy data since the
-            // values themselves do not matter.
-            let mock_query = &mut create_mongo_query_for_bind_col_tests();
-
-            // Must call next to set the `current` field.
-            let _ = mock_query.next(None);
-
-            // Set the mongo_statement
-            *s.mongo_statement.write().unwrap() = Some(Box::new(mock_query.clone()));
-
-            let indicator: *mut Len = null_mut();
-            let buffer: *mut std::ffi::c_void = Box::into_raw(Box::new([0u8; 4])) as *mut _;
-
-            // set all statement attributes to the correct values except for row_bind_offset_ptr.
-            s.attributes.write().unwrap().row_bind_offset_ptr =
-                Box::into_raw(Box::new(100)) as *mut ULen;
-            s.attributes.write().unwrap().row_array_size = 1;
-            s.attributes.write().unwrap().row_bind_type = BindType::SQL_BIND_BY_COLUMN as usize;
+            let mut rows: [Row; 3] = Default::default();
 
-            // Assert that SQLBindCol returns an error because row_bind_offset_ptr is not null.
-            assert_eq!(
-                SqlReturn::ERROR,
-                SQLBindCol(
-                    stmt as *mut _,
-                    1,
-                    CDataType::SQL_C_SLONG as SmallInt,
-                    buffer,
-                    4,
-                    indicator
-                )
+            // The columns are bound to the first row, and the offset moves the bindings one row
+            // down, so the first row is never written.
+            *s.bound_cols.write().unwrap() = create_column_bindings_for_num_and_word(
+                &mut rows[0].num as *mut i32 as *mut _,
+                &mut rows[0].num_indicator,
+                rows[0].word.as_mut_ptr() as *mut _,
+                &mut rows[0].word_indicator,
             );
+            let mut offset: ULen = size_of::<Row>();
+            s.attributes.write().unwrap().row_bind_offset_ptr = &mut offset;
+            s.attributes.write().unwrap().row_array_size = 2;
+            s.attributes.write().unwrap().row_bind_type = size_of::<Row>();
 
-            // Free memory and set row_bind_offset_ptr to null. Set row_bind_type to an invalid number.
-            let _ = Box::from_raw(s.attributes.write().unwrap().row_bind_offset_ptr as *mut WChar);
-            s.attributes.write().unwrap().row_bind_offset_ptr = null_mut();
-            s.attributes.write().unwrap().row_bind_type = 10;
+            *s.mongo_statement.write().unwrap() =
+                Some(Box::new(create_mongo_query_for_bind_col_fetching_tests()));
 
-            // Assert that SQLBindCol returns an error because row_bind_type is not 0 (i.e., BindType::SQL_BIND_BY_COLUMN).
-            assert_eq!(
-                SqlReturn::ERROR,
-                SQLBindCol(
-                    stmt as *mut _,
-                    1,
-                    CDataType::SQL_C_SLONG as SmallInt,
-                    buffer,
-                    4,
-                    indicator
-                )
-            );
-
-            // free buffer
-            let _ = Box::from_raw(buffer as *mut WChar);
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt as *mut _));
+            assert_eq!((0, 0), (rows[0].num, rows[0].num_indicator));
+            for (row, (num, word)) in rows[1..].iter().zip([(10, "aaaa"), (20, "bbbb")]) {
+                assert_eq!((num, 4), (row.num, row.num_indicator));
+                assert_eq!(
+                    (word.to_string(), 8),
+                    (
+                        input_text_to_string_w(row.word.as_ptr(), SQL_NTS_ISIZE),
+                        row.word_indicator
+                    )
+                );
+            }
+
+            // Changing the offset reuses the same bindings for another set of rows.
+            *s.attributes.read().unwrap().row_bind_offset_ptr = 0;
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt as *mut _));
+            assert_eq!((30, 40, 20), (rows[0].num, rows[1].num, rows[2].num));
         }
     }
 
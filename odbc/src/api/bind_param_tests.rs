@@ -0,0 +1,360 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        api::params::param_value_to_bson,
+        handles::definitions::{
+            BoundParamInfo, Connection, ConnectionState, Env, EnvState, MongoHandle, Statement,
+            StatementState,
+        },
+        SQLBindParameter, SQLDescribeParam, SQLFreeStmt, SQLNumParams, SQLNumResultCols,
+        SQLPrepareW,
+    };
+    use bson::{spec::BinarySubtype, Binary, Bson};
+    use definitions::{
+        CDataType, Date, FreeStmtOption, Integer, Len, Nullability, ParamType, Pointer, SmallInt,
+        SqlDataType, SqlReturn, ULen, SQL_NTS, SQL_NTS_ISIZE, SQL_NULL_DATA,
+    };
+    use mongo_odbc_core::mock_query::MongoQuery;
+    use std::ptr::null_mut;
+
+    fn bind_param(
+        stmt: *mut MongoHandle,
+        parameter_number: u16,
+        value_type: CDataType,
+        parameter_type: SqlDataType,
+        value_ptr: Pointer,
+        str_len_or_ind_ptr: *mut Len,
+    ) -> SqlReturn {
+        unsafe {
+            SQLBindParameter(
+                stmt as *mut _,
+                parameter_number,
+                ParamType::SQL_PARAM_INPUT as SmallInt,
+                value_type as SmallInt,
+                parameter_type as SmallInt,
+                10,
+                0,
+                value_ptr,
+                0,
+                str_len_or_ind_ptr,
+            )
+        }
+    }
+
+    fn param_info(
+        value_type: CDataType,
+        value_ptr: Pointer,
+        indicator: *mut Len,
+    ) -> BoundParamInfo {
+        BoundParamInfo {
+            input_output_type: ParamType::SQL_PARAM_INPUT as SmallInt,
+            value_type: value_type as SmallInt,
+            parameter_type: SqlDataType::SQL_UNKNOWN_TYPE as SmallInt,
+            column_size: 0,
+            decimal_digits: 0,
+            parameter_value_ptr: value_ptr,
+            buffer_length: 0,
+            str_len_or_ind_ptr: indicator,
+        }
+    }
+
+    #[test]
+    fn test_bind_and_reset_parameters() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let value = &mut 42i32;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                bind_param(
+                    stmt,
+                    1,
+                    CDataType::SQL_C_SLONG,
+                    SqlDataType::SQL_INTEGER,
+                    value as *mut i32 as Pointer,
+                    null_mut(),
+                )
+            );
+            let s = (*stmt).as_statement().unwrap();
+            let bound_params = s.bound_params.read().unwrap();
+            let param = bound_params.as_ref().unwrap().get(&1).unwrap();
+            assert_eq!(CDataType::SQL_C_SLONG as SmallInt, param.value_type);
+            assert_eq!(SqlDataType::SQL_INTEGER as SmallInt, param.parameter_type);
+            drop(bound_params);
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeStmt(stmt as *mut _, FreeStmtOption::SQL_RESET_PARAMS as SmallInt)
+            );
+            assert!(s.bound_params.read().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_bind_parameter_errors() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let value = &mut 42i32;
+            // parameters are numbered from 1
+            assert_eq!(
+                SqlReturn::ERROR,
+                bind_param(
+                    stmt,
+                    0,
+                    CDataType::SQL_C_SLONG,
+                    SqlDataType::SQL_INTEGER,
+                    value as *mut i32 as Pointer,
+                    null_mut(),
+                )
+            );
+            // output parameters are not supported
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLBindParameter(
+                    stmt as *mut _,
+                    1,
+                    ParamType::SQL_PARAM_OUTPUT as SmallInt,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    SqlDataType::SQL_INTEGER as SmallInt,
+                    0,
+                    0,
+                    value as *mut i32 as Pointer,
+                    0,
+                    null_mut(),
+                )
+            );
+            // unknown C type
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLBindParameter(
+                    stmt as *mut _,
+                    1,
+                    ParamType::SQL_PARAM_INPUT as SmallInt,
+                    1234,
+                    SqlDataType::SQL_INTEGER as SmallInt,
+                    0,
+                    0,
+                    value as *mut i32 as Pointer,
+                    0,
+                    null_mut(),
+                )
+            );
+            assert!((*stmt)
+                .as_statement()
+                .unwrap()
+                .bound_params
+                .read()
+                .unwrap()
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_num_params_and_describe_param() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let param_count = &mut 0;
+            // The statement must be prepared first
+            assert_eq!(SqlReturn::ERROR, SQLNumParams(stmt as *mut _, param_count));
+
+            let s = (*stmt).as_statement().unwrap();
+            *s.mongo_statement.write().unwrap() = Some(Box::new(MongoQuery::new(vec![], vec![])));
+            *s.parameterized_query.write().unwrap() =
+                Some("select * from foo where a = ? and b = '?' and c = ?".to_string());
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLNumParams(stmt as *mut _, param_count)
+            );
+            assert_eq!(2, *param_count);
+
+            let value = &mut 42i64;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                bind_param(
+                    stmt,
+                    2,
+                    CDataType::SQL_C_SBIGINT,
+                    SqlDataType::SQL_BIGINT,
+                    value as *mut i64 as Pointer,
+                    null_mut(),
+                )
+            );
+
+            let data_type = &mut SqlDataType::SQL_UNKNOWN_TYPE;
+            let size: *mut ULen = &mut 0;
+            let decimal_digits = &mut 0;
+            let nullable = &mut 0;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLDescribeParam(stmt as *mut _, 1, data_type, size, decimal_digits, nullable)
+            );
+            assert_eq!(SqlDataType::SQL_WVARCHAR, *data_type);
+            assert_eq!(Nullability::SQL_NULLABLE as SmallInt, *nullable);
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLDescribeParam(stmt as *mut _, 2, data_type, size, decimal_digits, nullable)
+            );
+            assert_eq!(SqlDataType::SQL_BIGINT, *data_type);
+            assert_eq!(10, *size);
+
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLDescribeParam(stmt as *mut _, 3, data_type, size, decimal_digits, nullable)
+            );
+        }
+    }
+
+    #[test]
+    fn test_prepare_defers_parameterized_query() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+        let mut query = cstr::to_widechar_vec("select * from foo limit ?");
+        query.push(0);
+
+        unsafe {
+            // The query is only prepared by the server once it is executed with the bound values,
+            // so preparing it does not need a connection.
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLPrepareW(stmt as *mut _, query.as_ptr(), SQL_NTS as Integer)
+            );
+            let s = (*stmt).as_statement().unwrap();
+            assert!(s.mongo_statement.read().unwrap().is_none());
+
+            let param_count = &mut 0;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLNumParams(stmt as *mut _, param_count)
+            );
+            assert_eq!(1, *param_count);
+
+            let column_count = &mut -1;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLNumResultCols(stmt as *mut _, column_count)
+            );
+            assert_eq!(0, *column_count);
+        }
+    }
+
+    #[test]
+    fn test_param_value_to_bson() {
+        unsafe {
+            let int = &mut 7i32;
+            assert_eq!(
+                Bson::Int32(7),
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_SLONG,
+                    int as *mut i32 as Pointer,
+                    null_mut()
+                ))
+                .unwrap()
+            );
+
+            let double = &mut 1.5f64;
+            assert_eq!(
+                Bson::Double(1.5),
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_DOUBLE,
+                    double as *mut f64 as Pointer,
+                    null_mut()
+                ))
+                .unwrap()
+            );
+
+            let text = b"hello world\0";
+            let len = &mut 5;
+            assert_eq!(
+                Bson::String("hello".to_string()),
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_CHAR,
+                    text.as_ptr() as Pointer,
+                    len
+                ))
+                .unwrap()
+            );
+            let nts = &mut { SQL_NTS_ISIZE };
+            assert_eq!(
+                Bson::String("hello world".to_string()),
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_CHAR,
+                    text.as_ptr() as Pointer,
+                    nts
+                ))
+                .unwrap()
+            );
+
+            let bytes = &mut [5u8, 6, 42];
+            let len = &mut 3;
+            assert_eq!(
+                Bson::Binary(Binary {
+                    subtype: BinarySubtype::Generic,
+                    bytes: vec![5, 6, 42],
+                }),
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_BINARY,
+                    bytes.as_mut_ptr() as Pointer,
+                    len
+                ))
+                .unwrap()
+            );
+
+            let null = &mut { SQL_NULL_DATA };
+            assert_eq!(
+                Bson::Null,
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_CHAR,
+                    text.as_ptr() as Pointer,
+                    null
+                ))
+                .unwrap()
+            );
+
+            let date = &mut Date {
+                year: 2020,
+                month: 2,
+                day: 3,
+            };
+            assert_eq!(
+                Bson::DateTime(
+                    bson::DateTime::builder()
+                        .year(2020)
+                        .month(2)
+                        .day(3)
+                        .build()
+                        .unwrap()
+                ),
+                param_value_to_bson(&param_info(
+                    CDataType::SQL_C_TYPE_DATE,
+                    date as *mut Date as Pointer,
+                    null_mut()
+                ))
+                .unwrap()
+            );
+        }
+    }
+}
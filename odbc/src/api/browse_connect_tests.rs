@@ -0,0 +1,76 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{Connection, ConnectionState, Env, EnvState, MongoHandle},
+        SQLBrowseConnectW,
+    };
+    use cstr::WideChar;
+    use definitions::{HDbc, SqlReturn, SQL_NTS};
+
+    // Calls SQLBrowseConnectW with the given attributes and returns its result with the output
+    // string.
+    fn browse_connect(conn: *mut MongoHandle, attributes: &str) -> (SqlReturn, String) {
+        let mut attributes = cstr::to_widechar_vec(attributes);
+        attributes.push(0);
+        let mut buffer: [WideChar; 256] = [0; 256];
+        let out_length = &mut 0;
+        unsafe {
+            let sql_return = SQLBrowseConnectW(
+                conn as HDbc,
+                attributes.as_ptr(),
+                SQL_NTS as i16,
+                buffer.as_mut_ptr(),
+                256,
+                out_length,
+            );
+            (
+                sql_return,
+                cstr::from_widechar_ref_lossy(&buffer[..*out_length as usize]),
+            )
+        }
+    }
+
+    #[test]
+    fn attributes_are_requested_in_rounds() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        let (sql_return, output) = browse_connect(conn, "DRIVER={MongoDB Atlas SQL ODBC Driver}");
+        assert_eq!(SqlReturn::NEED_DATA, sql_return);
+        assert!(output.starts_with("SERVER:Server=?;*URI:MongoDB URI=?;*AUTHMECHANISM:"));
+
+        // The driver from the first call is kept, so only the new attributes are supplied.
+        let (sql_return, output) = browse_connect(conn, "SERVER=localhost:27017;UID=foo");
+        assert_eq!(SqlReturn::NEED_DATA, sql_return);
+        assert_eq!("PWD:Password=?", output);
+    }
+
+    #[test]
+    fn invalid_attribute_restarts_browse() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        let (sql_return, _) = browse_connect(conn, "DRIVER=MongoDB;SERVER=localhost:27017");
+        assert_eq!(SqlReturn::NEED_DATA, sql_return);
+        let (sql_return, _) = browse_connect(conn, "NOT_A_KEYWORD=1");
+        assert_eq!(SqlReturn::ERROR, sql_return);
+        assert!(conn
+            .as_connection()
+            .unwrap()
+            .browse_connect_attributes
+            .read()
+            .unwrap()
+            .is_none());
+
+        let (sql_return, output) = browse_connect(conn, "DRIVER=MongoDB");
+        assert_eq!(SqlReturn::NEED_DATA, sql_return);
+        assert!(output.starts_with("SERVER:"));
+    }
+}
@@ -158,3 +158,141 @@ mod integration {
         }
     }
 }
+
+mod unit {
+    use super::*;
+    use crate::{SQLCancelHandle, SQLFetch};
+    use bson::doc;
+    use definitions::{HStmt, HandleType, Nullability};
+    use mongo_odbc_core::{
+        json_schema::{
+            simplified::{Atomic, Schema},
+            BsonTypeName,
+        },
+        mock_query::MongoQuery,
+        Error, MongoColMetadata, MongoStatement, Result, TypeMode,
+    };
+    use mongodb::bson::Bson;
+
+    fn mock_query() -> MongoQuery {
+        MongoQuery::new(
+            (1..=3).map(|x| doc! {"": {"x": x}}).collect(),
+            vec![MongoColMetadata::new(
+                "",
+                "".to_string(),
+                "x".to_string(),
+                Schema::Atomic(Atomic::Scalar(BsonTypeName::Int)),
+                Nullability::SQL_NO_NULLS,
+                TypeMode::Simple,
+                None,
+            )],
+        )
+    }
+
+    // A statement that calls SQLCancel once its first row is fetched, like an application
+    // cancelling a long fetch from another thread.
+    #[derive(Debug)]
+    struct CancelAfterFirstRow {
+        query: MongoQuery,
+        statement_handle: HStmt,
+        rows: usize,
+    }
+
+    impl MongoStatement for CancelAfterFirstRow {
+        fn next(&mut self, connection: Option<&MongoConnection>) -> Result<(bool, Vec<Error>)> {
+            if self.rows == 1 {
+                assert_eq!(SqlReturn::SUCCESS, unsafe {
+                    SQLCancel(self.statement_handle)
+                });
+            }
+            self.rows += 1;
+            self.query.next(connection)
+        }
+
+        fn get_value(
+            &self,
+            col_index: u16,
+            max_string_length: Option<u16>,
+        ) -> Result<Option<Bson>> {
+            self.query.get_value(col_index, max_string_length)
+        }
+
+        fn get_resultset_metadata(&self, max_string_length: Option<u16>) -> &Vec<MongoColMetadata> {
+            self.query.get_resultset_metadata(max_string_length)
+        }
+    }
+
+    #[test]
+    fn test_cancel_fetch_mid_rowset() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            s.attributes.write().unwrap().row_array_size = 3;
+            *s.mongo_statement.write().unwrap() = Some(Box::new(CancelAfterFirstRow {
+                query: mock_query(),
+                statement_handle: stmt as HStmt,
+                rows: 0,
+            }));
+
+            assert_eq!(SqlReturn::ERROR, SQLFetch(stmt as HStmt));
+            assert_eq!(
+                "HY008",
+                s.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+            );
+            assert_eq!(StatementState::Allocated, *s.state.read().unwrap());
+
+            // The statement can be executed and fetched from again after the cancellation.
+            *s.mongo_statement.write().unwrap() = Some(Box::new(mock_query()));
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt as HStmt));
+        }
+    }
+
+    #[test]
+    fn test_cancel_handle_connection() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn: *mut _ =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let executing: *mut _ = &mut MongoHandle::Statement(Statement::with_state(
+            conn,
+            StatementState::SynchronousQueryExecuting,
+        ));
+        let allocated: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let statements = &(*conn).as_connection().unwrap().statements;
+            statements.write().unwrap().insert(executing);
+            statements.write().unwrap().insert(allocated);
+
+            // Only the statements executing on the connection are cancelled.
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLCancelHandle(HandleType::SQL_HANDLE_DBC, conn as *mut _)
+            );
+            let is_cancelled = |stmt: *mut MongoHandle| {
+                (*stmt).as_statement().unwrap().cancellation.is_cancelled()
+            };
+            assert!(is_cancelled(executing));
+            assert!(!is_cancelled(allocated));
+
+            // A statement handle is cancelled like with SQLCancel.
+            *(*allocated).as_statement().unwrap().state.write().unwrap() =
+                StatementState::SynchronousQueryExecuting;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLCancelHandle(HandleType::SQL_HANDLE_STMT, allocated as *mut _)
+            );
+            assert!(is_cancelled(allocated));
+
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLCancelHandle(HandleType::SQL_HANDLE_ENV, env as *mut _ as *mut _)
+            );
+        }
+    }
+}
@@ -10,7 +10,6 @@ use crate::{
 };
 use definitions::{Desc, Nullability, SmallInt, SqlReturn, WChar};
 use mongo_odbc_core::{MongoFields, SQL_SEARCHABLE};
-use std::sync::RwLock;
 
 mod unit {
     use definitions::SqlDataType;
@@ -293,8 +292,8 @@ mod unit {
             ConnectionState::Connected,
         ))));
 
-        let mut stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
-        stmt.mongo_statement = RwLock::new(Some(Box::new(MongoFields::empty())));
+        let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+        *stmt.mongo_statement.write().unwrap() = Some(Box::new(MongoFields::empty()));
         let stmt_handle: *mut _ = &mut MongoHandle::Statement(stmt);
 
         unsafe {
@@ -353,8 +352,8 @@ mod unit {
             ConnectionState::Connected,
         ))));
 
-        let mut stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
-        stmt.mongo_statement = RwLock::new(Some(Box::new(MongoFields::empty())));
+        let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+        *stmt.mongo_statement.write().unwrap() = Some(Box::new(MongoFields::empty()));
         let mongo_handle: *mut _ = &mut MongoHandle::Statement(stmt);
         for desc in [
             // string descriptor
@@ -416,9 +415,9 @@ mod unit {
                 ConnectionState::Connected,
             ))));
 
-            let mut stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+            let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
 
-            stmt.mongo_statement = RwLock::new(Some(Box::new(MongoFields::empty())));
+            *stmt.mongo_statement.write().unwrap() = Some(Box::new(MongoFields::empty()));
             let mongo_handle: *mut _ = &mut MongoHandle::Statement(stmt);
             let col_index = 3; //TABLE_NAME
             for (desc, expected) in [
@@ -476,8 +475,8 @@ mod unit {
             ConnectionState::Connected,
         ))));
 
-        let mut stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
-        stmt.mongo_statement = RwLock::new(Some(Box::new(MongoFields::empty())));
+        let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+        *stmt.mongo_statement.write().unwrap() = Some(Box::new(MongoFields::empty()));
         let mongo_handle: *mut _ = &mut MongoHandle::Statement(stmt);
         let col_index = 3; //TABLE_NAME
         for (desc, expected) in [
@@ -547,8 +546,8 @@ mod unit {
                 ConnectionState::Connected,
             ))));
 
-            let mut stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
-            stmt.mongo_statement = RwLock::new(Some(Box::new(MongoFields::empty())));
+            let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+            *stmt.mongo_statement.write().unwrap() = Some(Box::new(MongoFields::empty()));
             let mongo_handle: *mut _ = &mut MongoHandle::Statement(stmt);
             let invalid_field_identifier = 1;
             assert_eq!(
@@ -594,9 +593,9 @@ mod unit {
                 ConnectionState::Connected,
             ))));
 
-            let mut stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+            let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
 
-            stmt.mongo_statement = RwLock::new(Some(Box::new(MongoFields::empty())));
+            *stmt.mongo_statement.write().unwrap() = Some(Box::new(MongoFields::empty()));
             let mongo_handle: *mut _ = &mut MongoHandle::Statement(stmt);
             let col_index = 3; //TABLE_NAME
             let name_buffer: *mut std::ffi::c_void = Box::into_raw(Box::new([0u8; 40])) as *mut _;
@@ -0,0 +1,74 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{Connection, ConnectionState, Env, EnvState, MongoHandle},
+        SQLConnectW,
+    };
+    use definitions::{HDbc, SqlReturn, SQL_NTS};
+    use std::ptr::null;
+
+    #[test]
+    fn connect_without_dsn_is_err() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let mut user = cstr::to_widechar_vec("foo");
+        user.push(0);
+
+        unsafe {
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLConnectW(
+                    conn as *mut _ as HDbc,
+                    null(),
+                    SQL_NTS as i16,
+                    user.as_ptr(),
+                    SQL_NTS as i16,
+                    null(),
+                    SQL_NTS as i16,
+                )
+            );
+        }
+        let conn = conn.as_connection().unwrap();
+        assert!(conn.mongo_connection.read().unwrap().is_none());
+        assert_eq!(
+            "IM007",
+            conn.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+        );
+    }
+
+    #[test]
+    fn connect_with_unknown_dsn_is_err() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let mut dsn = cstr::to_widechar_vec("not_a_dsn");
+        dsn.push(0);
+
+        unsafe {
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLConnectW(
+                    conn as *mut _ as HDbc,
+                    dsn.as_ptr(),
+                    SQL_NTS as i16,
+                    null(),
+                    SQL_NTS as i16,
+                    null(),
+                    SQL_NTS as i16,
+                )
+            );
+        }
+        let conn = conn.as_connection().unwrap();
+        assert!(conn.mongo_connection.read().unwrap().is_none());
+        assert_eq!(
+            "IM002",
+            conn.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+        );
+    }
+}
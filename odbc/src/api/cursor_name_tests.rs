@@ -0,0 +1,187 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{
+            Connection, ConnectionState, Env, EnvState, MongoHandle, Statement, StatementState,
+        },
+        SQLCloseCursor, SQLGetCursorNameW, SQLGetTypeInfoW, SQLSetCursorNameW,
+    };
+    use cstr::WideChar;
+    use definitions::{HStmt, SmallInt, SqlDataType, SqlReturn, SQL_NTS};
+
+    fn last_sql_state(stmt: *mut MongoHandle) -> String {
+        unsafe {
+            (*stmt)
+                .as_statement()
+                .unwrap()
+                .errors
+                .read()
+                .unwrap()
+                .last()
+                .unwrap()
+                .get_sql_state()
+                .odbc_3_state
+                .to_string()
+        }
+    }
+
+    fn get_cursor_name(stmt: *mut MongoHandle) -> String {
+        let mut buffer: [WideChar; 64] = [0; 64];
+        let name_length = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetCursorNameW(stmt as HStmt, buffer.as_mut_ptr(), 64, name_length)
+            );
+            cstr::from_widechar_ref_lossy(&buffer[..*name_length as usize])
+        }
+    }
+
+    fn set_cursor_name(stmt: *mut MongoHandle, name: &str) -> SqlReturn {
+        let mut name = cstr::to_widechar_vec(name);
+        name.push(0);
+        unsafe { SQLSetCursorNameW(stmt as HStmt, name.as_ptr(), SQL_NTS as i16) }
+    }
+
+    // Allocates a statement on the connection and registers it in the connection's statements.
+    fn alloc_statement(conn: *mut MongoHandle) -> *mut MongoHandle {
+        let stmt = Box::into_raw(Box::new(MongoHandle::Statement(Statement::with_state(
+            conn,
+            StatementState::Allocated,
+        ))));
+        unsafe {
+            (*conn)
+                .as_connection()
+                .unwrap()
+                .statements
+                .write()
+                .unwrap()
+                .insert(stmt);
+        }
+        stmt
+    }
+
+    #[test]
+    fn generated_names_are_unique() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let first = alloc_statement(conn);
+        let second = alloc_statement(conn);
+
+        let first_name = get_cursor_name(first);
+        let second_name = get_cursor_name(second);
+        assert!(first_name.starts_with("SQL_CUR"));
+        assert!(second_name.starts_with("SQL_CUR"));
+        assert_ne!(first_name, second_name);
+    }
+
+    #[test]
+    fn get_truncated_name() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+        assert_eq!(SqlReturn::SUCCESS, set_cursor_name(stmt, "orders"));
+
+        let mut buffer: [WideChar; 4] = [0; 4];
+        let name_length = &mut 0;
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS_WITH_INFO,
+                SQLGetCursorNameW(stmt as HStmt, buffer.as_mut_ptr(), 4, name_length)
+            );
+            assert_eq!("01004", last_sql_state(stmt));
+            assert_eq!("ord", cstr::from_widechar_ref_lossy(&buffer[..3]));
+        }
+    }
+
+    #[test]
+    fn set_and_get_name() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+
+        assert_eq!(SqlReturn::SUCCESS, set_cursor_name(stmt, "orders_cursor"));
+        assert_eq!("orders_cursor", get_cursor_name(stmt));
+    }
+
+    #[test]
+    fn reserved_or_empty_names_are_invalid() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+        let generated_name = get_cursor_name(stmt);
+
+        for name in ["", "SQL_CUR42", "sqlcur_orders"] {
+            assert_eq!(SqlReturn::ERROR, set_cursor_name(stmt, name));
+            assert_eq!("34000", last_sql_state(stmt));
+        }
+        assert_eq!(generated_name, get_cursor_name(stmt));
+    }
+
+    #[test]
+    fn duplicate_names_are_rejected() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let first = alloc_statement(conn);
+        let second = alloc_statement(conn);
+
+        assert_eq!(SqlReturn::SUCCESS, set_cursor_name(first, "orders"));
+        // A statement can be renamed with its own name.
+        assert_eq!(SqlReturn::SUCCESS, set_cursor_name(first, "orders"));
+        assert_eq!(SqlReturn::ERROR, set_cursor_name(second, "ORDERS"));
+        assert_eq!("3C000", last_sql_state(second));
+
+        // Statements of another connection can use the same name.
+        let other_conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let other = alloc_statement(other_conn);
+        assert_eq!(SqlReturn::SUCCESS, set_cursor_name(other, "orders"));
+    }
+
+    #[test]
+    fn name_cannot_change_while_cursor_is_open() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+
+        unsafe {
+            *(*stmt).as_statement().unwrap().cursor_open.write().unwrap() = true;
+            assert_eq!(SqlReturn::ERROR, set_cursor_name(stmt, "orders"));
+            assert_eq!("24000", last_sql_state(stmt));
+
+            assert_eq!(SqlReturn::SUCCESS, SQLCloseCursor(stmt as HStmt));
+            assert_eq!(SqlReturn::SUCCESS, set_cursor_name(stmt, "orders"));
+        }
+    }
+
+    #[test]
+    fn catalog_functions_open_the_cursor() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt = alloc_statement(conn);
+
+        unsafe {
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetTypeInfoW(stmt as HStmt, SqlDataType::SQL_INTEGER as SmallInt)
+            );
+            assert_eq!(SqlReturn::ERROR, set_cursor_name(stmt, "orders"));
+            assert_eq!("24000", last_sql_state(stmt));
+
+            assert_eq!(SqlReturn::SUCCESS, SQLCloseCursor(stmt as HStmt));
+            assert_eq!(SqlReturn::SUCCESS, set_cursor_name(stmt, "orders"));
+        }
+    }
+}
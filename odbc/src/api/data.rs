@@ -11,7 +11,8 @@ use cstr::{
     write_wstring_slice_to_buffer, WideChar,
 };
 use definitions::{
-    CDataType, Char, Date, Integer, Len, Pointer, SmallInt, SqlReturn, Time, Timestamp, USmallInt,
+    CDataType, Char, Date, Integer, Len, Pointer, SmallInt, SqlDataType, SqlReturn, Time,
+    Timestamp, USmallInt,
 };
 use mongodb::bson::{spec::BinarySubtype, Bson, UuidRepresentation};
 use regex::Regex;
@@ -847,6 +848,77 @@ pub unsafe fn format_cached_data(
         }
     }
 }
+///
+/// truncate_json truncates the json representation of a value to at most `max_length` bytes,
+/// without splitting a character. A `max_length` of 0 means there is no limit.
+///
+fn truncate_json(mut json: String, max_length: usize) -> String {
+    if max_length > 0 && json.len() > max_length {
+        let end = (0..=max_length)
+            .rev()
+            .find(|i| json.is_char_boundary(*i))
+            .unwrap_or(0);
+        json.truncate(end);
+    }
+    json
+}
+
+///
+/// truncate_wide truncates wide character data to at most `max_length` bytes, without splitting a
+/// surrogate pair. A `max_length` of 0 means there is no limit.
+///
+fn truncate_wide(mut data: Vec<WideChar>, max_length: usize) -> Vec<WideChar> {
+    let max_chars = max_length / size_of::<WideChar>();
+    if max_length > 0 && data.len() > max_chars {
+        data.truncate(max_chars);
+        if matches!(data.last(), Some(0xD800..=0xDBFF)) {
+            data.pop();
+        }
+    }
+    data
+}
+
+///
+/// is_variable_length_column returns whether SQL_ATTR_MAX_LENGTH applies to a column, which it
+/// does for columns of character and binary data. Documents, arrays and the other types without an
+/// SQL equivalent are rendered as JSON, so they count as character data, while numbers, booleans
+/// and dates converted to characters are never truncated.
+///
+fn is_variable_length_column(stmt: &Statement, col_num: USmallInt) -> bool {
+    let max_string_length = stmt.get_max_string_length();
+    let sql_type = stmt
+        .mongo_statement
+        .read()
+        .unwrap()
+        .as_ref()
+        .and_then(|mongo_statement| {
+            mongo_statement
+                .get_resultset_metadata(max_string_length)
+                .get(usize::from(col_num).checked_sub(1)?)
+                .map(|col| col.sql_type)
+        });
+    matches!(
+        sql_type,
+        None | Some(
+            SqlDataType::SQL_CHAR
+                | SqlDataType::SQL_VARCHAR
+                | SqlDataType::SQL_LONGVARCHAR
+                | SqlDataType::SQL_WCHAR
+                | SqlDataType::SQL_WVARCHAR
+                | SqlDataType::SQL_WLONGVARCHAR
+                | SqlDataType::SQL_BINARY
+                | SqlDataType::SQL_VARBINARY
+                | SqlDataType::SQL_LONGVARBINARY
+                | SqlDataType::SQL_UNKNOWN_TYPE
+        )
+    )
+}
+
+///
+/// format_bson_data writes the value of a column to the target buffer, converted to the target
+/// type. Character and binary data longer than the SQL_ATTR_MAX_LENGTH of the statement are
+/// truncated to it, without a truncation warning.
+///
 #[allow(clippy::too_many_arguments)]
 pub unsafe fn format_bson_data(
     mongo_handle: &mut MongoHandle,
@@ -890,13 +962,24 @@ pub unsafe fn format_bson_data(
         },
         None => None,
     };
+    let max_length = match (*mongo_handle).as_statement() {
+        Some(stmt) if is_variable_length_column(stmt, col_num) => {
+            stmt.attributes.read().unwrap().max_length
+        }
+        _ => 0,
+    };
 
     match target_type {
         CDataType::SQL_C_BINARY | CDataType::SQL_C_GUID => {
             let data = if target_type == CDataType::SQL_C_GUID {
                 data.to_guid(uuid_repr)
             } else {
-                data.to_binary(uuid_repr)
+                data.to_binary(uuid_repr).map(|mut data| {
+                    if max_length > 0 {
+                        data.truncate(max_length);
+                    }
+                    data
+                })
             };
             match data {
                 Ok(data) => format_binary(
@@ -918,7 +1001,7 @@ pub unsafe fn format_bson_data(
             }
         }
         CDataType::SQL_C_CHAR => {
-            let data = data.to_json(uuid_repr).bytes().collect::<Vec<u8>>();
+            let data = truncate_json(data.to_json(uuid_repr), max_length).into_bytes();
             char_data!(
                 mongo_handle,
                 col_num,
@@ -932,7 +1015,7 @@ pub unsafe fn format_bson_data(
             )
         }
         CDataType::SQL_C_WCHAR => {
-            let data = cstr::to_widechar_vec(&data.to_json(uuid_repr));
+            let data = truncate_wide(cstr::to_widechar_vec(&data.to_json(uuid_repr)), max_length);
             char_data!(
                 mongo_handle,
                 col_num,
@@ -1217,6 +1300,51 @@ pub mod i16_len {
         ret
     }
 
+    ///
+    /// set_output_string writes [`message`] to the *Char [`output_ptr`]. [`buffer_len`] is the
+    /// length of the [`output_ptr`] buffer in bytes; the message should be truncated
+    /// if it is longer than the buffer length. The number of bytes written to [`output_ptr`]
+    /// should be stored in [`text_length_ptr`].
+    ///
+    /// # Safety
+    /// This writes to multiple raw C-pointers
+    ///
+    pub unsafe fn set_output_string(
+        message: &str,
+        output_ptr: *mut Char,
+        buffer_len: usize,
+        text_length_ptr: *mut SmallInt,
+    ) -> SqlReturn {
+        let (len, ret) = set_output_string_helper(message.as_bytes(), output_ptr, buffer_len);
+        ptr_safe_write(
+            text_length_ptr,
+            len.try_into().expect("Data too large to fit"),
+        );
+        ret
+    }
+
+    ///
+    /// set_output_string_as_bytes writes [`message`] to the Pointer [`output_ptr`]. It is the
+    /// ANSI counterpart of set_output_wstring_as_bytes, and since UTF-8 characters are counted in
+    /// bytes, it is the same as set_output_string.
+    ///
+    /// # Safety
+    /// This writes to multiple raw C-pointers
+    ///
+    pub unsafe fn set_output_string_as_bytes(
+        message: &str,
+        output_ptr: Pointer,
+        buffer_len: usize,
+        text_length_ptr: *mut SmallInt,
+    ) -> SqlReturn {
+        set_output_string(
+            message,
+            output_ptr.cast::<Char>(),
+            buffer_len,
+            text_length_ptr,
+        )
+    }
+
     ///
     /// set_output_fixed_data writes [`data`], which must be a fixed sized type, to the Pointer [`output_ptr`].
     /// ODBC drivers assume the output buffer is large enough for fixed types, and are allowed to
@@ -1275,6 +1403,75 @@ pub mod i32_len {
         ret
     }
 
+    ///
+    /// set_output_wstring writes [`message`] to the *WideChar [`output_ptr`]. [`buffer_len`] is the
+    /// length of the [`output_ptr`] buffer in characters; the message should be truncated
+    /// if it is longer than the buffer length. The number of characters written to [`output_ptr`]
+    /// should be stored in [`text_length_ptr`].
+    ///
+    /// # Safety
+    /// This writes to multiple raw C-pointers
+    ///
+    pub unsafe fn set_output_wstring(
+        message: &str,
+        output_ptr: *mut WideChar,
+        buffer_len: usize,
+        text_length_ptr: *mut Integer,
+    ) -> SqlReturn {
+        let message = cstr::to_widechar_vec(message);
+        let (len, ret) = set_output_wstring_helper(&message, output_ptr, buffer_len, None);
+        ptr_safe_write(
+            text_length_ptr,
+            len.try_into().expect("Data too large to fit"),
+        );
+        ret
+    }
+
+    ///
+    /// set_output_string writes [`message`] to the *Char [`output_ptr`]. [`buffer_len`] is the
+    /// length of the [`output_ptr`] buffer in bytes; the message should be truncated
+    /// if it is longer than the buffer length. The number of bytes written to [`output_ptr`]
+    /// should be stored in [`text_length_ptr`].
+    ///
+    /// # Safety
+    /// This writes to multiple raw C-pointers
+    ///
+    pub unsafe fn set_output_string(
+        message: &str,
+        output_ptr: *mut Char,
+        buffer_len: usize,
+        text_length_ptr: *mut Integer,
+    ) -> SqlReturn {
+        let (len, ret) = set_output_string_helper(message.as_bytes(), output_ptr, buffer_len);
+        ptr_safe_write(
+            text_length_ptr,
+            len.try_into().expect("Data too large to fit"),
+        );
+        ret
+    }
+
+    ///
+    /// set_output_string_as_bytes writes [`message`] to the Pointer [`output_ptr`]. It is the
+    /// ANSI counterpart of set_output_wstring_as_bytes, and since UTF-8 characters are counted in
+    /// bytes, it is the same as set_output_string.
+    ///
+    /// # Safety
+    /// This writes to multiple raw C-pointers
+    ///
+    pub unsafe fn set_output_string_as_bytes(
+        message: &str,
+        output_ptr: Pointer,
+        buffer_len: usize,
+        text_length_ptr: *mut Integer,
+    ) -> SqlReturn {
+        set_output_string(
+            message,
+            output_ptr.cast::<Char>(),
+            buffer_len,
+            text_length_ptr,
+        )
+    }
+
     ///
     /// set_output_fixed_data writes [`data`], which must be a fixed sized type, to the Pointer [`output_ptr`].
     /// ODBC drivers assume the output buffer is large enough for fixed types, and are allowed to
@@ -0,0 +1,117 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{Env, EnvState, MongoHandle},
+        SQLDataSourcesW, SQLDriversW,
+    };
+    use definitions::{FetchOrientation, HEnv, SqlReturn};
+    use shared_sql_utils::odbcinst::{ODBC_BOTH_DSN, ODBC_USER_DSN};
+    use std::ptr::null_mut;
+
+    fn last_sql_state(env: &MongoHandle) -> String {
+        env.as_env()
+            .unwrap()
+            .errors
+            .read()
+            .unwrap()
+            .last()
+            .unwrap()
+            .get_sql_state()
+            .odbc_3_state
+            .to_string()
+    }
+
+    #[test]
+    fn invalid_direction_is_err() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        unsafe {
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLDataSourcesW(
+                    env as *mut _ as HEnv,
+                    FetchOrientation::SQL_FETCH_LAST as u16,
+                    null_mut(),
+                    0,
+                    null_mut(),
+                    null_mut(),
+                    0,
+                    null_mut(),
+                )
+            );
+            assert_eq!("HY103", last_sql_state(env));
+            // Only SQLDataSources can enumerate user or system DSNs.
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLDriversW(
+                    env as *mut _ as HEnv,
+                    FetchOrientation::SQL_FETCH_FIRST_USER as u16,
+                    null_mut(),
+                    0,
+                    null_mut(),
+                    null_mut(),
+                    0,
+                    null_mut(),
+                )
+            );
+            assert_eq!("HY103", last_sql_state(env));
+        }
+    }
+
+    #[test]
+    fn next_starts_over_after_last_entry() {
+        let env: *mut _ = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        unsafe {
+            let next_driver = || *(*env).as_env().unwrap().next_driver.read().unwrap();
+            // Each call returns the next driver until NO_DATA, whatever drivers are installed.
+            let mut returned = 0;
+            while SQLDriversW(
+                env as HEnv,
+                FetchOrientation::SQL_FETCH_NEXT as u16,
+                null_mut(),
+                0,
+                null_mut(),
+                null_mut(),
+                0,
+                null_mut(),
+            ) != SqlReturn::NO_DATA
+            {
+                returned += 1;
+                assert_eq!(returned, next_driver());
+            }
+            assert_eq!(0, next_driver());
+        }
+    }
+
+    #[test]
+    fn next_keeps_the_kind_of_data_sources() {
+        let env: *mut _ = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        unsafe {
+            let data_source_mode = || *(*env).as_env().unwrap().data_source_mode.read().unwrap();
+            for (direction, config_mode) in [
+                (FetchOrientation::SQL_FETCH_FIRST_USER, ODBC_USER_DSN),
+                (FetchOrientation::SQL_FETCH_NEXT, ODBC_USER_DSN),
+                (FetchOrientation::SQL_FETCH_FIRST, ODBC_BOTH_DSN),
+            ] {
+                assert_ne!(
+                    SqlReturn::ERROR,
+                    SQLDataSourcesW(
+                        env as HEnv,
+                        direction as u16,
+                        null_mut(),
+                        0,
+                        null_mut(),
+                        null_mut(),
+                        0,
+                        null_mut(),
+                    )
+                );
+                assert_eq!(config_mode, data_source_mode());
+            }
+        }
+    }
+}
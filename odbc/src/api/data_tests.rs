@@ -3366,9 +3366,15 @@ mod unit_tests {
             attributes: RwLock::new(ConnectionAttributes::default()),
             state: RwLock::new(ConnectionState::Connected),
             statements: RwLock::new(HashSet::new()),
+            descriptors: RwLock::new(HashSet::new()),
             errors: RwLock::new(vec![]),
             type_mode: RwLock::new(TypeMode::Simple),
             max_string_length: RwLock::new(Some(6)),
+            static_cursor_spill_threshold: RwLock::new(
+                constants::DEFAULT_STATIC_CURSOR_SPILL_THRESHOLD,
+            ),
+            accurate_row_count: RwLock::new(false),
+            browse_connect_attributes: RwLock::new(None),
         })));
 
         // use simple type mode to test string columns for complex types
@@ -3433,6 +3439,145 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn sql_get_data_max_length_set() {
+        use crate::api::functions::{SQLGetData, SQLSetStmtAttrW};
+        use cstr::{input_text_to_string_a, input_text_to_string_w};
+        use definitions::{CDataType, Pointer, StatementAttribute};
+
+        let env = Box::into_raw(Box::new(MongoHandle::Env(Env::with_state(
+            EnvState::ConnectionAllocated,
+        ))));
+        let conn = Box::into_raw(Box::new(MongoHandle::Connection(Connection::with_state(
+            env as *mut _,
+            ConnectionState::Connected,
+        ))));
+        let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+        *stmt.mongo_statement.write().unwrap() = Some(Box::new(STANDARD_BSON_TYPE_MQ.clone()));
+
+        let stmt_handle: *mut _ = &mut MongoHandle::Statement(stmt);
+        unsafe {
+            // data longer than SQL_ATTR_MAX_LENGTH is truncated without a truncation warning.
+            let get_data = |max_length: usize, col: u16, target_type: CDataType| {
+                assert_eq!(
+                    SqlReturn::SUCCESS,
+                    SQLSetStmtAttrW(
+                        stmt_handle as *mut _,
+                        StatementAttribute::SQL_ATTR_MAX_LENGTH as i32,
+                        max_length as Pointer,
+                        0,
+                    )
+                );
+                let buffer: *mut std::ffi::c_void = Box::into_raw(Box::new([0u8; 200])) as *mut _;
+                let out_len_or_ind = &mut 0;
+                assert_eq!(
+                    SqlReturn::SUCCESS,
+                    SQLGetData(
+                        stmt_handle as *mut _,
+                        col,
+                        target_type as i16,
+                        buffer,
+                        200,
+                        out_len_or_ind,
+                    )
+                );
+                (buffer, *out_len_or_ind)
+            };
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt_handle as *mut _,));
+
+            let (buffer, out_len) = get_data(5, STRING_COL, CDataType::SQL_C_CHAR);
+            assert_eq!(5, out_len);
+            assert_eq!("hello", input_text_to_string_a(buffer as *const _, out_len));
+            let _ = Box::from_raw(buffer as *mut [u8; 200]);
+
+            // multibyte characters are not split.
+            let (buffer, out_len) = get_data(7, UNICODE_COL, CDataType::SQL_C_CHAR);
+            assert_eq!(6, out_len);
+            assert_eq!("你好", input_text_to_string_a(buffer as *const _, out_len));
+            let _ = Box::from_raw(buffer as *mut [u8; 200]);
+
+            let (buffer, out_len) = get_data(5, BIN_COL, CDataType::SQL_C_WCHAR);
+            assert_eq!(2 * std::mem::size_of::<WideChar>() as isize, out_len);
+            assert_eq!("{\"", input_text_to_string_w(buffer as *const _, 2));
+            let _ = Box::from_raw(buffer as *mut [u8; 200]);
+
+            // documents are rendered as JSON, so they are truncated like character data.
+            let (buffer, out_len) = get_data(2, DOC_COL, CDataType::SQL_C_CHAR);
+            assert_eq!(2, out_len);
+            assert_eq!("{\"", input_text_to_string_a(buffer as *const _, out_len));
+            let _ = Box::from_raw(buffer as *mut [u8; 200]);
+
+            // numbers and dates converted to characters are not truncated.
+            for (col, expected) in [DOUBLE_STR_VAL, DATETIME_STR_VAL] {
+                let (buffer, out_len) = get_data(2, col, CDataType::SQL_C_CHAR);
+                assert_eq!(
+                    expected,
+                    input_text_to_string_a(buffer as *const _, out_len)
+                );
+                let _ = Box::from_raw(buffer as *mut [u8; 200]);
+            }
+
+            let (buffer, out_len) = get_data(2, GUID_COL, CDataType::SQL_C_BINARY);
+            assert_eq!(2, out_len);
+            assert_eq!(GUID_STR_VAL.1.as_bytes()[..2], *(buffer as *const [u8; 2]));
+            let _ = Box::from_raw(buffer as *mut [u8; 200]);
+
+            // 0 means there is no limit.
+            let (buffer, out_len) = get_data(0, ARRAY_COL, CDataType::SQL_C_CHAR);
+            assert_eq!(ARRAY_STR_VAL.1.len() as isize, out_len);
+            let _ = Box::from_raw(buffer as *mut [u8; 200]);
+
+            let _ = Box::from_raw(conn as *mut WChar);
+            let _ = Box::from_raw(env as *mut WChar);
+        }
+    }
+
+    #[test]
+    fn sql_row_count() {
+        use crate::api::functions::SQLRowCount;
+        use definitions::Len;
+
+        let env = Box::into_raw(Box::new(MongoHandle::Env(Env::with_state(
+            EnvState::ConnectionAllocated,
+        ))));
+        let conn = Box::into_raw(Box::new(MongoHandle::Connection(Connection::with_state(
+            env as *mut _,
+            ConnectionState::Connected,
+        ))));
+        let stmt = Statement::with_state(conn as *mut _, StatementState::Allocated);
+        *stmt.mongo_statement.write().unwrap() = Some(Box::new(STANDARD_BSON_TYPE_MQ.clone()));
+
+        let stmt_handle: *mut _ = &mut MongoHandle::Statement(stmt);
+        unsafe {
+            let row_count = |expected: Len| {
+                let mut row_count: Len = 42;
+                assert_eq!(
+                    SqlReturn::SUCCESS,
+                    SQLRowCount(stmt_handle as *mut _, &mut row_count)
+                );
+                assert_eq!(expected, row_count);
+            };
+            // SQLRowCount returns 0 unless accurate_row_count is set.
+            row_count(0);
+
+            // The row count is -1 until the whole result set was fetched.
+            *(*conn)
+                .as_connection()
+                .unwrap()
+                .accurate_row_count
+                .write()
+                .unwrap() = true;
+            row_count(-1);
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt_handle as *mut _,));
+            row_count(-1);
+            assert_eq!(SqlReturn::NO_DATA, SQLFetch(stmt_handle as *mut _,));
+            row_count(1);
+
+            let _ = Box::from_raw(conn as *mut WChar);
+            let _ = Box::from_raw(env as *mut WChar);
+        }
+    }
+
     #[test]
     fn sql_fetch_and_more_results_basic_functionality_test() {
         sql_fetch_and_more_results_basic_functionality(TypeMode::Standard);
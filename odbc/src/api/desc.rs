@@ -0,0 +1,685 @@
+use crate::{
+    api::{errors::ODBCError, util::handle_sql_type},
+    handles::definitions::{
+        AppRowDesc, BoundColInfo, BoundParamInfo, Descriptor, DescriptorState, DescriptorType,
+        MongoHandle, Statement,
+    },
+};
+use definitions::{
+    AllocType, AttrOdbcVersion, CDataType, Desc, Integer, Len, Nullability, ParamType, Pointer,
+    SmallInt, SqlDataType, ULen, USmallInt,
+};
+use mongo_odbc_core::MongoColMetadata;
+use num_traits::FromPrimitive;
+use std::{collections::HashMap, ptr::null_mut};
+
+type Result<T> = std::result::Result<T, ODBCError>;
+
+///
+/// DescField is the value of a descriptor field, with the type SQLGetDescField returns it as.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DescField {
+    SmallInt(SmallInt),
+    Integer(Integer),
+    Len(Len),
+    ULen(ULen),
+    Pointer(Pointer),
+    String(String),
+}
+
+///
+/// DescRec is a descriptor record as returned by SQLGetDescRec.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct DescRec {
+    pub name: String,
+    pub desc_type: SmallInt,
+    pub sub_type: SmallInt,
+    pub length: Len,
+    pub precision: SmallInt,
+    pub scale: SmallInt,
+    pub nullable: SmallInt,
+}
+
+fn desc_type(desc: &Descriptor) -> DescriptorType {
+    desc.attributes.read().unwrap().desc_type
+}
+
+fn is_implicit(desc: &Descriptor) -> bool {
+    *desc.state.read().unwrap() == DescriptorState::ImplicitlyAllocated
+}
+
+// statement returns the statement an implicitly allocated descriptor belongs to.
+unsafe fn statement<'a>(desc: &Descriptor) -> Result<&'a Statement> {
+    let stmt = desc.attributes.read().unwrap().statement;
+    stmt.as_ref()
+        .and_then(MongoHandle::as_statement)
+        .ok_or(ODBCError::AssociatedStatementNotPrepared)
+}
+
+// app_desc returns the fields of an application descriptor. The records of an application
+// parameter descriptor are the parameters bound with SQLBindParameter.
+unsafe fn app_desc(desc: &Descriptor) -> Result<AppRowDesc> {
+    if !is_implicit(desc) {
+        return Ok(desc.attributes.read().unwrap().app_row_desc.clone());
+    }
+    let stmt = statement(desc)?;
+    if desc_type(desc) == DescriptorType::AppRow {
+        return Ok(stmt.implicit_app_row_desc());
+    }
+    let attributes = stmt.attributes.read().unwrap();
+    Ok(AppRowDesc {
+        bound_cols: stmt.bound_params.read().unwrap().as_ref().map(|params| {
+            params
+                .iter()
+                .map(|(&number, param)| {
+                    (
+                        number,
+                        BoundColInfo {
+                            target_type: param.value_type,
+                            target_buffer: param.parameter_value_ptr,
+                            buffer_length: param.buffer_length,
+                            length_or_indicator: param.str_len_or_ind_ptr,
+                        },
+                    )
+                })
+                .collect()
+        }),
+        array_size: attributes.paramset_size,
+        bind_offset_ptr: attributes.param_bind_offset_ptr,
+        bind_type: attributes.param_bind_type,
+    })
+}
+
+// set_app_desc sets the fields of an application descriptor. Parameters keep the fields of
+// their implementation parameter descriptor record.
+unsafe fn set_app_desc(desc: &Descriptor, fields: AppRowDesc) -> Result<()> {
+    if !is_implicit(desc) {
+        desc.attributes.write().unwrap().app_row_desc = fields;
+        return Ok(());
+    }
+    let stmt = statement(desc)?;
+    if desc_type(desc) == DescriptorType::AppRow {
+        stmt.set_implicit_app_row_desc(fields);
+        return Ok(());
+    }
+    {
+        let mut attributes = stmt.attributes.write().unwrap();
+        attributes.paramset_size = fields.array_size;
+        attributes.param_bind_offset_ptr = fields.bind_offset_ptr;
+        attributes.param_bind_type = fields.bind_type;
+    }
+    let mut bound_params = stmt.bound_params.write().unwrap();
+    *bound_params = fields.bound_cols.map(|records| {
+        records
+            .into_iter()
+            .filter(|(number, _)| *number > 0)
+            .map(|(number, record)| {
+                let param = bound_params
+                    .as_ref()
+                    .and_then(|params| params.get(&number))
+                    .copied()
+                    .unwrap_or_else(default_param);
+                (
+                    number,
+                    BoundParamInfo {
+                        value_type: record.target_type,
+                        parameter_value_ptr: record.target_buffer,
+                        buffer_length: record.buffer_length,
+                        str_len_or_ind_ptr: record.length_or_indicator,
+                        ..param
+                    },
+                )
+            })
+            .collect()
+    });
+    Ok(())
+}
+
+fn default_col() -> BoundColInfo {
+    BoundColInfo {
+        target_type: CDataType::SQL_C_DEFAULT as SmallInt,
+        target_buffer: null_mut(),
+        buffer_length: 0,
+        length_or_indicator: null_mut(),
+    }
+}
+
+fn default_param() -> BoundParamInfo {
+    BoundParamInfo {
+        input_output_type: ParamType::SQL_PARAM_INPUT as SmallInt,
+        value_type: CDataType::SQL_C_DEFAULT as SmallInt,
+        parameter_type: SqlDataType::SQL_UNKNOWN_TYPE as SmallInt,
+        column_size: 0,
+        decimal_digits: 0,
+        parameter_value_ptr: null_mut(),
+        buffer_length: 0,
+        str_len_or_ind_ptr: null_mut(),
+    }
+}
+
+// count returns the number of the highest record, which is 0 when there are no records.
+fn count<T>(records: Option<&HashMap<USmallInt, T>>) -> SmallInt {
+    records
+        .and_then(|records| records.keys().max().copied())
+        .map_or(0, |max| SmallInt::try_from(max).unwrap_or(SmallInt::MAX))
+}
+
+fn is_header_field(field: Desc) -> bool {
+    matches!(
+        field,
+        Desc::SQL_DESC_ALLOC_TYPE
+            | Desc::SQL_DESC_ARRAY_SIZE
+            | Desc::SQL_DESC_ARRAY_STATUS_PTR
+            | Desc::SQL_DESC_BIND_OFFSET_PTR
+            | Desc::SQL_DESC_BIND_TYPE
+            | Desc::SQL_DESC_COUNT
+            | Desc::SQL_DESC_ROWS_PROCESSED_PTR
+    )
+}
+
+// record_number checks that a record field is read from or written to a valid record. Only
+// application row descriptors have a record 0, which is the bookmark column.
+fn record_number(desc: &Descriptor, rec_number: SmallInt) -> Result<USmallInt> {
+    match USmallInt::try_from(rec_number) {
+        Ok(0) if desc_type(desc) != DescriptorType::AppRow => {
+            Err(ODBCError::InvalidDescriptorIndex(0))
+        }
+        Ok(rec) => Ok(rec),
+        Err(_) => Err(ODBCError::InvalidDescriptorIndex(rec_number as USmallInt)),
+    }
+}
+
+///
+/// get_desc_field returns the value of a field of a descriptor, or None if the record does not
+/// exist.
+///
+/// # Safety
+/// The statement of an implicitly allocated descriptor is read through a raw pointer.
+///
+pub(crate) unsafe fn get_desc_field(
+    desc: &Descriptor,
+    odbc_version: AttrOdbcVersion,
+    rec_number: SmallInt,
+    field: Desc,
+) -> Result<Option<DescField>> {
+    if field == Desc::SQL_DESC_ALLOC_TYPE {
+        let alloc_type = if is_implicit(desc) {
+            AllocType::SQL_DESC_ALLOC_AUTO
+        } else {
+            AllocType::SQL_DESC_ALLOC_USER
+        };
+        return Ok(Some(DescField::SmallInt(alloc_type as SmallInt)));
+    }
+    match desc_type(desc) {
+        DescriptorType::AppRow | DescriptorType::AppParam => {
+            get_app_desc_field(&app_desc(desc)?, desc, rec_number, field)
+        }
+        DescriptorType::ImpRow => get_imp_row_desc_field(desc, odbc_version, rec_number, field),
+        DescriptorType::ImpParam => get_imp_param_desc_field(desc, rec_number, field),
+    }
+}
+
+fn get_app_desc_field(
+    fields: &AppRowDesc,
+    desc: &Descriptor,
+    rec_number: SmallInt,
+    field: Desc,
+) -> Result<Option<DescField>> {
+    let value = match field {
+        Desc::SQL_DESC_ARRAY_SIZE => DescField::ULen(fields.array_size),
+        // Row and parameter operation arrays are not supported.
+        Desc::SQL_DESC_ARRAY_STATUS_PTR => DescField::Pointer(null_mut()),
+        Desc::SQL_DESC_BIND_OFFSET_PTR => DescField::Pointer(fields.bind_offset_ptr.cast()),
+        Desc::SQL_DESC_BIND_TYPE => {
+            DescField::Integer(Integer::try_from(fields.bind_type).unwrap_or(Integer::MAX))
+        }
+        Desc::SQL_DESC_COUNT => DescField::SmallInt(count(fields.bound_cols.as_ref())),
+        _ if is_header_field(field) => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+        _ => {
+            let rec = record_number(desc, rec_number)?;
+            let Some(record) = fields.bound_cols.as_ref().and_then(|cols| cols.get(&rec)) else {
+                return Ok(None);
+            };
+            match field {
+                Desc::SQL_DESC_TYPE | Desc::SQL_DESC_CONCISE_TYPE => {
+                    DescField::SmallInt(record.target_type)
+                }
+                Desc::SQL_DESC_DATA_PTR => DescField::Pointer(record.target_buffer),
+                Desc::SQL_DESC_INDICATOR_PTR | Desc::SQL_DESC_OCTET_LENGTH_PTR => {
+                    DescField::Pointer(record.length_or_indicator.cast())
+                }
+                Desc::SQL_DESC_OCTET_LENGTH => DescField::Len(record.buffer_length),
+                _ => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+            }
+        }
+    };
+    Ok(Some(value))
+}
+
+unsafe fn get_imp_row_desc_field(
+    desc: &Descriptor,
+    odbc_version: AttrOdbcVersion,
+    rec_number: SmallInt,
+    field: Desc,
+) -> Result<Option<DescField>> {
+    let stmt = statement(desc)?;
+    match field {
+        Desc::SQL_DESC_ARRAY_STATUS_PTR => {
+            return Ok(Some(DescField::Pointer(
+                stmt.attributes.read().unwrap().row_status_ptr.cast(),
+            )))
+        }
+        Desc::SQL_DESC_ROWS_PROCESSED_PTR => {
+            return Ok(Some(DescField::Pointer(
+                stmt.attributes.read().unwrap().rows_fetched_ptr.cast(),
+            )))
+        }
+        _ => {}
+    }
+    // The records of the implementation row descriptor are the columns of the result set.
+    let max_string_length = stmt.get_max_string_length();
+    let mongo_stmt = stmt.mongo_statement.read().unwrap();
+    let mongo_stmt = mongo_stmt
+        .as_ref()
+        .ok_or(ODBCError::AssociatedStatementNotPrepared)?;
+    if field == Desc::SQL_DESC_COUNT {
+        let count = mongo_stmt.get_resultset_metadata(max_string_length).len();
+        return Ok(Some(DescField::SmallInt(
+            SmallInt::try_from(count).unwrap_or(SmallInt::MAX),
+        )));
+    }
+    if is_header_field(field) {
+        return Err(ODBCError::InvalidFieldDescriptor(field as u16));
+    }
+    let rec = record_number(desc, rec_number)?;
+    let Ok(col) = mongo_stmt.get_col_metadata(rec, max_string_length) else {
+        return Ok(None);
+    };
+    imp_row_desc_field(col, odbc_version, field).map(Some)
+}
+
+fn imp_row_desc_field(
+    col: &MongoColMetadata,
+    odbc_version: AttrOdbcVersion,
+    field: Desc,
+) -> Result<DescField> {
+    let small_int = |value: Option<u16>| {
+        DescField::SmallInt(SmallInt::try_from(value.unwrap_or(0)).unwrap_or(SmallInt::MAX))
+    };
+    Ok(match field {
+        Desc::SQL_DESC_TYPE => DescField::SmallInt(col.non_concise_type as SmallInt),
+        Desc::SQL_DESC_CONCISE_TYPE => {
+            DescField::SmallInt(handle_sql_type(odbc_version, col.sql_type) as SmallInt)
+        }
+        Desc::SQL_DESC_DATETIME_INTERVAL_CODE => {
+            DescField::SmallInt(col.sql_code.map_or(0, |code| code as SmallInt))
+        }
+        Desc::SQL_DESC_NAME => DescField::String(col.col_name.clone()),
+        Desc::SQL_DESC_LABEL => DescField::String(col.label.clone()),
+        Desc::SQL_DESC_BASE_COLUMN_NAME => DescField::String(col.base_col_name.clone()),
+        Desc::SQL_DESC_BASE_TABLE_NAME => DescField::String(col.base_table_name.clone()),
+        Desc::SQL_DESC_CATALOG_NAME => DescField::String(col.catalog_name.clone()),
+        Desc::SQL_DESC_TABLE_NAME => DescField::String(col.table_name.clone()),
+        Desc::SQL_DESC_TYPE_NAME => DescField::String(col.type_name.clone()),
+        Desc::SQL_DESC_LOCAL_TYPE_NAME | Desc::SQL_DESC_SCHEMA_NAME => {
+            DescField::String(String::new())
+        }
+        Desc::SQL_DESC_LITERAL_PREFIX => {
+            DescField::String(col.literal_prefix.unwrap_or("").to_string())
+        }
+        Desc::SQL_DESC_LITERAL_SUFFIX => {
+            DescField::String(col.literal_suffix.unwrap_or("").to_string())
+        }
+        Desc::SQL_DESC_NULLABLE => DescField::SmallInt(col.nullability as SmallInt),
+        Desc::SQL_DESC_UNNAMED | Desc::SQL_DESC_UPDATABLE => DescField::SmallInt(0),
+        Desc::SQL_DESC_SEARCHABLE => {
+            DescField::SmallInt(SmallInt::try_from(col.searchable).unwrap_or(0))
+        }
+        Desc::SQL_DESC_FIXED_PREC_SCALE => DescField::SmallInt(col.fixed_prec_scale.into()),
+        Desc::SQL_DESC_UNSIGNED => DescField::SmallInt(col.is_unsigned.into()),
+        Desc::SQL_DESC_PRECISION => small_int(col.precision),
+        Desc::SQL_DESC_SCALE => small_int(col.scale),
+        Desc::SQL_DESC_LENGTH => DescField::ULen(col.length.unwrap_or(0).into()),
+        Desc::SQL_DESC_OCTET_LENGTH => DescField::Len(
+            Len::try_from(col.transfer_octet_length.unwrap_or(0)).unwrap_or(Len::MAX),
+        ),
+        Desc::SQL_DESC_DISPLAY_SIZE => {
+            DescField::Len(Len::try_from(col.display_size.unwrap_or(0)).unwrap_or(Len::MAX))
+        }
+        Desc::SQL_DESC_CASE_SENSITIVE => DescField::Integer(col.case_sensitive.into()),
+        Desc::SQL_DESC_NUM_PREC_RADIX => DescField::Integer(col.num_prec_radix.unwrap_or(0).into()),
+        Desc::SQL_DESC_AUTO_UNIQUE_VALUE => DescField::Integer(0),
+        _ => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+    })
+}
+
+unsafe fn get_imp_param_desc_field(
+    desc: &Descriptor,
+    rec_number: SmallInt,
+    field: Desc,
+) -> Result<Option<DescField>> {
+    let stmt = statement(desc)?;
+    let value = match field {
+        Desc::SQL_DESC_ARRAY_STATUS_PTR => {
+            DescField::Pointer(stmt.attributes.read().unwrap().param_status_ptr.cast())
+        }
+        Desc::SQL_DESC_ROWS_PROCESSED_PTR => {
+            DescField::Pointer(stmt.attributes.read().unwrap().param_processed_ptr.cast())
+        }
+        Desc::SQL_DESC_COUNT => {
+            DescField::SmallInt(count(stmt.bound_params.read().unwrap().as_ref()))
+        }
+        _ if is_header_field(field) => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+        _ => {
+            let rec = record_number(desc, rec_number)?;
+            let Some(param) = stmt
+                .bound_params
+                .read()
+                .unwrap()
+                .as_ref()
+                .and_then(|params| params.get(&rec).copied())
+            else {
+                return Ok(None);
+            };
+            match field {
+                Desc::SQL_DESC_PARAMETER_TYPE => DescField::SmallInt(param.input_output_type),
+                Desc::SQL_DESC_TYPE | Desc::SQL_DESC_CONCISE_TYPE => {
+                    DescField::SmallInt(param.parameter_type)
+                }
+                Desc::SQL_DESC_LENGTH => DescField::ULen(param.column_size),
+                Desc::SQL_DESC_PRECISION => DescField::SmallInt(
+                    SmallInt::try_from(param.column_size).unwrap_or(SmallInt::MAX),
+                ),
+                Desc::SQL_DESC_SCALE => DescField::SmallInt(param.decimal_digits),
+                Desc::SQL_DESC_NULLABLE => {
+                    DescField::SmallInt(Nullability::SQL_NULLABLE as SmallInt)
+                }
+                Desc::SQL_DESC_NAME => DescField::String(String::new()),
+                Desc::SQL_DESC_UNNAMED => DescField::SmallInt(1),
+                _ => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+            }
+        }
+    };
+    Ok(Some(value))
+}
+
+///
+/// set_desc_field sets a field of a descriptor. Setting a field of a record past the last one
+/// adds the record.
+///
+/// # Safety
+/// The statement of an implicitly allocated descriptor is read through a raw pointer.
+///
+pub(crate) unsafe fn set_desc_field(
+    desc: &Descriptor,
+    rec_number: SmallInt,
+    field: Desc,
+    value: Pointer,
+) -> Result<()> {
+    if field == Desc::SQL_DESC_ALLOC_TYPE {
+        return Err(ODBCError::InvalidFieldDescriptor(field as u16));
+    }
+    match desc_type(desc) {
+        DescriptorType::AppRow | DescriptorType::AppParam => {
+            let mut fields = app_desc(desc)?;
+            set_app_desc_field(&mut fields, desc, rec_number, field, value)?;
+            set_app_desc(desc, fields)
+        }
+        DescriptorType::ImpRow => {
+            let stmt = statement(desc)?;
+            let mut attributes = stmt.attributes.write().unwrap();
+            match field {
+                Desc::SQL_DESC_ARRAY_STATUS_PTR => attributes.row_status_ptr = value.cast(),
+                Desc::SQL_DESC_ROWS_PROCESSED_PTR => attributes.rows_fetched_ptr = value.cast(),
+                _ => return Err(ODBCError::CannotModifyImpRowDesc),
+            }
+            Ok(())
+        }
+        DescriptorType::ImpParam => set_imp_param_desc_field(desc, rec_number, field, value),
+    }
+}
+
+fn set_app_desc_field(
+    fields: &mut AppRowDesc,
+    desc: &Descriptor,
+    rec_number: SmallInt,
+    field: Desc,
+    value: Pointer,
+) -> Result<()> {
+    match field {
+        Desc::SQL_DESC_ARRAY_SIZE => match value as ULen {
+            0 => return Err(ODBCError::InvalidAttrValue("SQL_DESC_ARRAY_SIZE")),
+            array_size => fields.array_size = array_size,
+        },
+        Desc::SQL_DESC_ARRAY_STATUS_PTR => {
+            return Err(ODBCError::Unimplemented("SQL_DESC_ARRAY_STATUS_PTR"))
+        }
+        Desc::SQL_DESC_BIND_OFFSET_PTR => fields.bind_offset_ptr = value.cast(),
+        Desc::SQL_DESC_BIND_TYPE => fields.bind_type = value as ULen,
+        Desc::SQL_DESC_COUNT => {
+            let count = USmallInt::try_from(value as Len)
+                .map_err(|_| ODBCError::InvalidDescriptorIndex(value as USmallInt))?;
+            if let Some(cols) = fields.bound_cols.as_mut() {
+                cols.retain(|&number, _| number <= count);
+            }
+        }
+        _ if is_header_field(field) => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+        _ => {
+            let rec = record_number(desc, rec_number)?;
+            let record = fields
+                .bound_cols
+                .get_or_insert_with(HashMap::new)
+                .entry(rec)
+                .or_insert_with(default_col);
+            match field {
+                Desc::SQL_DESC_TYPE | Desc::SQL_DESC_CONCISE_TYPE => {
+                    let target_type = value as SmallInt;
+                    if <CDataType as FromPrimitive>::from_i16(target_type).is_none() {
+                        return Err(ODBCError::InvalidTargetType(target_type));
+                    }
+                    record.target_type = target_type;
+                }
+                Desc::SQL_DESC_DATA_PTR => record.target_buffer = value,
+                Desc::SQL_DESC_INDICATOR_PTR | Desc::SQL_DESC_OCTET_LENGTH_PTR => {
+                    record.length_or_indicator = value.cast();
+                }
+                Desc::SQL_DESC_OCTET_LENGTH => record.buffer_length = value as Len,
+                _ => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+            }
+        }
+    }
+    Ok(())
+}
+
+unsafe fn set_imp_param_desc_field(
+    desc: &Descriptor,
+    rec_number: SmallInt,
+    field: Desc,
+    value: Pointer,
+) -> Result<()> {
+    let stmt = statement(desc)?;
+    match field {
+        Desc::SQL_DESC_ARRAY_STATUS_PTR => {
+            stmt.attributes.write().unwrap().param_status_ptr = value.cast();
+        }
+        Desc::SQL_DESC_ROWS_PROCESSED_PTR => {
+            stmt.attributes.write().unwrap().param_processed_ptr = value.cast();
+        }
+        Desc::SQL_DESC_COUNT => {
+            let count = USmallInt::try_from(value as Len)
+                .map_err(|_| ODBCError::InvalidDescriptorIndex(value as USmallInt))?;
+            if let Some(params) = stmt.bound_params.write().unwrap().as_mut() {
+                params.retain(|&number, _| number <= count);
+            }
+        }
+        _ if is_header_field(field) => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+        _ => {
+            let rec = record_number(desc, rec_number)?;
+            let mut bound_params = stmt.bound_params.write().unwrap();
+            let param = bound_params
+                .get_or_insert_with(HashMap::new)
+                .entry(rec)
+                .or_insert_with(default_param);
+            match field {
+                Desc::SQL_DESC_PARAMETER_TYPE => param.input_output_type = value as SmallInt,
+                Desc::SQL_DESC_TYPE | Desc::SQL_DESC_CONCISE_TYPE => {
+                    param.parameter_type = value as SmallInt;
+                }
+                Desc::SQL_DESC_LENGTH | Desc::SQL_DESC_PRECISION => {
+                    param.column_size = value as ULen;
+                }
+                Desc::SQL_DESC_SCALE => param.decimal_digits = value as SmallInt,
+                _ => return Err(ODBCError::InvalidFieldDescriptor(field as u16)),
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+/// get_desc_rec returns the fields of a descriptor record SQLGetDescRec returns, or None if the
+/// record does not exist.
+///
+/// # Safety
+/// The statement of an implicitly allocated descriptor is read through a raw pointer.
+///
+pub(crate) unsafe fn get_desc_rec(
+    desc: &Descriptor,
+    odbc_version: AttrOdbcVersion,
+    rec_number: SmallInt,
+) -> Result<Option<DescRec>> {
+    // Fields that do not apply to the type of descriptor are left with their default values.
+    let field = |field: Desc| match get_desc_field(desc, odbc_version, rec_number, field) {
+        Err(ODBCError::InvalidFieldDescriptor(_)) => Ok(None),
+        result => result,
+    };
+    let small_int = |value: Option<DescField>| match value {
+        Some(DescField::SmallInt(value)) => value,
+        _ => 0,
+    };
+    let Some(desc_type) = field(Desc::SQL_DESC_TYPE)? else {
+        return Ok(None);
+    };
+    Ok(Some(DescRec {
+        name: match field(Desc::SQL_DESC_NAME)? {
+            Some(DescField::String(name)) => name,
+            _ => String::new(),
+        },
+        desc_type: small_int(Some(desc_type)),
+        sub_type: small_int(field(Desc::SQL_DESC_DATETIME_INTERVAL_CODE)?),
+        length: match field(Desc::SQL_DESC_OCTET_LENGTH)? {
+            Some(DescField::Len(length)) => length,
+            _ => 0,
+        },
+        precision: small_int(field(Desc::SQL_DESC_PRECISION)?),
+        scale: small_int(field(Desc::SQL_DESC_SCALE)?),
+        nullable: small_int(field(Desc::SQL_DESC_NULLABLE)?),
+    }))
+}
+
+///
+/// set_desc_rec sets the type, length, precision, scale and pointer fields of a descriptor
+/// record.
+///
+/// # Safety
+/// The statement of an implicitly allocated descriptor is read through a raw pointer.
+///
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe fn set_desc_rec(
+    desc: &Descriptor,
+    rec_number: SmallInt,
+    desc_type: SmallInt,
+    length: Len,
+    precision: SmallInt,
+    scale: SmallInt,
+    data_ptr: Pointer,
+    string_length_ptr: *mut Len,
+    indicator_ptr: *mut Len,
+) -> Result<()> {
+    let fields: Vec<(Desc, Pointer)> = match self::desc_type(desc) {
+        DescriptorType::ImpRow => return Err(ODBCError::CannotModifyImpRowDesc),
+        DescriptorType::ImpParam => [
+            (Desc::SQL_DESC_TYPE, desc_type as Pointer),
+            (Desc::SQL_DESC_LENGTH, length as Pointer),
+            (Desc::SQL_DESC_SCALE, scale as Pointer),
+        ]
+        .into_iter()
+        .chain((precision != 0).then_some((Desc::SQL_DESC_PRECISION, precision as Pointer)))
+        .collect(),
+        // There is a single length/indicator buffer, so the indicator pointer is used when both
+        // are set.
+        DescriptorType::AppRow | DescriptorType::AppParam => vec![
+            (Desc::SQL_DESC_TYPE, desc_type as Pointer),
+            (Desc::SQL_DESC_OCTET_LENGTH, length as Pointer),
+            (Desc::SQL_DESC_DATA_PTR, data_ptr),
+            (
+                Desc::SQL_DESC_INDICATOR_PTR,
+                if indicator_ptr.is_null() {
+                    string_length_ptr.cast()
+                } else {
+                    indicator_ptr.cast()
+                },
+            ),
+        ],
+    };
+    fields
+        .into_iter()
+        .try_for_each(|(field, value)| set_desc_field(desc, rec_number, field, value))
+}
+
+///
+/// copy_desc copies the fields of the source descriptor to the target descriptor.
+///
+/// # Safety
+/// The statements of implicitly allocated descriptors are read through raw pointers.
+///
+pub(crate) unsafe fn copy_desc(source: &Descriptor, target: &Descriptor) -> Result<()> {
+    match (desc_type(source), desc_type(target)) {
+        (_, DescriptorType::ImpRow) => Err(ODBCError::CannotModifyImpRowDesc),
+        (
+            DescriptorType::AppRow | DescriptorType::AppParam,
+            DescriptorType::AppRow | DescriptorType::AppParam,
+        ) => set_app_desc(target, app_desc(source)?),
+        _ => copy_desc_records(source, target),
+    }
+}
+
+// The record fields copied from an implementation descriptor. The precision is set before the
+// length, since both are the column size of an implementation parameter descriptor record.
+const COPIED_RECORD_FIELDS: [Desc; 6] = [
+    Desc::SQL_DESC_CONCISE_TYPE,
+    Desc::SQL_DESC_PARAMETER_TYPE,
+    Desc::SQL_DESC_PRECISION,
+    Desc::SQL_DESC_LENGTH,
+    Desc::SQL_DESC_SCALE,
+    Desc::SQL_DESC_OCTET_LENGTH,
+];
+
+// copy_desc_records copies the records of a descriptor field by field, as SQLSetDescField
+// would. Fields that the source or the target descriptor does not have are skipped.
+unsafe fn copy_desc_records(source: &Descriptor, target: &Descriptor) -> Result<()> {
+    let odbc_version = AttrOdbcVersion::SQL_OV_ODBC3;
+    let count = match get_desc_field(source, odbc_version, 0, Desc::SQL_DESC_COUNT)? {
+        Some(DescField::SmallInt(count)) => count,
+        _ => 0,
+    };
+    set_desc_field(target, 0, Desc::SQL_DESC_COUNT, count as Pointer)?;
+    for rec_number in 1..=count {
+        for field in COPIED_RECORD_FIELDS {
+            let value = match get_desc_field(source, odbc_version, rec_number, field) {
+                Ok(Some(DescField::SmallInt(value))) => value as Pointer,
+                Ok(Some(DescField::Len(value))) => value as Pointer,
+                Ok(Some(DescField::ULen(value))) => value as Pointer,
+                Ok(_) | Err(ODBCError::InvalidFieldDescriptor(_)) => continue,
+                Err(error) => return Err(error),
+            };
+            match set_desc_field(target, rec_number, field, value) {
+                Ok(()) | Err(ODBCError::InvalidFieldDescriptor(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,429 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{Connection, ConnectionState, Env, EnvState, MongoHandle},
+        SQLAllocHandle, SQLBindCol, SQLBindParameter, SQLCopyDesc, SQLFetch, SQLFreeHandle,
+        SQLGetDescFieldW, SQLGetDescRecW, SQLGetStmtAttrW, SQLSetDescFieldW, SQLSetDescRec,
+        SQLSetStmtAttrW,
+    };
+    use bson::doc;
+    use cstr::{input_text_to_string_w, WideChar};
+    use definitions::{
+        AllocType, CDataType, Desc, HDesc, HStmt, Handle, HandleType, Integer, Len, Nullability,
+        ParamType, Pointer, SmallInt, SqlDataType, SqlReturn, StatementAttribute, ULen,
+    };
+    use mongo_odbc_core::{
+        json_schema::{
+            simplified::{Atomic, Schema},
+            BsonTypeName,
+        },
+        mock_query::MongoQuery,
+        MongoColMetadata, TypeMode,
+    };
+    use std::ptr::{addr_of_mut, null_mut};
+
+    // Allocates a statement on conn whose result set is the rows 1 to 3 in the int column x.
+    unsafe fn allocate_statement(conn: *mut MongoHandle) -> HStmt {
+        let mut stmt: Handle = null_mut();
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLAllocHandle(HandleType::SQL_HANDLE_STMT, conn as *mut _, &mut stmt)
+        );
+        let s = (*stmt.cast::<MongoHandle>()).as_statement().unwrap();
+        *s.mongo_statement.write().unwrap() = Some(Box::new(MongoQuery::new(
+            (1..=3).map(|x| doc! {"foo": {"x": x}}).collect(),
+            vec![MongoColMetadata::new(
+                "",
+                "foo".to_string(),
+                "x".to_string(),
+                Schema::Atomic(Atomic::Scalar(BsonTypeName::Int)),
+                Nullability::SQL_NO_NULLS,
+                TypeMode::Simple,
+                None,
+            )],
+        )));
+        stmt.cast()
+    }
+
+    unsafe fn get_desc_handle(stmt: HStmt, attribute: StatementAttribute) -> HDesc {
+        let mut desc: HDesc = null_mut();
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLGetStmtAttrW(
+                stmt,
+                attribute as Integer,
+                &mut desc as *mut HDesc as Pointer,
+                0,
+                null_mut()
+            )
+        );
+        desc
+    }
+
+    unsafe fn get_desc_field<T: Default>(
+        desc: HDesc,
+        rec_number: SmallInt,
+        field: Desc,
+    ) -> (SqlReturn, T) {
+        let mut value = T::default();
+        let sql_return = SQLGetDescFieldW(
+            desc,
+            rec_number,
+            field as SmallInt,
+            &mut value as *mut T as Pointer,
+            0,
+            null_mut(),
+        );
+        (sql_return, value)
+    }
+
+    // Returns the SQLSTATE of the last diagnostic, since SQLFreeHandle keeps diagnostics.
+    unsafe fn sql_state(desc: HDesc) -> String {
+        (*desc.cast::<MongoHandle>())
+            .as_descriptor()
+            .unwrap()
+            .errors
+            .read()
+            .unwrap()
+            .last()
+            .unwrap()
+            .get_sql_state()
+            .odbc_3_state
+            .to_string()
+    }
+
+    #[test]
+    fn test_implementation_row_descriptor() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn: *mut _ =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        unsafe {
+            let stmt = allocate_statement(conn);
+            let ird = get_desc_handle(stmt, StatementAttribute::SQL_ATTR_IMP_ROW_DESC);
+
+            assert_eq!(
+                (
+                    SqlReturn::SUCCESS,
+                    AllocType::SQL_DESC_ALLOC_AUTO as SmallInt
+                ),
+                get_desc_field(ird, 0, Desc::SQL_DESC_ALLOC_TYPE)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 1 as SmallInt),
+                get_desc_field(ird, 0, Desc::SQL_DESC_COUNT)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, SqlDataType::SQL_INTEGER as SmallInt),
+                get_desc_field(ird, 1, Desc::SQL_DESC_CONCISE_TYPE)
+            );
+            assert_eq!(
+                (SqlReturn::NO_DATA, 0 as SmallInt),
+                get_desc_field(ird, 2, Desc::SQL_DESC_TYPE)
+            );
+
+            let name = &mut [0 as WideChar; 10];
+            let mut name_len: SmallInt = 0;
+            let mut desc_type: SmallInt = 0;
+            let mut nullable: SmallInt = -1;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetDescRecW(
+                    ird,
+                    1,
+                    name.as_mut_ptr(),
+                    name.len() as SmallInt,
+                    &mut name_len,
+                    &mut desc_type,
+                    null_mut(),
+                    null_mut(),
+                    null_mut(),
+                    null_mut(),
+                    &mut nullable,
+                )
+            );
+            assert_eq!(
+                (
+                    "x".to_string(),
+                    SqlDataType::SQL_INTEGER as SmallInt,
+                    Nullability::SQL_NO_NULLS as SmallInt
+                ),
+                (
+                    input_text_to_string_w(name.as_ptr(), name_len.into()),
+                    desc_type,
+                    nullable
+                )
+            );
+
+            // The implementation row descriptor is read-only and cannot be freed.
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLSetDescFieldW(
+                    ird,
+                    1,
+                    Desc::SQL_DESC_TYPE as SmallInt,
+                    CDataType::SQL_C_CHAR as usize as Pointer,
+                    0
+                )
+            );
+            assert_eq!("HY016", sql_state(ird));
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLFreeHandle(HandleType::SQL_HANDLE_DESC, ird as Handle)
+            );
+            assert_eq!("HY017", sql_state(ird));
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLSetStmtAttrW(
+                    stmt,
+                    StatementAttribute::SQL_ATTR_APP_ROW_DESC as Integer,
+                    ird as Pointer,
+                    0
+                )
+            );
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeHandle(HandleType::SQL_HANDLE_STMT, stmt as Handle)
+            );
+        }
+    }
+
+    #[test]
+    fn test_copy_implementation_descriptors() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn: *mut _ =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        unsafe {
+            let select = allocate_statement(conn);
+            let insert = allocate_statement(conn);
+            let ird = get_desc_handle(select, StatementAttribute::SQL_ATTR_IMP_ROW_DESC);
+            let ipd = get_desc_handle(insert, StatementAttribute::SQL_ATTR_IMP_PARAM_DESC);
+
+            // The columns of a result set describe the parameters of another statement.
+            assert_eq!(SqlReturn::SUCCESS, SQLCopyDesc(ird, ipd));
+            assert_eq!(
+                (SqlReturn::SUCCESS, 1 as SmallInt),
+                get_desc_field(ipd, 0, Desc::SQL_DESC_COUNT)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, SqlDataType::SQL_INTEGER as SmallInt),
+                get_desc_field(ipd, 1, Desc::SQL_DESC_CONCISE_TYPE)
+            );
+
+            let mut value = 0i32;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindParameter(
+                    select,
+                    1,
+                    ParamType::SQL_PARAM_INPUT as SmallInt,
+                    CDataType::SQL_C_CHAR as SmallInt,
+                    SqlDataType::SQL_VARCHAR as SmallInt,
+                    20,
+                    0,
+                    &mut value as *mut i32 as Pointer,
+                    0,
+                    null_mut(),
+                )
+            );
+            let select_ipd = get_desc_handle(select, StatementAttribute::SQL_ATTR_IMP_PARAM_DESC);
+            assert_eq!(SqlReturn::SUCCESS, SQLCopyDesc(select_ipd, ipd));
+            assert_eq!(
+                (SqlReturn::SUCCESS, SqlDataType::SQL_VARCHAR as SmallInt),
+                get_desc_field(ipd, 1, Desc::SQL_DESC_CONCISE_TYPE)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 20 as ULen),
+                get_desc_field(ipd, 1, Desc::SQL_DESC_LENGTH)
+            );
+
+            // The implementation row descriptor cannot be a target.
+            assert_eq!(SqlReturn::ERROR, SQLCopyDesc(ipd, ird));
+            assert_eq!("HY016", sql_state(ird));
+
+            for stmt in [select, insert] {
+                assert_eq!(
+                    SqlReturn::SUCCESS,
+                    SQLFreeHandle(HandleType::SQL_HANDLE_STMT, stmt as Handle)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_implicit_app_row_descriptor() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn: *mut _ =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        unsafe {
+            let stmt = allocate_statement(conn);
+            let ard = get_desc_handle(stmt, StatementAttribute::SQL_ATTR_APP_ROW_DESC);
+
+            // Columns bound with SQLBindCol are records of the application row descriptor.
+            let mut value = 0i32;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindCol(
+                    stmt,
+                    1,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    &mut value as *mut i32 as Pointer,
+                    4,
+                    null_mut(),
+                )
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 1 as SmallInt),
+                get_desc_field(ard, 0, Desc::SQL_DESC_COUNT)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, &mut value as *mut i32 as Pointer),
+                get_desc_field(ard, 1, Desc::SQL_DESC_DATA_PTR)
+            );
+
+            // Setting descriptor fields changes the bindings and statement attributes.
+            let mut values = [0i64; 2];
+            for (field, value) in [
+                (
+                    Desc::SQL_DESC_TYPE,
+                    CDataType::SQL_C_SBIGINT as usize as Pointer,
+                ),
+                (Desc::SQL_DESC_OCTET_LENGTH, 8 as Pointer),
+                (Desc::SQL_DESC_DATA_PTR, values.as_mut_ptr() as Pointer),
+            ] {
+                assert_eq!(
+                    SqlReturn::SUCCESS,
+                    SQLSetDescFieldW(ard, 1, field as SmallInt, value, 0)
+                );
+            }
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetDescFieldW(
+                    ard,
+                    0,
+                    Desc::SQL_DESC_ARRAY_SIZE as SmallInt,
+                    2 as Pointer,
+                    0
+                )
+            );
+            let mut row_array_size: ULen = 0;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetStmtAttrW(
+                    stmt,
+                    StatementAttribute::SQL_ATTR_ROW_ARRAY_SIZE as Integer,
+                    &mut row_array_size as *mut ULen as Pointer,
+                    0,
+                    null_mut()
+                )
+            );
+            assert_eq!(2, row_array_size);
+
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt));
+            assert_eq!((0, [1, 2]), (value, values));
+
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLSetDescFieldW(ard, 1, Desc::SQL_DESC_TYPE as SmallInt, 1234 as Pointer, 0)
+            );
+            assert_eq!("HY003", sql_state(ard));
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeHandle(HandleType::SQL_HANDLE_STMT, stmt as Handle)
+            );
+        }
+    }
+
+    #[test]
+    fn test_explicit_app_row_descriptor() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn: *mut _ =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+
+        unsafe {
+            let stmt = allocate_statement(conn);
+            let implicit_ard = get_desc_handle(stmt, StatementAttribute::SQL_ATTR_APP_ROW_DESC);
+            let mut handle: Handle = null_mut();
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLAllocHandle(HandleType::SQL_HANDLE_DESC, conn as *mut _, &mut handle)
+            );
+            let desc: HDesc = handle.cast();
+            assert_eq!(
+                (
+                    SqlReturn::SUCCESS,
+                    AllocType::SQL_DESC_ALLOC_USER as SmallInt
+                ),
+                get_desc_field(desc, 0, Desc::SQL_DESC_ALLOC_TYPE)
+            );
+
+            let mut value = 0i32;
+            let mut indicator: Len = 0;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetDescRec(
+                    desc,
+                    1,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    0,
+                    4,
+                    0,
+                    0,
+                    &mut value as *mut i32 as Pointer,
+                    addr_of_mut!(indicator),
+                    addr_of_mut!(indicator),
+                )
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetStmtAttrW(
+                    stmt,
+                    StatementAttribute::SQL_ATTR_APP_ROW_DESC as Integer,
+                    desc as Pointer,
+                    0
+                )
+            );
+            assert_eq!(
+                desc,
+                get_desc_handle(stmt, StatementAttribute::SQL_ATTR_APP_ROW_DESC)
+            );
+
+            // The statement fetches into the records of the explicitly allocated descriptor.
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt));
+            assert_eq!((1, 4), (value, indicator));
+
+            // Copying the descriptor binds the same columns on the implicit descriptor.
+            assert_eq!(SqlReturn::SUCCESS, SQLCopyDesc(desc, implicit_ard));
+            assert_eq!(
+                (SqlReturn::SUCCESS, &mut value as *mut i32 as Pointer),
+                get_desc_field(implicit_ard, 1, Desc::SQL_DESC_DATA_PTR)
+            );
+
+            // Freeing the descriptor reverts the statement to its implicit descriptor.
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeHandle(HandleType::SQL_HANDLE_DESC, handle)
+            );
+            assert_eq!(
+                implicit_ard,
+                get_desc_handle(stmt, StatementAttribute::SQL_ATTR_APP_ROW_DESC)
+            );
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt));
+            assert_eq!(2, value);
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeHandle(HandleType::SQL_HANDLE_STMT, stmt as Handle)
+            );
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{api::data::i16_len, errors::ODBCError};
 use cstr::WideChar;
 use definitions::{
-    AttrOdbcVersion, DiagType, Integer, Pointer, SmallInt, SqlReturn, SQL_ROW_NUMBER_UNKNOWN,
+    AttrOdbcVersion, Char, DiagType, Integer, Pointer, SmallInt, SqlReturn, SQL_ROW_NUMBER_UNKNOWN,
 };
 use std::ptr::copy_nonoverlapping;
 
@@ -20,6 +20,20 @@ pub unsafe fn set_sql_statew(sql_state: &str, output_ptr: *mut WideChar) {
     copy_nonoverlapping(state_u16.as_ptr(), output_ptr, 6);
 }
 
+///
+/// set_sql_state writes the given sql state to the *Char [`output_ptr`].
+///
+/// # Safety
+/// This writes to a raw C-pointer
+///
+pub unsafe fn set_sql_state(sql_state: &str, output_ptr: *mut Char) {
+    if output_ptr.is_null() {
+        return;
+    }
+    let sql_state = format!("{sql_state}\0");
+    copy_nonoverlapping(sql_state.as_ptr(), output_ptr, 6);
+}
+
 ///
 /// get_diag_recw copies the given ODBC error's diagnostic information
 /// into the provided pointers.
@@ -55,6 +69,41 @@ pub unsafe fn get_diag_recw(
     )
 }
 
+///
+/// get_diag_rec copies the given ODBC error's diagnostic information
+/// into the provided *Char pointers.
+///
+/// # Safety
+/// This writes to multiple raw C-pointers
+///
+pub unsafe fn get_diag_rec(
+    error: &ODBCError,
+    state: *mut Char,
+    odbc_ver: AttrOdbcVersion,
+    message_text: *mut Char,
+    buffer_length: SmallInt,
+    text_length_ptr: *mut SmallInt,
+    native_error_ptr: *mut Integer,
+) -> SqlReturn {
+    if !native_error_ptr.is_null() {
+        *native_error_ptr = error.get_native_err_code();
+    }
+    let sql_state = match odbc_ver {
+        AttrOdbcVersion::SQL_OV_ODBC2 => error.get_sql_state().odbc_2_state,
+        AttrOdbcVersion::SQL_OV_ODBC3 | AttrOdbcVersion::SQL_OV_ODBC3_80 => {
+            error.get_sql_state().odbc_3_state
+        }
+    };
+    set_sql_state(sql_state, state);
+    let message = format!("{error}");
+    i16_len::set_output_string(
+        &message,
+        message_text,
+        buffer_length as usize,
+        text_length_ptr,
+    )
+}
+
 ///
 /// get_stmt_diag_field copies a part of the given ODBC error's diagnostic information
 /// into the provided pointer.
@@ -80,8 +129,8 @@ pub unsafe fn get_stmt_diag_field(diag_identifier: DiagType, diag_info_ptr: Poin
 }
 
 ///
-/// get_diag_field copies a part of the given ODBC error's diagnostic information
-/// into the provided pointers.
+/// get_diag_fieldw copies a part of the given ODBC error's diagnostic information
+/// into the provided pointers, writing strings as WideChars.
 ///
 /// # Safety
 /// This writes to multiple raw C-pointers
@@ -94,6 +143,57 @@ pub unsafe fn get_diag_fieldw(
     record_number: i16,
     buffer_length: i16,
     string_length_ptr: *mut i16,
+) -> SqlReturn {
+    get_diag_field_helper(
+        errors,
+        diag_identifier,
+        odbc_ver,
+        diag_info_ptr,
+        record_number,
+        buffer_length,
+        string_length_ptr,
+        i16_len::set_output_wstring_as_bytes,
+    )
+}
+
+///
+/// get_diag_field copies a part of the given ODBC error's diagnostic information
+/// into the provided pointers, writing strings as Chars.
+///
+/// # Safety
+/// This writes to multiple raw C-pointers
+///
+pub unsafe fn get_diag_field(
+    errors: &[ODBCError],
+    diag_identifier: DiagType,
+    odbc_ver: AttrOdbcVersion,
+    diag_info_ptr: Pointer,
+    record_number: i16,
+    buffer_length: i16,
+    string_length_ptr: *mut i16,
+) -> SqlReturn {
+    get_diag_field_helper(
+        errors,
+        diag_identifier,
+        odbc_ver,
+        diag_info_ptr,
+        record_number,
+        buffer_length,
+        string_length_ptr,
+        i16_len::set_output_string_as_bytes,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn get_diag_field_helper(
+    errors: &[ODBCError],
+    diag_identifier: DiagType,
+    odbc_ver: AttrOdbcVersion,
+    diag_info_ptr: Pointer,
+    record_number: i16,
+    buffer_length: i16,
+    string_length_ptr: *mut i16,
+    set_output_string: unsafe fn(&str, Pointer, usize, *mut SmallInt) -> SqlReturn,
 ) -> SqlReturn {
     // NOTE: number is dependent on the list of errors, but is a header, hence separating it from the match
     if diag_identifier == DiagType::SQL_DIAG_NUMBER {
@@ -116,7 +216,7 @@ pub unsafe fn get_diag_fieldw(
                                 error.get_sql_state().odbc_3_state
                             }
                         };
-                        i16_len::set_output_wstring_as_bytes(
+                        set_output_string(
                             sql_state,
                             diag_info_ptr,
                             buffer_length as usize,
@@ -130,7 +230,7 @@ pub unsafe fn get_diag_fieldw(
                     ),
                     DiagType::SQL_DIAG_MESSAGE_TEXT => {
                         let message = format!("{error}");
-                        i16_len::set_output_wstring_as_bytes(
+                        set_output_string(
                             &message,
                             diag_info_ptr,
                             buffer_length as usize,
@@ -1,11 +1,17 @@
 use constants::{
-    OdbcState, CONNECTION_NOT_OPEN, FETCH_TYPE_OUT_OF_RANGE, FRACTIONAL_TRUNCATION, GENERAL_ERROR,
-    GENERAL_WARNING, INDICATOR_VARIABLE_REQUIRED, INTEGRAL_TRUNCATION,
-    INVALID_ATTRIBUTE_OR_OPTION_IDENTIFIER, INVALID_ATTR_VALUE, INVALID_CHARACTER_VALUE,
-    INVALID_COLUMN_NUMBER, INVALID_CURSOR_STATE, INVALID_DATETIME_FORMAT, INVALID_DESCRIPTOR_INDEX,
-    INVALID_DRIVER_COMPLETION, INVALID_FIELD_DESCRIPTOR, INVALID_INFO_TYPE_VALUE, INVALID_SQL_TYPE,
-    NOT_IMPLEMENTED, NO_DSN_OR_DRIVER, NO_RESULTSET, OPTION_CHANGED, PROGRAM_TYPE_OUT_OF_RANGE,
-    RESTRICTED_DATATYPE, RIGHT_TRUNCATED, VENDOR_IDENTIFIER,
+    OdbcState, ACCURACY_OPTION_OUT_OF_RANGE, ASSOCIATED_STATEMENT_NOT_PREPARED, CANNOT_MODIFY_IRD,
+    COLUMN_TYPE_OUT_OF_RANGE, CONNECTION_NOT_OPEN, COUNT_FIELD_INCORRECT, DATA_SOURCE_NOT_FOUND,
+    DUPLICATE_CURSOR_NAME, FETCH_BEFORE_START, FETCH_TYPE_OUT_OF_RANGE, FRACTIONAL_TRUNCATION,
+    FUNCTION_SEQUENCE_ERROR, GENERAL_ERROR, GENERAL_WARNING, INDICATOR_VARIABLE_REQUIRED,
+    INTEGRAL_TRUNCATION, INVALID_ATTRIBUTE_OR_OPTION_IDENTIFIER, INVALID_ATTR_VALUE,
+    INVALID_BOOKMARK_VALUE, INVALID_CHARACTER_VALUE, INVALID_COLUMN_NUMBER, INVALID_CURSOR_NAME,
+    INVALID_CURSOR_STATE, INVALID_DATETIME_FORMAT, INVALID_DESCRIPTOR_INDEX,
+    INVALID_DRIVER_COMPLETION, INVALID_FIELD_DESCRIPTOR, INVALID_INFO_TYPE_VALUE,
+    INVALID_PARAMETER_TYPE, INVALID_RETRIEVAL_CODE, INVALID_SQL_TYPE,
+    INVALID_STRING_OR_BUFFER_LENGTH, INVALID_USE_OF_AUTOMATIC_DESCRIPTOR,
+    NON_CHARACTER_DATA_IN_PIECES, NOT_IMPLEMENTED, NO_DSN_OR_DRIVER, NO_RESULTSET,
+    NULLABLE_TYPE_OUT_OF_RANGE, OPTION_CHANGED, PROGRAM_TYPE_OUT_OF_RANGE, RESTRICTED_DATATYPE,
+    RIGHT_TRUNCATED, SCOPE_TYPE_OUT_OF_RANGE, UNIQUENESS_OPTION_OUT_OF_RANGE, VENDOR_IDENTIFIER,
 };
 use thiserror::Error;
 
@@ -65,6 +71,12 @@ pub enum ODBCError {
     InvalidColumnNumber(u16),
     #[error("[{vendor}][API] No ResultSet", vendor = VENDOR_IDENTIFIER)]
     InvalidCursorState,
+    #[error("[{vendor}][API] Invalid cursor name {0}", vendor = VENDOR_IDENTIFIER)]
+    InvalidCursorName(String),
+    #[error("[{vendor}][API] Duplicate cursor name {0}", vendor = VENDOR_IDENTIFIER)]
+    DuplicateCursorName(String),
+    #[error("[{vendor}][API] Invalid retrieval code {0}", vendor = VENDOR_IDENTIFIER)]
+    InvalidRetrievalCode(u16),
     #[error("[{vendor}][API] Invalid SQL Type: {0}", vendor = VENDOR_IDENTIFIER)]
     InvalidSqlType(String),
     #[error("[{vendor}][API] Invalid handle type, expected {0}", vendor = VENDOR_IDENTIFIER)]
@@ -75,6 +87,23 @@ pub enum ODBCError {
     InvalidAttrIdentifier(i32),
     #[error("[{vendor}][API] Fetch type out of range {0}", vendor = VENDOR_IDENTIFIER)]
     FetchTypeOutOfRange(i16),
+    #[error(
+        "[{vendor}][API] The requested rowset started before the result set, so the first rowset was returned",
+        vendor = VENDOR_IDENTIFIER
+    )]
+    FetchBeforeStart,
+    #[error("[{vendor}][API] Column type out of range {0}", vendor = VENDOR_IDENTIFIER)]
+    ColumnTypeOutOfRange(i16),
+    #[error("[{vendor}][API] Scope type out of range {0}", vendor = VENDOR_IDENTIFIER)]
+    ScopeTypeOutOfRange(i16),
+    #[error("[{vendor}][API] Nullable type out of range {0}", vendor = VENDOR_IDENTIFIER)]
+    NullableTypeOutOfRange(i16),
+    #[error("[{vendor}][API] Uniqueness option type out of range {0}", vendor = VENDOR_IDENTIFIER)]
+    UniquenessOptionOutOfRange(i16),
+    #[error("[{vendor}][API] Accuracy option type out of range {0}", vendor = VENDOR_IDENTIFIER)]
+    AccuracyOptionOutOfRange(i16),
+    #[error("[{vendor}][API] Invalid bookmark value", vendor = VENDOR_IDENTIFIER)]
+    InvalidBookmarkValue,
     #[error("[{vendor}][API] Invalid target type {0}", vendor = VENDOR_IDENTIFIER)]
     InvalidTargetType(i16),
     #[error("[{vendor}][API] Invalid driver completion type {0}", vendor = VENDOR_IDENTIFIER)]
@@ -84,6 +113,8 @@ pub enum ODBCError {
         vendor = VENDOR_IDENTIFIER
     )]
     MissingDriverOrDSNProperty,
+    #[error("[{vendor}][API] Data source name \"{0}\" not found", vendor = VENDOR_IDENTIFIER)]
+    DataSourceNotFound(String),
     #[error(
         "[{vendor}][API] Buffer size \"{0}\" not large enough for data",
         vendor = VENDOR_IDENTIFIER
@@ -123,6 +154,21 @@ pub enum ODBCError {
     InvalidCharacterValue(&'static str),
     #[error("[{vendor}][API] Invalid field descriptor value {0}", vendor = VENDOR_IDENTIFIER)]
     InvalidFieldDescriptor(u16),
+    #[error(
+        "[{vendor}][API] The statement of the implementation row descriptor is not prepared or executed",
+        vendor = VENDOR_IDENTIFIER
+    )]
+    AssociatedStatementNotPrepared,
+    #[error(
+        "[{vendor}][API] An implementation row descriptor cannot be modified",
+        vendor = VENDOR_IDENTIFIER
+    )]
+    CannotModifyImpRowDesc,
+    #[error(
+        "[{vendor}][API] Invalid use of an automatically allocated descriptor handle",
+        vendor = VENDOR_IDENTIFIER
+    )]
+    InvalidUseOfAutomaticDescriptor,
     #[error(
         "[{vendor}][API] Invalid value for attribute {0}, changed to {1}",
         vendor = VENDOR_IDENTIFIER
@@ -135,6 +181,21 @@ pub enum ODBCError {
     RestrictedDataType(&'static str, &'static str),
     #[error("[{vendor}][API] No resultset for statement", vendor = VENDOR_IDENTIFIER)]
     NoResultSet,
+    #[error("[{vendor}][API] Function sequence error: {0}", vendor = VENDOR_IDENTIFIER)]
+    FunctionSequenceError(&'static str),
+    #[error("[{vendor}][API] Invalid parameter type {0}", vendor = VENDOR_IDENTIFIER)]
+    InvalidParameterType(i16),
+    #[error("[{vendor}][API] No value was bound for parameter {0}", vendor = VENDOR_IDENTIFIER)]
+    UnboundParameter(u16),
+    #[error("{1} (parameter set {0})")]
+    ParameterSetFailed(usize, Box<ODBCError>),
+    #[error(
+        "[{vendor}][API] Non-character and non-binary data cannot be sent in pieces",
+        vendor = VENDOR_IDENTIFIER
+    )]
+    NonCharacterDataInPieces,
+    #[error("[{vendor}][API] Invalid string or buffer length {0}", vendor = VENDOR_IDENTIFIER)]
+    InvalidStringOrBufferLength(isize),
     #[error("Connection not open")]
     ConnectionNotOpen,
     #[error("[{vendor}][Core] {0}", vendor = VENDOR_IDENTIFIER)]
@@ -160,17 +221,31 @@ impl ODBCError {
             ODBCError::InvalidAttrValue(_) => INVALID_ATTR_VALUE,
             ODBCError::InvalidAttrIdentifier(_) => INVALID_ATTRIBUTE_OR_OPTION_IDENTIFIER,
             ODBCError::FetchTypeOutOfRange(_) => FETCH_TYPE_OUT_OF_RANGE,
+            ODBCError::FetchBeforeStart => FETCH_BEFORE_START,
+            ODBCError::ColumnTypeOutOfRange(_) => COLUMN_TYPE_OUT_OF_RANGE,
+            ODBCError::ScopeTypeOutOfRange(_) => SCOPE_TYPE_OUT_OF_RANGE,
+            ODBCError::NullableTypeOutOfRange(_) => NULLABLE_TYPE_OUT_OF_RANGE,
+            ODBCError::UniquenessOptionOutOfRange(_) => UNIQUENESS_OPTION_OUT_OF_RANGE,
+            ODBCError::AccuracyOptionOutOfRange(_) => ACCURACY_OPTION_OUT_OF_RANGE,
+            ODBCError::InvalidBookmarkValue => INVALID_BOOKMARK_VALUE,
             ODBCError::InvalidCursorState => INVALID_CURSOR_STATE,
+            ODBCError::InvalidCursorName(_) => INVALID_CURSOR_NAME,
+            ODBCError::DuplicateCursorName(_) => DUPLICATE_CURSOR_NAME,
+            ODBCError::InvalidRetrievalCode(_) => INVALID_RETRIEVAL_CODE,
             ODBCError::InvalidHandleType(_) => NOT_IMPLEMENTED,
             ODBCError::InvalidTargetType(_) => PROGRAM_TYPE_OUT_OF_RANGE,
             ODBCError::InvalidDriverCompletion(_) => INVALID_DRIVER_COMPLETION,
             ODBCError::OptionValueChanged(_, _) => OPTION_CHANGED,
             ODBCError::OutStringTruncated(_) => RIGHT_TRUNCATED,
             ODBCError::MissingDriverOrDSNProperty => NO_DSN_OR_DRIVER,
+            ODBCError::DataSourceNotFound(_) => DATA_SOURCE_NOT_FOUND,
             ODBCError::InvalidDescriptorIndex(_) => INVALID_DESCRIPTOR_INDEX,
             ODBCError::InvalidColumnNumber(_) => INVALID_COLUMN_NUMBER,
             ODBCError::InvalidSqlType(_) => INVALID_SQL_TYPE,
             ODBCError::InvalidFieldDescriptor(_) => INVALID_FIELD_DESCRIPTOR,
+            ODBCError::AssociatedStatementNotPrepared => ASSOCIATED_STATEMENT_NOT_PREPARED,
+            ODBCError::CannotModifyImpRowDesc => CANNOT_MODIFY_IRD,
+            ODBCError::InvalidUseOfAutomaticDescriptor => INVALID_USE_OF_AUTOMATIC_DESCRIPTOR,
             ODBCError::RestrictedDataType(_, _) => RESTRICTED_DATATYPE,
             ODBCError::FractionalTruncation(_) => FRACTIONAL_TRUNCATION,
             ODBCError::FractionalSecondsTruncation(_) => FRACTIONAL_TRUNCATION,
@@ -181,6 +256,12 @@ impl ODBCError {
             ODBCError::InvalidCharacterValue(_) => INVALID_CHARACTER_VALUE,
             ODBCError::IndicatorVariableRequiredButNotSupplied => INDICATOR_VARIABLE_REQUIRED,
             ODBCError::NoResultSet => NO_RESULTSET,
+            ODBCError::FunctionSequenceError(_) => FUNCTION_SEQUENCE_ERROR,
+            ODBCError::InvalidParameterType(_) => INVALID_PARAMETER_TYPE,
+            ODBCError::UnboundParameter(_) => COUNT_FIELD_INCORRECT,
+            ODBCError::ParameterSetFailed(_, e) => e.get_sql_state(),
+            ODBCError::NonCharacterDataInPieces => NON_CHARACTER_DATA_IN_PIECES,
+            ODBCError::InvalidStringOrBufferLength(_) => INVALID_STRING_OR_BUFFER_LENGTH,
             ODBCError::UnknownInfoType(_) => INVALID_INFO_TYPE_VALUE,
             ODBCError::ConnectionNotOpen => CONNECTION_NOT_OPEN,
         }
@@ -199,16 +280,30 @@ impl ODBCError {
             | ODBCError::InvalidAttrValue(_)
             | ODBCError::InvalidAttrIdentifier(_)
             | ODBCError::FetchTypeOutOfRange(_)
+            | ODBCError::FetchBeforeStart
+            | ODBCError::ColumnTypeOutOfRange(_)
+            | ODBCError::ScopeTypeOutOfRange(_)
+            | ODBCError::NullableTypeOutOfRange(_)
+            | ODBCError::UniquenessOptionOutOfRange(_)
+            | ODBCError::AccuracyOptionOutOfRange(_)
+            | ODBCError::InvalidBookmarkValue
             | ODBCError::InvalidCursorState
+            | ODBCError::InvalidCursorName(_)
+            | ODBCError::DuplicateCursorName(_)
+            | ODBCError::InvalidRetrievalCode(_)
             | ODBCError::InvalidHandleType(_)
             | ODBCError::InvalidTargetType(_)
             | ODBCError::MissingDriverOrDSNProperty
+            | ODBCError::DataSourceNotFound(_)
             | ODBCError::OutStringTruncated(_)
             | ODBCError::UnsupportedDriverConnectOption(_)
             | ODBCError::UnsupportedConnectionAttribute(_)
             | ODBCError::UnsupportedStatementAttribute(_)
             | ODBCError::UnsupportedFieldSchema()
             | ODBCError::InvalidFieldDescriptor(_)
+            | ODBCError::AssociatedStatementNotPrepared
+            | ODBCError::CannotModifyImpRowDesc
+            | ODBCError::InvalidUseOfAutomaticDescriptor
             | ODBCError::OptionValueChanged(_, _)
             | ODBCError::InvalidDescriptorIndex(_)
             | ODBCError::InvalidColumnNumber(_)
@@ -225,10 +320,16 @@ impl ODBCError {
             | ODBCError::InvalidCharacterValue(_)
             | ODBCError::InvalidDriverCompletion(_)
             | ODBCError::NoResultSet
+            | ODBCError::FunctionSequenceError(_)
+            | ODBCError::InvalidParameterType(_)
+            | ODBCError::UnboundParameter(_)
+            | ODBCError::NonCharacterDataInPieces
+            | ODBCError::InvalidStringOrBufferLength(_)
             | ODBCError::UnsupportedInfoTypeRetrieval(_)
             | ODBCError::ConnectionNotOpen
             | ODBCError::UnknownInfoType(_) => 0,
             ODBCError::Core(me) => me.code(),
+            ODBCError::ParameterSetFailed(_, e) => e.get_native_err_code(),
         }
     }
 }
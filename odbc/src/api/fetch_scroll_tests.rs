@@ -0,0 +1,346 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{
+            Connection, ConnectionState, Env, EnvState, MongoHandle, Statement, StatementState,
+        },
+        SQLBindCol, SQLFetch, SQLFetchScroll, SQLGetData, SQLSetStmtAttrW,
+    };
+    use bson::doc;
+    use definitions::{
+        CDataType, CursorType, FetchOrientation, Len, Nullability, Pointer, SmallInt, SqlReturn,
+        StatementAttribute, ULen, UseBookmarks,
+    };
+    use mongo_odbc_core::{
+        json_schema::{
+            simplified::{Atomic, Schema},
+            BsonTypeName,
+        },
+        mock_query::MongoQuery,
+        MongoColMetadata, MongoStaticCursor, TypeMode,
+    };
+
+    // Returns a static cursor over the rows 1 to 5, which keeps two rows in memory.
+    fn static_cursor() -> MongoStaticCursor {
+        MongoStaticCursor::new(
+            Box::new(MongoQuery::new(
+                (1..=5).map(|x| doc! {"": {"x": x}}).collect(),
+                vec![MongoColMetadata::new(
+                    "",
+                    "".to_string(),
+                    "x".to_string(),
+                    Schema::Atomic(Atomic::Scalar(BsonTypeName::Int)),
+                    Nullability::SQL_NO_NULLS,
+                    TypeMode::Simple,
+                    None,
+                )],
+            )),
+            None,
+            2,
+        )
+    }
+
+    #[test]
+    fn test_fetch_scroll_static_cursor() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetStmtAttrW(
+                    stmt as *mut _,
+                    StatementAttribute::SQL_ATTR_CURSOR_TYPE as i32,
+                    CursorType::SQL_CURSOR_STATIC as usize as Pointer,
+                    0,
+                )
+            );
+            let mut rows_fetched: ULen = 0;
+            s.attributes.write().unwrap().row_array_size = 2;
+            s.attributes.write().unwrap().rows_fetched_ptr = &mut rows_fetched;
+            *s.mongo_statement.write().unwrap() = Some(Box::new(static_cursor()));
+
+            let mut values = [0i32; 2];
+            let mut indicators: [Len; 2] = [0; 2];
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindCol(
+                    stmt as *mut _,
+                    1,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    values.as_mut_ptr() as Pointer,
+                    4,
+                    indicators.as_mut_ptr(),
+                )
+            );
+
+            let mut fetch = |orientation: FetchOrientation, offset: Len| {
+                values = [0; 2];
+                let sql_return = SQLFetchScroll(stmt as *mut _, orientation as SmallInt, offset);
+                (sql_return, rows_fetched, values)
+            };
+
+            assert_eq!(
+                (SqlReturn::SUCCESS, 2, [4, 5]),
+                fetch(FetchOrientation::SQL_FETCH_LAST, 0)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 2, [2, 3]),
+                fetch(FetchOrientation::SQL_FETCH_PRIOR, 0)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 1, [5, 0]),
+                fetch(FetchOrientation::SQL_FETCH_ABSOLUTE, 5)
+            );
+            assert_eq!(
+                (SqlReturn::NO_DATA, 0, [0, 0]),
+                fetch(FetchOrientation::SQL_FETCH_NEXT, 0)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 2, [3, 4]),
+                fetch(FetchOrientation::SQL_FETCH_RELATIVE, -3)
+            );
+            assert_eq!(
+                (SqlReturn::SUCCESS, 2, [2, 3]),
+                fetch(FetchOrientation::SQL_FETCH_RELATIVE, -1)
+            );
+
+            // Moving back before the first row returns the first rowset with a warning.
+            assert_eq!(
+                (SqlReturn::SUCCESS_WITH_INFO, 2, [1, 2]),
+                fetch(FetchOrientation::SQL_FETCH_PRIOR, 0)
+            );
+            assert_eq!(
+                "01S06",
+                s.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+            );
+            assert_eq!(
+                (SqlReturn::NO_DATA, 0, [0, 0]),
+                fetch(FetchOrientation::SQL_FETCH_PRIOR, 0)
+            );
+
+            // SQLFetch moves to the next rowset of a static cursor.
+            assert_eq!(
+                (SqlReturn::SUCCESS, 2, [1, 2]),
+                fetch(FetchOrientation::SQL_FETCH_FIRST, 0)
+            );
+            assert_eq!(SqlReturn::SUCCESS, SQLFetch(stmt as *mut _));
+            assert_eq!([3, 4], values);
+        }
+    }
+
+    #[test]
+    fn test_fetch_scroll_bookmark() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetStmtAttrW(
+                    stmt as *mut _,
+                    StatementAttribute::SQL_ATTR_CURSOR_TYPE as i32,
+                    CursorType::SQL_CURSOR_STATIC as usize as Pointer,
+                    0,
+                )
+            );
+            *s.mongo_statement.write().unwrap() = Some(Box::new(static_cursor()));
+
+            // Column 0 is only available once bookmarks are enabled.
+            let mut bookmark = [0u8; 8];
+            let mut bookmark_len: Len = 0;
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLBindCol(
+                    stmt as *mut _,
+                    0,
+                    CDataType::SQL_C_BINARY as SmallInt,
+                    bookmark.as_mut_ptr() as Pointer,
+                    8,
+                    &mut bookmark_len,
+                )
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetStmtAttrW(
+                    stmt as *mut _,
+                    StatementAttribute::SQL_ATTR_USE_BOOKMARKS as i32,
+                    UseBookmarks::Variable as usize as Pointer,
+                    0,
+                )
+            );
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLBindCol(
+                    stmt as *mut _,
+                    0,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    bookmark.as_mut_ptr() as Pointer,
+                    8,
+                    &mut bookmark_len,
+                )
+            );
+            let mut value = 0i32;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindCol(
+                    stmt as *mut _,
+                    1,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    &mut value as *mut i32 as Pointer,
+                    4,
+                    std::ptr::null_mut(),
+                )
+            );
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_ABSOLUTE as SmallInt,
+                    3
+                )
+            );
+            assert_eq!(3, value);
+
+            // SQLGetData returns the bookmark of the current row for column 0.
+            let mut saved = [0u8; 8];
+            let mut saved_len: Len = 0;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLGetData(
+                    stmt as *mut _,
+                    0,
+                    CDataType::SQL_C_BINARY as SmallInt,
+                    saved.as_mut_ptr() as Pointer,
+                    8,
+                    &mut saved_len,
+                )
+            );
+            assert_eq!(8, saved_len);
+
+            // Bound bookmarks are filled in by each fetch.
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindCol(
+                    stmt as *mut _,
+                    0,
+                    CDataType::SQL_C_BINARY as SmallInt,
+                    bookmark.as_mut_ptr() as Pointer,
+                    8,
+                    &mut bookmark_len,
+                )
+            );
+
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_FIRST as SmallInt,
+                    0
+                )
+            );
+            assert_eq!((1, 8), (value, bookmark_len));
+
+            // Without a bookmark, fetching by bookmark fails.
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_BOOKMARK as SmallInt,
+                    0
+                )
+            );
+            assert_eq!(
+                "HY111",
+                s.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+            );
+
+            let mut fetch_bookmark = saved;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLSetStmtAttrW(
+                    stmt as *mut _,
+                    StatementAttribute::SQL_ATTR_FETCH_BOOKMARK_PTR as i32,
+                    fetch_bookmark.as_mut_ptr() as Pointer,
+                    0,
+                )
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_BOOKMARK as SmallInt,
+                    0
+                )
+            );
+            assert_eq!((3, saved), (value, bookmark));
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_BOOKMARK as SmallInt,
+                    -1
+                )
+            );
+            assert_eq!(2, value);
+            assert_eq!(
+                SqlReturn::NO_DATA,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_BOOKMARK as SmallInt,
+                    3
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_fetch_scroll_forward_only_cursor() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            *s.mongo_statement.write().unwrap() =
+                Some(Box::new(MongoQuery::new(vec![doc! {"": {"x": 1}}], vec![])));
+
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_FIRST as SmallInt,
+                    0
+                )
+            );
+            assert_eq!(
+                "HY106",
+                s.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFetchScroll(
+                    stmt as *mut _,
+                    FetchOrientation::SQL_FETCH_NEXT as SmallInt,
+                    0
+                )
+            );
+        }
+    }
+}
@@ -32,7 +32,7 @@ mod unit {
         let stmt: *mut _ =
             &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
 
-        unsafe { assert_eq!(SqlReturn::ERROR, SQLFreeStmt(stmt as *mut _, 1)) }
+        unsafe { assert_eq!(SqlReturn::ERROR, SQLFreeStmt(stmt as *mut _, 4)) }
     }
 
     #[test]
@@ -130,14 +130,14 @@ mod unit {
                 _ => panic!("cursor not closed -- able to call get_value()"),
             }
 
-            match s
+            let next = s
                 .mongo_statement
                 .write()
                 .unwrap()
                 .as_mut()
                 .unwrap()
-                .next(None)
-            {
+                .next(None);
+            match next {
                 // we expect false since there should be no data to iterate after SQLFreeStmt
                 Ok((false, _)) => {}
                 _ => panic!("cursor not closed -- able to call next()"),
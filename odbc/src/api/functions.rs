@@ -1,9 +1,15 @@
 use crate::{
     add_diag_with_function,
     api::{
+        async_exec::{complete_async, exec_async, poll_async},
         data::{i16_len, i32_len, ptr_safe_write},
-        diag::{get_diag_fieldw, get_diag_recw, get_stmt_diag_field},
+        desc::{self, DescField},
+        diag::{get_diag_field, get_diag_fieldw, get_diag_rec, get_diag_recw, get_stmt_diag_field},
         errors::{ODBCError, Result},
+        params::{
+            data_at_exec_value_to_bson, is_data_at_exec, param_c_type, param_set_binding,
+            param_value_to_bson, put_data_bytes,
+        },
         util::{connection_attribute_to_string, handle_sql_type, statement_attribute_to_string},
     },
     handles::definitions::*,
@@ -12,27 +18,39 @@ use crate::{
 use constants::*;
 use mongodb::bson::{doc, Bson};
 
-use cstr::{input_text_to_string_w, input_text_to_string_w_allow_null, Charset, WideChar};
+use cstr::{
+    input_text_to_string_a, input_text_to_string_a_allow_null, input_text_to_string_w,
+    input_text_to_string_w_allow_null, Charset, WideChar,
+};
 
 use definitions::{
-    AccessMode, AllocType, AsyncEnable, AttrConnectionPooling, AttrCpMatch, AttrOdbcVersion,
-    BindType, CDataType, Concurrency, ConnectionAttribute, CursorScrollable, CursorSensitivity,
+    AccessMode, AllocType, AttrConnectionPooling, AttrCpMatch, AttrOdbcVersion, BindType,
+    CDataType, Char, Concurrency, ConnectionAttribute, CursorScrollable, CursorSensitivity,
     CursorType, Desc, DiagType, DriverConnectOption, EnvironmentAttribute, FetchOrientation,
     FreeStmtOption, HDbc, HDesc, HEnv, HStmt, HWnd, Handle, HandleType, Integer, Len, NoScan,
-    Pointer, RetCode, RetrieveData, RowStatus, SmallInt, SqlBool, SqlDataType, SqlReturn,
-    StatementAttribute, ULen, USmallInt, UseBookmarks, SQL_NTS,
+    Nullability, ParamStatus, ParamType, Pointer, RetCode, RetrieveData, RowStatus, SmallInt,
+    SqlBool, SqlDataType, SqlReturn, StatementAttribute, ULen, USmallInt, UseBookmarks, SQL_NTS,
 };
 use function_name::named;
 use log::{debug, error, info};
 use logger::Logger;
 use mongo_odbc_core::{
-    odbc_uri::ODBCUri, Error, MongoColMetadata, MongoCollections, MongoConnection, MongoDatabases,
-    MongoFields, MongoForeignKeys, MongoPrimaryKeys, MongoQuery, MongoStatement, MongoTableTypes,
-    MongoTypesInfo, TypeMode,
+    escapes::rewrite_escape_sequences,
+    odbc_uri::{ODBCUri, DATABASE},
+    parameters::{count_parameter_markers, split_statements, substitute_parameters},
+    Error, MongoColMetadata, MongoCollections, MongoConnection, MongoDatabases, MongoFields,
+    MongoForeignKeys, MongoPrimaryKeys, MongoPrivileges, MongoQuery, MongoSpecialColumns,
+    MongoStatement, MongoStaticCursor, MongoStatistics, MongoTableTypes, MongoTypesInfo,
+    ScrollResult, TypeMode,
 };
 use num_traits::FromPrimitive;
 use std::ptr::null_mut;
-use std::{collections::HashMap, mem::size_of, panic, sync::mpsc};
+use std::{
+    collections::{HashMap, VecDeque},
+    mem::size_of,
+    panic,
+    sync::{mpsc, RwLock},
+};
 
 const NULL_HANDLE_ERROR: &str = "handle cannot be null";
 const HANDLE_MUST_BE_ENV_ERROR: &str = "handle must be env";
@@ -187,7 +205,11 @@ macro_rules! panic_safe_exec_keep_diagnostics {
     }};
 }
 pub(crate) use panic_safe_exec_keep_diagnostics;
-use shared_sql_utils::driver_settings::DriverSettings;
+use shared_sql_utils::{
+    driver_settings::DriverSettings,
+    odbcinst::{ODBC_BOTH_DSN, ODBC_SYSTEM_DSN, ODBC_USER_DSN},
+    Dsn,
+};
 
 ///
 /// unsupported_function is a macro for correctly setting the state for unsupported functions.
@@ -303,6 +325,7 @@ fn sql_alloc_handle(
             let stmt = Statement::with_state(input_handle, StatementState::Allocated);
             let mh = Box::new(MongoHandle::Statement(stmt));
             let mh_ptr = Box::into_raw(mh);
+            unsafe { (*mh_ptr).as_statement().unwrap() }.set_descriptor_statement(mh_ptr);
             conn.statements.write().unwrap().insert(mh_ptr);
             *(conn.state.write().unwrap()) = ConnectionState::StatementAllocated;
             unsafe { *output_handle = mh_ptr.cast() }
@@ -313,7 +336,7 @@ fn sql_alloc_handle(
                 return Err(ODBCError::InvalidHandleType(NULL_HANDLE_ERROR));
             }
             // input handle must be a Connection
-            unsafe {
+            let conn = unsafe {
                 (*input_handle)
                     .as_connection()
                     .ok_or(ODBCError::InvalidHandleType(HANDLE_MUST_BE_CONN_ERROR))?
@@ -321,6 +344,7 @@ fn sql_alloc_handle(
             let desc = Descriptor::with_state(input_handle, DescriptorState::ExplicitlyAllocated);
             let mh = Box::new(MongoHandle::Descriptor(desc));
             let mh_ptr = Box::into_raw(mh);
+            conn.descriptors.write().unwrap().insert(mh_ptr);
             unsafe { *output_handle = mh_ptr.cast() }
             Ok(())
         }
@@ -349,34 +373,6 @@ pub unsafe extern "C" fn SQLBindCol(
             let mongo_handle = try_mongo_handle!(hstmt);
             let stmt = must_be_valid!((*mongo_handle).as_statement());
 
-            // Currently, we only support column binding with no offsets.
-            // Make sure that column-wise binding is being used.
-            if stmt.attributes.read().unwrap().row_bind_type != BindType::SQL_BIND_BY_COLUMN as ULen
-            {
-                let mongo_handle = try_mongo_handle!(hstmt);
-                add_diag_info!(
-                    mongo_handle,
-                    ODBCError::Unimplemented("`row-wise column binding`")
-                );
-                return SqlReturn::ERROR;
-            }
-
-            // Make sure that offsets are not being used in column binding.
-            if !stmt
-                .attributes
-                .read()
-                .unwrap()
-                .row_bind_offset_ptr
-                .is_null()
-            {
-                let mongo_handle = try_mongo_handle!(hstmt);
-                add_diag_info!(
-                    mongo_handle,
-                    ODBCError::Unimplemented("`column binding with offsets`")
-                );
-                return SqlReturn::ERROR;
-            }
-
             // Make sure that a query was executed/prepared and the number of columns for the resultset is known.
             let mongo_stmt = stmt.mongo_statement.read().unwrap();
             if mongo_stmt.is_none() {
@@ -392,77 +388,243 @@ pub unsafe extern "C" fn SQLBindCol(
                 .get_resultset_metadata(max_string_length)
                 .len();
 
-            // Make sure that col_number is in bounds. Columns are 1-indexed as per the ODBC spec.
-            if (col_number as usize) > max_col_index || col_number == 0 {
+            // Make sure that col_number is in bounds. Columns are 1-indexed as per the ODBC spec,
+            // and column 0 is the bookmark column when bookmarks are enabled.
+            let use_bookmarks = !matches!(
+                stmt.attributes.read().unwrap().use_bookmarks,
+                UseBookmarks::Off
+            );
+            if (col_number as usize) > max_col_index || (col_number == 0 && !use_bookmarks) {
                 let mongo_handle = try_mongo_handle!(hstmt);
                 add_diag_info!(mongo_handle, ODBCError::InvalidColumnNumber(col_number));
                 return SqlReturn::ERROR;
             }
 
-            // make sure that target_type is valid.
-            if <CDataType as FromPrimitive>::from_i16(target_type).is_none() {
+            // make sure that target_type is valid. Bookmarks are variable-length binary data.
+            if <CDataType as FromPrimitive>::from_i16(target_type).is_none()
+                || (col_number == 0 && target_type != CDataType::SQL_C_BINARY as SmallInt)
+            {
                 let mongo_handle = try_mongo_handle!(hstmt);
                 add_diag_info!(mongo_handle, ODBCError::InvalidTargetType(target_type));
                 return SqlReturn::ERROR;
             }
 
-            if stmt.bound_cols.read().unwrap().is_none() {
-                *stmt.bound_cols.write().unwrap() = Some(HashMap::new());
+            // The bound columns are the records of the application row descriptor.
+            stmt.update_app_row_desc(|ard| {
+                let bound_cols = ard.bound_cols.get_or_insert_with(HashMap::new);
+                // Unbind column if target_value is null
+                if target_value.is_null() {
+                    bound_cols.remove(&col_number);
+                }
+                // Bind column or rebind column with a new value
+                else {
+                    bound_cols.insert(
+                        col_number,
+                        BoundColInfo {
+                            target_type,
+                            target_buffer: target_value,
+                            buffer_length,
+                            length_or_indicator,
+                        },
+                    );
+                }
+            });
+
+            SqlReturn::SUCCESS
+        },
+        hstmt
+    );
+}
+
+///
+/// [`SQLBindParameter`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLBindParameter-function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+//
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLBindParameter(
+    hstmt: HStmt,
+    parameter_number: USmallInt,
+    input_output_type: SmallInt,
+    value_type: SmallInt,
+    parameter_type: SmallInt,
+    column_size: ULen,
+    decimal_digits: SmallInt,
+    parameter_value_ptr: Pointer,
+    buffer_length: Len,
+    str_len_or_ind_ptr: *mut Len,
+) -> SqlReturn {
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(hstmt);
+            let stmt = must_be_valid!((*mongo_handle).as_statement());
+
+            // Parameters are 1-indexed as per the ODBC spec.
+            if parameter_number == 0 {
+                let mongo_handle = try_mongo_handle!(hstmt);
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::InvalidDescriptorIndex(parameter_number)
+                );
+                return SqlReturn::ERROR;
             }
 
-            // Unbind column if target_value is null
-            if target_value.is_null() {
-                stmt.bound_cols
-                    .write()
-                    .unwrap()
-                    .as_mut()
-                    .unwrap()
-                    .remove(&col_number);
+            // We only support input parameters since we only support queries.
+            match FromPrimitive::from_i16(input_output_type) {
+                Some(ParamType::SQL_PARAM_INPUT) => {}
+                Some(_) => {
+                    let mongo_handle = try_mongo_handle!(hstmt);
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::Unimplemented("`output parameters`")
+                    );
+                    return SqlReturn::ERROR;
+                }
+                None => {
+                    let mongo_handle = try_mongo_handle!(hstmt);
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::InvalidParameterType(input_output_type)
+                    );
+                    return SqlReturn::ERROR;
+                }
             }
-            // Bind column or rebind column with a new value
-            else {
-                let bound_col_info = BoundColInfo {
-                    target_type,
-                    target_buffer: target_value,
-                    buffer_length,
-                    length_or_indicator,
-                };
 
-                stmt.bound_cols
-                    .write()
-                    .unwrap()
-                    .as_mut()
-                    .unwrap()
-                    .insert(col_number, bound_col_info);
+            if <CDataType as FromPrimitive>::from_i16(value_type).is_none() {
+                let mongo_handle = try_mongo_handle!(hstmt);
+                add_diag_info!(mongo_handle, ODBCError::InvalidTargetType(value_type));
+                return SqlReturn::ERROR;
+            }
+
+            if <SqlDataType as FromPrimitive>::from_i16(parameter_type).is_none() {
+                let mongo_handle = try_mongo_handle!(hstmt);
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::InvalidSqlType(parameter_type.to_string())
+                );
+                return SqlReturn::ERROR;
+            }
+
+            let param = BoundParamInfo {
+                input_output_type,
+                value_type,
+                parameter_type,
+                column_size,
+                decimal_digits,
+                parameter_value_ptr,
+                buffer_length,
+                str_len_or_ind_ptr,
+            };
+            if let Err(e) = param_c_type(&param) {
+                let mongo_handle = try_mongo_handle!(hstmt);
+                add_diag_info!(mongo_handle, e);
+                return SqlReturn::ERROR;
             }
 
+            // Bind parameter or rebind parameter with a new value. The value itself is not read
+            // until the statement is executed.
+            stmt.bound_params
+                .write()
+                .unwrap()
+                .get_or_insert_with(HashMap::new)
+                .insert(parameter_number, param);
+
             SqlReturn::SUCCESS
         },
         hstmt
     );
 }
 
+macro_rules! sql_browse_connect_impl {
+    (
+        $connection_handle:ident,
+        $in_connection_string:ident,
+        $string_length:ident,
+        $out_connection_string:ident,
+        $buffer_length:ident,
+        $out_buffer_length:ident,
+        $input_text:path,
+        $set_output_string:path,
+    ) => {{
+        let connection_handle = $connection_handle;
+        let in_connection_string = $in_connection_string;
+        let string_length = $string_length;
+        let out_connection_string = $out_connection_string;
+        let buffer_length = $buffer_length;
+        let out_buffer_length = $out_buffer_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let conn_handle = try_mongo_handle!(connection_handle);
+                let conn = must_be_valid!((*conn_handle).as_connection());
+                let input = $input_text(in_connection_string, string_length.into());
+                // Attributes supplied in this call take precedence over those from previous calls,
+                // since the first occurrence of a repeated attribute is the one that is kept.
+                let attributes = match conn.browse_connect_attributes.write().unwrap().take() {
+                    Some(previous) => format!("{input};{previous}"),
+                    None => input,
+                };
+                // An error ends the browse, and the next call starts over.
+                let browse_request =
+                    odbc_unwrap!(sql_browse_connect(conn, &attributes), conn_handle);
+                let (output, sql_return) = match browse_request {
+                    Some(browse_request) => {
+                        *conn.browse_connect_attributes.write().unwrap() = Some(attributes);
+                        (browse_request, SqlReturn::NEED_DATA)
+                    }
+                    None => (attributes, SqlReturn::SUCCESS),
+                };
+                let buffer_len = usize::try_from(buffer_length).unwrap_or(0);
+                if $set_output_string(
+                    &output,
+                    out_connection_string,
+                    buffer_len,
+                    out_buffer_length,
+                ) == SqlReturn::SUCCESS_WITH_INFO
+                {
+                    add_diag_info!(conn_handle, ODBCError::OutStringTruncated(buffer_len));
+                    if sql_return == SqlReturn::SUCCESS {
+                        return SqlReturn::SUCCESS_WITH_INFO;
+                    }
+                }
+                sql_return
+            },
+            connection_handle
+        );
+    }};
+}
+
 ///
-/// [`SQLBindParameter`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLBindParameter-function
+/// [`SQLBrowseConnect`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLBrowseConnect-function
+///
+/// This is the ANSI version of the SQLBrowseConnect function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
-//
+///
 #[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLBindParameter(
-    hstmt: HStmt,
-    _parameter_number: USmallInt,
-    _input_output_type: SmallInt,
-    _value_type: SmallInt,
-    _parmeter_type: SmallInt,
-    _column_size: ULen,
-    _decimal_digits: SmallInt,
-    _parameter_value_ptr: Pointer,
-    _buffer_length: Len,
-    _str_len_or_ind_ptr: *mut Len,
+pub unsafe extern "C" fn SQLBrowseConnect(
+    connection_handle: HDbc,
+    in_connection_string: *const Char,
+    string_length: SmallInt,
+    out_connection_string: *mut Char,
+    buffer_length: SmallInt,
+    out_buffer_length: *mut SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(hstmt)
+    sql_browse_connect_impl!(
+        connection_handle,
+        in_connection_string,
+        string_length,
+        out_connection_string,
+        buffer_length,
+        out_buffer_length,
+        input_text_to_string_a,
+        i16_len::set_output_string,
+    )
 }
 
 ///
@@ -477,13 +639,48 @@ pub unsafe extern "C" fn SQLBindParameter(
 #[no_mangle]
 pub unsafe extern "C" fn SQLBrowseConnectW(
     connection_handle: HDbc,
-    _in_connection_string: *const WideChar,
-    _string_length: SmallInt,
-    _out_connection_string: *mut WideChar,
-    _buffer_length: SmallInt,
-    _out_buffer_length: *mut SmallInt,
+    in_connection_string: *const WideChar,
+    string_length: SmallInt,
+    out_connection_string: *mut WideChar,
+    buffer_length: SmallInt,
+    out_buffer_length: *mut SmallInt,
 ) -> SqlReturn {
-    unimpl!(connection_handle);
+    sql_browse_connect_impl!(
+        connection_handle,
+        in_connection_string,
+        string_length,
+        out_connection_string,
+        buffer_length,
+        out_buffer_length,
+        input_text_to_string_w,
+        i16_len::set_output_wstring,
+    )
+}
+
+///
+/// sql_browse_connect returns the browse result string of the attributes that are still needed
+/// to connect with `attributes`, or connects and returns None once none are missing. The server
+/// and credentials are requested first, then the database, chosen among the databases listed by
+/// connecting without one.
+///
+fn sql_browse_connect(conn: &Connection, attributes: &str) -> Result<Option<String>> {
+    let odbc_uri = ODBCUri::new(attributes.to_string())?;
+    if let Some(browse_request) = odbc_uri.browse_request() {
+        return Ok(Some(browse_request));
+    }
+    if odbc_uri.get_attribute(&[DATABASE]).is_none()
+        && conn.attributes.read().unwrap().current_catalog.is_none()
+    {
+        let mongo_connection = sql_driver_connect(conn, attributes)?;
+        let database_names = mongo_connection.list_database_names();
+        let _ = mongo_connection.shutdown();
+        return Ok(Some(format!(
+            "DATABASE:Database={{{}}}",
+            database_names?.join(",")
+        )));
+    }
+    *conn.mongo_connection.write().unwrap() = Some(sql_driver_connect(conn, attributes)?);
+    Ok(None)
 }
 
 ///
@@ -516,11 +713,28 @@ pub unsafe extern "C" fn SQLCancel(statement_handle: HStmt) -> SqlReturn {
             let mongo_handle = try_mongo_handle!(statement_handle);
             let stmt = must_be_valid!(mongo_handle.as_statement());
 
+            // cancel the data-at-execution sequence, returning the statement to the state
+            // it was in before SQLExecute or SQLExecDirect was called
+            let needs_data = matches!(
+                *stmt.state.read().unwrap(),
+                StatementState::FunctionNeedsDataNoParam
+                    | StatementState::FunctionNeedsDataNoPut
+                    | StatementState::FunctionNeedsDataPutCalled
+            );
+            if needs_data {
+                *stmt.data_at_exec.write().unwrap() = None;
+                *stmt.state.write().unwrap() = StatementState::Allocated;
+                return SqlReturn::SUCCESS;
+            }
+
             // use the statement state to determine if a query is executing or not
             match *(stmt.state.read().unwrap()) {
                 // if a query is executing, verify we have a connection (we must to be executing a query) and use that connection to kill
                 // queries associated with the current statement handle
+                // The statement stops between rows or while it waits for the server once it is
+                // cancelled, and its queries still running on the server are killed.
                 StatementState::SynchronousQueryExecuting => {
+                    stmt.cancellation.cancel();
                     let stmt_id = stmt.statement_id.read().unwrap().clone();
                     let conn = must_be_valid!((*stmt.connection).as_connection());
                     if let Some(mongo_connection) = conn.mongo_connection.read().unwrap().as_ref() {
@@ -528,14 +742,8 @@ pub unsafe extern "C" fn SQLCancel(statement_handle: HStmt) -> SqlReturn {
                             mongo_connection.cancel_queries_for_statement(stmt_id),
                             try_mongo_handle!(statement_handle)
                         );
-                        SqlReturn::SUCCESS
-                    } else {
-                        stmt.errors
-                            .write()
-                            .unwrap()
-                            .push(ODBCError::InvalidCursorState);
-                        SqlReturn::ERROR
                     }
+                    SqlReturn::SUCCESS
                 }
                 _ => SqlReturn::SUCCESS,
             }
@@ -552,8 +760,47 @@ pub unsafe extern "C" fn SQLCancel(statement_handle: HStmt) -> SqlReturn {
 ///
 #[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLCancelHandle(_handle_type: HandleType, handle: Handle) -> SqlReturn {
-    unimpl!(handle);
+pub unsafe extern "C" fn SQLCancelHandle(handle_type: HandleType, handle: Handle) -> SqlReturn {
+    if matches!(handle_type, HandleType::SQL_HANDLE_STMT) {
+        return SQLCancel(handle.cast());
+    }
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(handle);
+            if !matches!(handle_type, HandleType::SQL_HANDLE_DBC) {
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::InvalidAttrIdentifier(handle_type as i32)
+                );
+                return SqlReturn::ERROR;
+            }
+            let conn = must_be_valid!(mongo_handle.as_connection());
+            // cancel every statement of the connection that is executing, then kill the queries
+            // they are running on the server
+            let statements: Vec<*mut MongoHandle> =
+                conn.statements.read().unwrap().iter().copied().collect();
+            let mut stmt_ids = Vec::new();
+            for statement in statements {
+                if let Some(stmt) = (*statement).as_statement() {
+                    if *stmt.state.read().unwrap() == StatementState::SynchronousQueryExecuting {
+                        stmt.cancellation.cancel();
+                        stmt_ids.push(stmt.statement_id.read().unwrap().clone());
+                    }
+                }
+            }
+            if let Some(mongo_connection) = conn.mongo_connection.read().unwrap().as_ref() {
+                for stmt_id in stmt_ids {
+                    odbc_unwrap!(
+                        mongo_connection.cancel_queries_for_statement(stmt_id),
+                        try_mongo_handle!(handle)
+                    );
+                }
+            }
+            SqlReturn::SUCCESS
+        },
+        handle
+    )
 }
 
 ///
@@ -562,23 +809,258 @@ pub unsafe extern "C" fn SQLCancelHandle(_handle_type: HandleType, handle: Handl
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
+#[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLCloseCursor(_statement_handle: HStmt) -> SqlReturn {
-    // We never need to do anything to close a cusor, so this is safe.
-    SqlReturn::SUCCESS
+pub unsafe extern "C" fn SQLCloseCursor(statement_handle: HStmt) -> SqlReturn {
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(statement_handle);
+            let stmt = must_be_valid!(mongo_handle.as_statement());
+            // We never need to do anything else to close a cursor, but its name can change again.
+            *stmt.cursor_open.write().unwrap() = false;
+            SqlReturn::SUCCESS
+        },
+        statement_handle
+    );
+}
+
+macro_rules! sql_col_attribute_impl {
+    (
+        $statement_handle:ident,
+        $column_number:ident,
+        $field_identifier:ident,
+        $character_attribute_ptr:ident,
+        $buffer_length:ident,
+        $string_length_ptr:ident,
+        $numeric_attribute_ptr:ident,
+        $set_output_string:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let column_number = $column_number;
+        let field_identifier = $field_identifier;
+        let character_attribute_ptr = $character_attribute_ptr;
+        let buffer_length = $buffer_length;
+        let string_length_ptr = $string_length_ptr;
+        let numeric_attribute_ptr = $numeric_attribute_ptr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                let odbc_version = mongo_handle.get_odbc_version();
+                let stmt = must_be_valid!((*mongo_handle).as_statement());
+                let mongo_stmt = stmt.mongo_statement.read().unwrap();
+                stmt.errors.write().unwrap().clear();
+                if mongo_stmt.is_none() {
+                    stmt.errors.write().unwrap().push(ODBCError::NoResultSet);
+                    return SqlReturn::ERROR;
+                }
+                let max_string_length = stmt.get_max_string_length();
+                let string_col_attr = |f: &dyn Fn(&MongoColMetadata) -> &str| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let col_metadata = mongo_stmt
+                        .as_ref()
+                        .unwrap()
+                        .get_col_metadata(column_number, max_string_length);
+                    if let Ok(col_metadata) = col_metadata {
+                        return $set_output_string(
+                            (*f)(col_metadata),
+                            character_attribute_ptr,
+                            buffer_length as usize,
+                            string_length_ptr,
+                        );
+                    }
+                    // unfortunately, we cannot use odbc_unwrap! on the value because it causes a deadlock.
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::InvalidDescriptorIndex(column_number)
+                    );
+                    SqlReturn::ERROR
+                };
+                let numeric_col_attr = |f: &dyn Fn(&MongoColMetadata) -> Len| {
+                    {
+                        let col_metadata = mongo_stmt
+                            .as_ref()
+                            .unwrap()
+                            .get_col_metadata(column_number, max_string_length);
+                        if let Ok(col_metadata) = col_metadata {
+                            *numeric_attribute_ptr = (*f)(col_metadata);
+                            return SqlReturn::SUCCESS;
+                        }
+                    }
+                    // unfortunately, we cannot use odbc_unwrap! on the value because it causes a deadlock.
+                    stmt.errors
+                        .write()
+                        .unwrap()
+                        .push(ODBCError::InvalidDescriptorIndex(column_number));
+                    SqlReturn::ERROR
+                };
+                match FromPrimitive::from_u16(field_identifier) {
+                    Some(desc) => match desc {
+                        Desc::SQL_DESC_AUTO_UNIQUE_VALUE => {
+                            *numeric_attribute_ptr = SqlBool::SQL_FALSE as Len;
+                            SqlReturn::SUCCESS
+                        }
+                        Desc::SQL_DESC_UNNAMED | Desc::SQL_DESC_UPDATABLE => {
+                            *numeric_attribute_ptr = 0 as Len;
+                            SqlReturn::SUCCESS
+                        }
+                        Desc::SQL_DESC_COUNT => {
+                            *numeric_attribute_ptr = isize::try_from(
+                                mongo_stmt
+                                    .as_ref()
+                                    .unwrap()
+                                    .get_resultset_metadata(max_string_length)
+                                    .len(),
+                            )
+                            .expect("SQL_DESC_COUNT value exceeds isize on this platform");
+                            SqlReturn::SUCCESS
+                        }
+                        Desc::SQL_DESC_CASE_SENSITIVE => {
+                            numeric_col_attr(&|x: &MongoColMetadata| isize::from(x.case_sensitive))
+                        }
+                        Desc::SQL_DESC_BASE_COLUMN_NAME => {
+                            string_col_attr(&|x: &MongoColMetadata| x.base_col_name.as_ref())
+                        }
+                        Desc::SQL_DESC_BASE_TABLE_NAME => {
+                            string_col_attr(&|x: &MongoColMetadata| x.base_table_name.as_ref())
+                        }
+                        Desc::SQL_DESC_CATALOG_NAME => {
+                            string_col_attr(&|x: &MongoColMetadata| x.catalog_name.as_ref())
+                        }
+                        Desc::SQL_DESC_DISPLAY_SIZE => numeric_col_attr(&|x: &MongoColMetadata| {
+                            isize::try_from(x.display_size.unwrap_or(0))
+                                .expect("display size exceeds isize on this platform")
+                        }),
+                        Desc::SQL_DESC_FIXED_PREC_SCALE => {
+                            numeric_col_attr(&|x: &MongoColMetadata| isize::from(x.fixed_prec_scale))
+                        }
+                        Desc::SQL_DESC_LABEL => {
+                            string_col_attr(&|x: &MongoColMetadata| x.label.as_ref())
+                        }
+                        Desc::SQL_DESC_LITERAL_PREFIX => {
+                            string_col_attr(&|x: &MongoColMetadata| x.literal_prefix.unwrap_or(""))
+                        }
+                        Desc::SQL_DESC_LITERAL_SUFFIX => {
+                            string_col_attr(&|x: &MongoColMetadata| x.literal_suffix.unwrap_or(""))
+                        }
+                        Desc::SQL_DESC_LOCAL_TYPE_NAME | Desc::SQL_DESC_SCHEMA_NAME => {
+                            string_col_attr(&|_| "")
+                        }
+                        Desc::SQL_DESC_NAME => {
+                            string_col_attr(&|x: &MongoColMetadata| x.col_name.as_ref())
+                        }
+                        Desc::SQL_DESC_NULLABLE => {
+                            numeric_col_attr(&|x: &MongoColMetadata| x.nullability as Len)
+                        }
+                        Desc::SQL_DESC_NUM_PREC_RADIX => numeric_col_attr(&|x: &MongoColMetadata| {
+                            isize::try_from(x.num_prec_radix.unwrap_or(0))
+                                .expect("num_prec_radix exceeds isize on this platform")
+                        }),
+                        Desc::SQL_DESC_OCTET_LENGTH | Desc::SQL_COLUMN_LENGTH => {
+                            numeric_col_attr(&|x: &MongoColMetadata| {
+                                isize::try_from(x.transfer_octet_length.unwrap_or(0))
+                                    .expect("transfer_octet_length exceeds isize on this platform")
+                            })
+                        }
+                        Desc::SQL_DESC_LENGTH => numeric_col_attr(&|x: &MongoColMetadata| {
+                            isize::try_from(x.length.unwrap_or(0))
+                                .expect("descriptor length exceeds isize on this platform")
+                        }),
+                        Desc::SQL_DESC_PRECISION => numeric_col_attr(&|x: &MongoColMetadata| {
+                            isize::try_from(x.precision.unwrap_or(0))
+                                .expect("descriptor precision exceeds isize on this platform")
+                        }),
+                        // Column size
+                        Desc::SQL_COLUMN_PRECISION => numeric_col_attr(&|x: &MongoColMetadata| {
+                            x.column_size
+                                .unwrap_or(0)
+                                .try_into()
+                                .expect("column size exceeds isize on this platform")
+                        }),
+                        // Decimal digit
+                        Desc::SQL_COLUMN_SCALE => numeric_col_attr(&|x: &MongoColMetadata| {
+                            x.decimal_digits
+                                .unwrap_or(0)
+                                .try_into()
+                                .expect("decimal digits exceeds isize")
+                        }),
+                        Desc::SQL_DESC_SCALE => numeric_col_attr(&|x: &MongoColMetadata| {
+                            x.scale
+                                .unwrap_or(0)
+                                .try_into()
+                                .expect("scale exceeds isize")
+                        }),
+                        Desc::SQL_DESC_SEARCHABLE => {
+                            numeric_col_attr(&|x: &MongoColMetadata| x.searchable as Len)
+                        }
+                        Desc::SQL_DESC_TABLE_NAME => {
+                            string_col_attr(&|x: &MongoColMetadata| x.table_name.as_ref())
+                        }
+                        Desc::SQL_DESC_TYPE_NAME => {
+                            string_col_attr(&|x: &MongoColMetadata| x.type_name.as_ref())
+                        }
+                        Desc::SQL_DESC_TYPE | Desc::SQL_DESC_CONCISE_TYPE => {
+                            numeric_col_attr(&|x: &MongoColMetadata| {
+                                handle_sql_type(odbc_version, x.sql_type) as Len
+                            })
+                        }
+                        Desc::SQL_DESC_UNSIGNED => {
+                            numeric_col_attr(&|x: &MongoColMetadata| x.is_unsigned.into())
+                        }
+                        Desc::SQL_DESC_ALLOC_TYPE => {
+                            numeric_col_attr(&|_| AllocType::SQL_DESC_ALLOC_AUTO as Len)
+                        }
+                        desc @ (Desc::SQL_DESC_OCTET_LENGTH_PTR
+                        | Desc::SQL_DESC_DATETIME_INTERVAL_CODE
+                        | Desc::SQL_DESC_INDICATOR_PTR
+                        | Desc::SQL_DESC_DATA_PTR
+                        | Desc::SQL_DESC_ARRAY_SIZE
+                        | Desc::SQL_DESC_ARRAY_STATUS_PTR
+                        | Desc::SQL_DESC_BIND_OFFSET_PTR
+                        | Desc::SQL_DESC_BIND_TYPE
+                        | Desc::SQL_DESC_DATETIME_INTERVAL_PRECISION
+                        | Desc::SQL_DESC_MAXIMUM_SCALE
+                        | Desc::SQL_DESC_MINIMUM_SCALE
+                        | Desc::SQL_DESC_PARAMETER_TYPE
+                        | Desc::SQL_DESC_ROWS_PROCESSED_PTR
+                        | Desc::SQL_DESC_ROWVER) => {
+                            let mongo_handle = try_mongo_handle!(statement_handle);
+                            let _ = must_be_valid!((*mongo_handle).as_statement());
+                            add_diag_info!(
+                                mongo_handle,
+                                ODBCError::UnsupportedFieldDescriptor(desc as u16)
+                            );
+                            SqlReturn::ERROR
+                        }
+                    },
+                    None => {
+                        let mongo_handle = try_mongo_handle!(statement_handle);
+                        let _ = must_be_valid!((*mongo_handle).as_statement());
+                        add_diag_info!(
+                            mongo_handle,
+                            ODBCError::InvalidFieldDescriptor(field_identifier)
+                        );
+                        SqlReturn::ERROR
+                    }
+                }
+            },
+            statement_handle
+        );
+    }};
 }
 
 ///
-/// [`SQLColAttributeW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLColAttribute-function
+/// [`SQLColAttribute`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLColAttribute-function
 ///
-/// This is the WideChar version of the SQLColAttribute function
+/// This is the ANSI version of the SQLColAttribute function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
 #[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLColAttributeW(
+pub unsafe extern "C" fn SQLColAttribute(
     statement_handle: HStmt,
     column_number: USmallInt,
     field_identifier: USmallInt,
@@ -587,210 +1069,142 @@ pub unsafe extern "C" fn SQLColAttributeW(
     string_length_ptr: *mut SmallInt,
     numeric_attribute_ptr: *mut Len,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let odbc_version = mongo_handle.get_odbc_version();
-            let stmt = must_be_valid!((*mongo_handle).as_statement());
-            let mongo_stmt = stmt.mongo_statement.read().unwrap();
-            stmt.errors.write().unwrap().clear();
-            if mongo_stmt.is_none() {
-                stmt.errors.write().unwrap().push(ODBCError::NoResultSet);
-                return SqlReturn::ERROR;
-            }
-            let max_string_length = stmt.get_max_string_length();
-            let string_col_attr = |f: &dyn Fn(&MongoColMetadata) -> &str| {
+    sql_col_attribute_impl!(
+        statement_handle,
+        column_number,
+        field_identifier,
+        character_attribute_ptr,
+        buffer_length,
+        string_length_ptr,
+        numeric_attribute_ptr,
+        i16_len::set_output_string_as_bytes,
+    )
+}
+
+///
+/// [`SQLColAttributeW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLColAttribute-function
+///
+/// This is the WideChar version of the SQLColAttribute function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLColAttributeW(
+    statement_handle: HStmt,
+    column_number: USmallInt,
+    field_identifier: USmallInt,
+    character_attribute_ptr: Pointer,
+    buffer_length: SmallInt,
+    string_length_ptr: *mut SmallInt,
+    numeric_attribute_ptr: *mut Len,
+) -> SqlReturn {
+    sql_col_attribute_impl!(
+        statement_handle,
+        column_number,
+        field_identifier,
+        character_attribute_ptr,
+        buffer_length,
+        string_length_ptr,
+        numeric_attribute_ptr,
+        i16_len::set_output_wstring_as_bytes,
+    )
+}
+
+macro_rules! sql_column_privileges_impl {
+    (
+        $statement_handle:ident,
+        $catalog_name:ident,
+        $catalog_name_length:ident,
+        $table_name:ident,
+        $table_name_length:ident,
+        $column_name:ident,
+        $column_name_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let catalog_name = $catalog_name;
+        let catalog_name_length = $catalog_name_length;
+        let table_name = $table_name;
+        let table_name_length = $table_name_length;
+        let column_name = $column_name;
+        let column_name_length = $column_name_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
                 let mongo_handle = try_mongo_handle!(statement_handle);
-                let col_metadata = mongo_stmt
-                    .as_ref()
-                    .unwrap()
-                    .get_col_metadata(column_number, max_string_length);
-                if let Ok(col_metadata) = col_metadata {
-                    return i16_len::set_output_wstring_as_bytes(
-                        (*f)(col_metadata),
-                        character_attribute_ptr,
-                        buffer_length as usize,
-                        string_length_ptr,
-                    );
-                }
-                // unfortunately, we cannot use odbc_unwrap! on the value because it causes a deadlock.
-                add_diag_info!(
-                    mongo_handle,
-                    ODBCError::InvalidDescriptorIndex(column_number)
-                );
-                SqlReturn::ERROR
-            };
-            let numeric_col_attr = |f: &dyn Fn(&MongoColMetadata) -> Len| {
-                {
-                    let col_metadata = mongo_stmt
-                        .as_ref()
-                        .unwrap()
-                        .get_col_metadata(column_number, max_string_length);
-                    if let Ok(col_metadata) = col_metadata {
-                        *numeric_attribute_ptr = (*f)(col_metadata);
-                        return SqlReturn::SUCCESS;
-                    }
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
                 }
-                // unfortunately, we cannot use odbc_unwrap! on the value because it causes a deadlock.
-                stmt.errors
-                    .write()
-                    .unwrap()
-                    .push(ODBCError::InvalidDescriptorIndex(column_number));
-                SqlReturn::ERROR
-            };
-            match FromPrimitive::from_u16(field_identifier) {
-                Some(desc) => match desc {
-                    Desc::SQL_DESC_AUTO_UNIQUE_VALUE => {
-                        *numeric_attribute_ptr = SqlBool::SQL_FALSE as Len;
-                        SqlReturn::SUCCESS
-                    }
-                    Desc::SQL_DESC_UNNAMED | Desc::SQL_DESC_UPDATABLE => {
-                        *numeric_attribute_ptr = 0 as Len;
-                        SqlReturn::SUCCESS
-                    }
-                    Desc::SQL_DESC_COUNT => {
-                        *numeric_attribute_ptr = isize::try_from(
-                            mongo_stmt
-                                .as_ref()
-                                .unwrap()
-                                .get_resultset_metadata(max_string_length)
-                                .len(),
-                        )
-                        .expect("SQL_DESC_COUNT value exceeds isize on this platform");
-                        SqlReturn::SUCCESS
-                    }
-                    Desc::SQL_DESC_CASE_SENSITIVE => {
-                        numeric_col_attr(&|x: &MongoColMetadata| isize::from(x.case_sensitive))
-                    }
-                    Desc::SQL_DESC_BASE_COLUMN_NAME => {
-                        string_col_attr(&|x: &MongoColMetadata| x.base_col_name.as_ref())
-                    }
-                    Desc::SQL_DESC_BASE_TABLE_NAME => {
-                        string_col_attr(&|x: &MongoColMetadata| x.base_table_name.as_ref())
-                    }
-                    Desc::SQL_DESC_CATALOG_NAME => {
-                        string_col_attr(&|x: &MongoColMetadata| x.catalog_name.as_ref())
-                    }
-                    Desc::SQL_DESC_DISPLAY_SIZE => numeric_col_attr(&|x: &MongoColMetadata| {
-                        isize::try_from(x.display_size.unwrap_or(0))
-                            .expect("display size exceeds isize on this platform")
-                    }),
-                    Desc::SQL_DESC_FIXED_PREC_SCALE => {
-                        numeric_col_attr(&|x: &MongoColMetadata| isize::from(x.fixed_prec_scale))
-                    }
-                    Desc::SQL_DESC_LABEL => {
-                        string_col_attr(&|x: &MongoColMetadata| x.label.as_ref())
-                    }
-                    Desc::SQL_DESC_LITERAL_PREFIX => {
-                        string_col_attr(&|x: &MongoColMetadata| x.literal_prefix.unwrap_or(""))
-                    }
-                    Desc::SQL_DESC_LITERAL_SUFFIX => {
-                        string_col_attr(&|x: &MongoColMetadata| x.literal_suffix.unwrap_or(""))
-                    }
-                    Desc::SQL_DESC_LOCAL_TYPE_NAME | Desc::SQL_DESC_SCHEMA_NAME => {
-                        string_col_attr(&|_| "")
-                    }
-                    Desc::SQL_DESC_NAME => {
-                        string_col_attr(&|x: &MongoColMetadata| x.col_name.as_ref())
-                    }
-                    Desc::SQL_DESC_NULLABLE => {
-                        numeric_col_attr(&|x: &MongoColMetadata| x.nullability as Len)
-                    }
-                    Desc::SQL_DESC_NUM_PREC_RADIX => numeric_col_attr(&|x: &MongoColMetadata| {
-                        isize::try_from(x.num_prec_radix.unwrap_or(0))
-                            .expect("num_prec_radix exceeds isize on this platform")
-                    }),
-                    Desc::SQL_DESC_OCTET_LENGTH | Desc::SQL_COLUMN_LENGTH => {
-                        numeric_col_attr(&|x: &MongoColMetadata| {
-                            isize::try_from(x.transfer_octet_length.unwrap_or(0))
-                                .expect("transfer_octet_length exceeds isize on this platform")
-                        })
-                    }
-                    Desc::SQL_DESC_LENGTH => numeric_col_attr(&|x: &MongoColMetadata| {
-                        isize::try_from(x.length.unwrap_or(0))
-                            .expect("descriptor length exceeds isize on this platform")
-                    }),
-                    Desc::SQL_DESC_PRECISION => numeric_col_attr(&|x: &MongoColMetadata| {
-                        isize::try_from(x.precision.unwrap_or(0))
-                            .expect("descriptor precision exceeds isize on this platform")
-                    }),
-                    // Column size
-                    Desc::SQL_COLUMN_PRECISION => numeric_col_attr(&|x: &MongoColMetadata| {
-                        x.column_size
-                            .unwrap_or(0)
-                            .try_into()
-                            .expect("column size exceeds isize on this platform")
-                    }),
-                    // Decimal digit
-                    Desc::SQL_COLUMN_SCALE => numeric_col_attr(&|x: &MongoColMetadata| {
-                        x.decimal_digits
-                            .unwrap_or(0)
-                            .try_into()
-                            .expect("decimal digits exceeds isize")
-                    }),
-                    Desc::SQL_DESC_SCALE => numeric_col_attr(&|x: &MongoColMetadata| {
-                        x.scale
-                            .unwrap_or(0)
-                            .try_into()
-                            .expect("scale exceeds isize")
-                    }),
-                    Desc::SQL_DESC_SEARCHABLE => {
-                        numeric_col_attr(&|x: &MongoColMetadata| x.searchable as Len)
-                    }
-                    Desc::SQL_DESC_TABLE_NAME => {
-                        string_col_attr(&|x: &MongoColMetadata| x.table_name.as_ref())
-                    }
-                    Desc::SQL_DESC_TYPE_NAME => {
-                        string_col_attr(&|x: &MongoColMetadata| x.type_name.as_ref())
-                    }
-                    Desc::SQL_DESC_TYPE | Desc::SQL_DESC_CONCISE_TYPE => {
-                        numeric_col_attr(&|x: &MongoColMetadata| {
-                            handle_sql_type(odbc_version, x.sql_type) as Len
-                        })
-                    }
-                    Desc::SQL_DESC_UNSIGNED => {
-                        numeric_col_attr(&|x: &MongoColMetadata| x.is_unsigned.into())
-                    }
-                    Desc::SQL_DESC_ALLOC_TYPE => {
-                        numeric_col_attr(&|_| AllocType::SQL_DESC_ALLOC_AUTO as Len)
-                    }
-                    desc @ (Desc::SQL_DESC_OCTET_LENGTH_PTR
-                    | Desc::SQL_DESC_DATETIME_INTERVAL_CODE
-                    | Desc::SQL_DESC_INDICATOR_PTR
-                    | Desc::SQL_DESC_DATA_PTR
-                    | Desc::SQL_DESC_ARRAY_SIZE
-                    | Desc::SQL_DESC_ARRAY_STATUS_PTR
-                    | Desc::SQL_DESC_BIND_OFFSET_PTR
-                    | Desc::SQL_DESC_BIND_TYPE
-                    | Desc::SQL_DESC_DATETIME_INTERVAL_PRECISION
-                    | Desc::SQL_DESC_MAXIMUM_SCALE
-                    | Desc::SQL_DESC_MINIMUM_SCALE
-                    | Desc::SQL_DESC_PARAMETER_TYPE
-                    | Desc::SQL_DESC_ROWS_PROCESSED_PTR
-                    | Desc::SQL_DESC_ROWVER) => {
-                        let mongo_handle = try_mongo_handle!(statement_handle);
-                        let _ = must_be_valid!((*mongo_handle).as_statement());
-                        add_diag_info!(
-                            mongo_handle,
-                            ODBCError::UnsupportedFieldDescriptor(desc as u16)
-                        );
-                        SqlReturn::ERROR
-                    }
-                },
-                None => {
+                let catalog = $input_text(catalog_name, catalog_name_length.into());
+                // ignore schema
+                let table = $input_text(table_name, table_name_length.into());
+                let column = $input_text(column_name, column_name_length.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
                     let mongo_handle = try_mongo_handle!(statement_handle);
-                    let _ = must_be_valid!((*mongo_handle).as_statement());
-                    add_diag_info!(
-                        mongo_handle,
-                        ODBCError::InvalidFieldDescriptor(field_identifier)
-                    );
-                    SqlReturn::ERROR
-                }
-            }
-        },
-        statement_handle
-    );
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    let catalog = catalog_or_current_catalog(connection, catalog);
+                    let mongo_statement = match catalog.is_empty() || table.is_empty() {
+                        true => MongoPrivileges::empty(true),
+                        false => odbc_unwrap!(
+                            MongoPrivileges::list_column_privileges(
+                                connection
+                                    .mongo_connection
+                                    .read()
+                                    .unwrap()
+                                    .as_ref()
+                                    .unwrap(),
+                                &catalog,
+                                &table,
+                                &column,
+                                *connection.type_mode.read().unwrap(),
+                                stmt.attributes.read().unwrap().max_rows as u64,
+                            ),
+                            mongo_handle
+                        ),
+                    };
+                    set_catalog_result_set(stmt, Box::new(mongo_statement));
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLColumnPrivileges`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLColumnPrivileges-function
+///
+/// This is the ANSI version of the SQLColumnPrivileges function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLColumnPrivileges(
+    statement_handle: HStmt,
+    catalog_name: *const Char,
+    catalog_name_length: SmallInt,
+    _schema_name: *const Char,
+    _schema_name_length: SmallInt,
+    table_name: *const Char,
+    table_name_length: SmallInt,
+    column_name: *const Char,
+    column_name_length: SmallInt,
+) -> SqlReturn {
+    sql_column_privileges_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        column_name,
+        column_name_length,
+        input_text_to_string_a_allow_null,
+    )
 }
 
 ///
@@ -805,16 +1219,141 @@ pub unsafe extern "C" fn SQLColAttributeW(
 #[no_mangle]
 pub unsafe extern "C" fn SQLColumnPrivilegesW(
     statement_handle: HStmt,
-    _catalog_name: *const WideChar,
-    _catalog_name_length: SmallInt,
+    catalog_name: *const WideChar,
+    catalog_name_length: SmallInt,
     _schema_name: *const WideChar,
     _schema_name_length: SmallInt,
-    _table_name: *const WideChar,
-    _table_name_length: SmallInt,
-    _column_name: *const WideChar,
-    _column_name_length: SmallInt,
+    table_name: *const WideChar,
+    table_name_length: SmallInt,
+    column_name: *const WideChar,
+    column_name_length: SmallInt,
 ) -> SqlReturn {
-    unimpl!(statement_handle);
+    sql_column_privileges_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        column_name,
+        column_name_length,
+        input_text_to_string_w_allow_null,
+    )
+}
+
+macro_rules! sql_columns_impl {
+    (
+        $statement_handle:ident,
+        $catalog_name:ident,
+        $catalog_name_length:ident,
+        $table_name:ident,
+        $table_name_length:ident,
+        $column_name:ident,
+        $column_name_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let catalog_name = $catalog_name;
+        let catalog_name_length = $catalog_name_length;
+        let table_name = $table_name;
+        let table_name_length = $table_name_length;
+        let column_name = $column_name;
+        let column_name_length = $column_name_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let catalog_string = $input_text(catalog_name, catalog_name_length.into());
+                let catalog = if catalog_name.is_null() || catalog_string.is_empty() {
+                    None
+                } else {
+                    Some(catalog_string)
+                };
+                // ignore schema
+                let table_string = $input_text(table_name, table_name_length.into());
+                let table = if table_name.is_null() {
+                    None
+                } else {
+                    Some(table_string)
+                };
+                let column_name_string = $input_text(column_name, column_name_length.into());
+                let column = if column_name.is_null() {
+                    None
+                } else {
+                    Some(column_name_string)
+                };
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let odbc_3_data_types = has_odbc_3_behavior!(mongo_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    let type_mode = *connection.type_mode.read().unwrap();
+                    let max_string_length = *connection.max_string_length.read().unwrap();
+                    let mongo_statement = Box::new(MongoFields::list_columns(
+                        connection
+                            .mongo_connection
+                            .read()
+                            .unwrap()
+                            .as_ref()
+                            .unwrap(),
+                        Some(
+                            stmt.attributes
+                                .read()
+                                .unwrap()
+                                .query_timeout
+                                .try_into()
+                                .unwrap_or(i32::MAX),
+                        ),
+                        catalog.as_deref(),
+                        table.as_deref(),
+                        column.as_deref(),
+                        type_mode,
+                        max_string_length,
+                        odbc_3_data_types,
+                        stmt.attributes.read().unwrap().max_rows as u64,
+                    ));
+                    set_catalog_result_set(stmt, mongo_statement);
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLColumns`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLColumns-function
+///
+/// This is the ANSI version of the SQLColumns function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLColumns(
+    statement_handle: HStmt,
+    catalog_name: *const Char,
+    catalog_name_length: SmallInt,
+    _schema_name: *const Char,
+    _schema_name_length: SmallInt,
+    table_name: *const Char,
+    table_name_length: SmallInt,
+    column_name: *const Char,
+    column_name_length: SmallInt,
+) -> SqlReturn {
+    sql_columns_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        column_name,
+        column_name_length,
+        input_text_to_string_a_allow_null,
+    )
 }
 
 ///
@@ -838,80 +1377,131 @@ pub unsafe extern "C" fn SQLColumnsW(
     column_name: *const WideChar,
     column_name_length: SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
+    sql_columns_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        column_name,
+        column_name_length,
+        input_text_to_string_w_allow_null,
+    )
+}
+
+///
+/// [`SQLCompleteAsync`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLCompleteAsync-function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLCompleteAsync(
+    handle_type: HandleType,
+    handle: Handle,
+    async_ret_code_ptr: *mut RetCode,
+) -> SqlReturn {
+    panic_safe_exec_keep_diagnostics!(
         debug,
         || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let odbc_3_data_types = has_odbc_3_behavior!(mongo_handle);
-            let stmt = must_be_valid!((*mongo_handle).as_statement());
-            let catalog_string =
-                input_text_to_string_w_allow_null(catalog_name, catalog_name_length.into());
-            let catalog = if catalog_name.is_null() || catalog_string.is_empty() {
-                None
-            } else {
-                Some(catalog_string.as_str())
-            };
-            // ignore schema
-            let table_string =
-                input_text_to_string_w_allow_null(table_name, table_name_length.into());
-            let table = if table_name.is_null() {
-                None
-            } else {
-                Some(table_string.as_str())
-            };
-            let column_name_string =
-                input_text_to_string_w_allow_null(column_name, column_name_length.into());
-            let column = if column_name.is_null() {
-                None
-            } else {
-                Some(column_name_string.as_str())
-            };
-            let connection = must_be_valid!((*stmt.connection).as_connection());
-            let type_mode = *connection.type_mode.read().unwrap();
-            let max_string_length = *connection.max_string_length.read().unwrap();
-            let mongo_statement = Box::new(MongoFields::list_columns(
-                connection
-                    .mongo_connection
-                    .read()
-                    .unwrap()
-                    .as_ref()
-                    .unwrap(),
-                Some(
-                    stmt.attributes
-                        .read()
-                        .unwrap()
-                        .query_timeout
-                        .try_into()
-                        .unwrap_or(i32::MAX),
-                ),
-                catalog,
-                table,
-                column,
-                type_mode,
-                max_string_length,
-                odbc_3_data_types,
-            ));
-            *stmt.mongo_statement.write().unwrap() = Some(mongo_statement);
+            let mongo_handle = try_mongo_handle!(handle);
+            // Only statement functions can run asynchronously.
+            if !matches!(handle_type, HandleType::SQL_HANDLE_STMT) {
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::InvalidHandleType(HANDLE_MUST_BE_STMT_ERROR)
+                );
+                return SqlReturn::ERROR;
+            }
+            let stmt = must_be_valid!(mongo_handle.as_statement());
+            if stmt.async_operation.read().unwrap().is_none() {
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::FunctionSequenceError("no asynchronous function is executing")
+                );
+                return SqlReturn::ERROR;
+            }
+            let sql_return = complete_async(mongo_handle);
+            ptr_safe_write(async_ret_code_ptr, sql_return.0);
             SqlReturn::SUCCESS
         },
-        statement_handle
+        handle
     );
 }
 
+macro_rules! sql_connect_impl {
+    (
+        $connection_handle:ident,
+        $server_name:ident,
+        $name_length_1:ident,
+        $user_name:ident,
+        $name_length_2:ident,
+        $authentication:ident,
+        $name_length_3:ident,
+        $input_text:path,
+    ) => {{
+        let connection_handle = $connection_handle;
+        let server_name = $server_name;
+        let name_length_1 = $name_length_1;
+        let user_name = $user_name;
+        let name_length_2 = $name_length_2;
+        let authentication = $authentication;
+        let name_length_3 = $name_length_3;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let conn_handle = try_mongo_handle!(connection_handle);
+                trace_odbc!(
+                    info,
+                    conn_handle,
+                    format!("Connecting using {DRIVER_NAME} {} ", *DRIVER_ODBC_VERSION),
+                    function_name!()
+                );
+                let conn = must_be_valid!((*conn_handle).as_connection());
+                let dsn = $input_text(server_name, name_length_1.into());
+                let user = $input_text(user_name, name_length_2.into());
+                let password = $input_text(authentication, name_length_3.into());
+                let odbc_uri = odbc_unwrap!(sql_connect_uri(&dsn, &user, &password), conn_handle);
+                let mongo_connection =
+                    odbc_unwrap!(sql_connect_with_uri(conn, odbc_uri), conn_handle);
+                *conn.mongo_connection.write().unwrap() = Some(mongo_connection);
+                SqlReturn::SUCCESS
+            },
+            connection_handle
+        );
+    }};
+}
+
 ///
-/// [`SQLCompleteAsync`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLCompleteAsync-function
+/// [`SQLConnect`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLConnect-function
+///
+/// This is the ANSI version of the SQLConnect function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
 #[no_mangle]
 #[named]
-pub unsafe extern "C" fn SQLCompleteAsync(
-    _handle_type: HandleType,
-    handle: Handle,
-    _async_ret_code_ptr: *mut RetCode,
+pub unsafe extern "C" fn SQLConnect(
+    connection_handle: HDbc,
+    server_name: *const Char,
+    name_length_1: SmallInt,
+    user_name: *const Char,
+    name_length_2: SmallInt,
+    authentication: *const Char,
+    name_length_3: SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(handle)
+    sql_connect_impl!(
+        connection_handle,
+        server_name,
+        name_length_1,
+        user_name,
+        name_length_2,
+        authentication,
+        name_length_3,
+        input_text_to_string_a_allow_null,
+    )
 }
 
 ///
@@ -926,14 +1516,46 @@ pub unsafe extern "C" fn SQLCompleteAsync(
 #[named]
 pub unsafe extern "C" fn SQLConnectW(
     connection_handle: HDbc,
-    _server_name: *const WideChar,
-    _name_length_1: SmallInt,
-    _user_name: *const WideChar,
-    _name_length_2: SmallInt,
-    _authentication: *const WideChar,
-    _name_length_3: SmallInt,
+    server_name: *const WideChar,
+    name_length_1: SmallInt,
+    user_name: *const WideChar,
+    name_length_2: SmallInt,
+    authentication: *const WideChar,
+    name_length_3: SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(connection_handle)
+    sql_connect_impl!(
+        connection_handle,
+        server_name,
+        name_length_1,
+        user_name,
+        name_length_2,
+        authentication,
+        name_length_3,
+        input_text_to_string_w_allow_null,
+    )
+}
+
+///
+/// sql_connect_uri returns the attributes SQLConnect connects with, read from the DSN in odbc.ini.
+/// The user and password, when supplied, take precedence over those of the DSN.
+///
+fn sql_connect_uri(dsn: &str, user: &str, password: &str) -> Result<ODBCUri> {
+    if dsn.is_empty() {
+        return Err(ODBCError::MissingDriverOrDSNProperty);
+    }
+    if !Dsn::list_data_sources(ODBC_BOTH_DSN)
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(dsn))
+    {
+        return Err(ODBCError::DataSourceNotFound(dsn.to_string()));
+    }
+    let dsn_opts = Dsn {
+        dsn: dsn.to_string(),
+        ..Default::default()
+    }
+    .from_private_profile_string()
+    .map_err(|_| ODBCError::General("failed to read the DSN from odbc.ini"))?;
+    Ok(ODBCUri::from_dsn(&dsn_opts, user, password))
 }
 
 ///
@@ -945,36 +1567,262 @@ pub unsafe extern "C" fn SQLConnectW(
 #[no_mangle]
 #[named]
 pub unsafe extern "C" fn SQLCopyDesc(
-    _source_desc_handle: HDesc,
-    _target_desc_handle: HDesc,
+    source_desc_handle: HDesc,
+    target_desc_handle: HDesc,
 ) -> SqlReturn {
-    unsupported_function!(_source_desc_handle)
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let source_handle = try_mongo_handle!(source_desc_handle);
+            let source = must_be_desc!(source_handle);
+            let target_handle = try_mongo_handle!(target_desc_handle);
+            let target = must_be_desc!(target_handle);
+            match desc::copy_desc(source, target) {
+                Ok(()) => SqlReturn::SUCCESS,
+                Err(error) => {
+                    add_diag_info!(target_handle, error);
+                    SqlReturn::ERROR
+                }
+            }
+        },
+        target_desc_handle
+    )
+}
+
+macro_rules! sql_data_sources_impl {
+    (
+        $environment_handle:ident,
+        $direction:ident,
+        $server_name:ident,
+        $buffer_length_1:ident,
+        $name_length_1:ident,
+        $description:ident,
+        $buffer_length_2:ident,
+        $name_length_2:ident,
+        $set_output_string:path,
+    ) => {{
+        let environment_handle = $environment_handle;
+        let direction = $direction;
+        let server_name = $server_name;
+        let buffer_length_1 = $buffer_length_1;
+        let name_length_1 = $name_length_1;
+        let description = $description;
+        let buffer_length_2 = $buffer_length_2;
+        let name_length_2 = $name_length_2;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let env_handle = try_mongo_handle!(environment_handle);
+                let env = must_be_valid!(env_handle.as_env());
+                // SQL_FETCH_NEXT keeps returning the kind of DSNs the last first fetch asked for.
+                let config_mode = match FromPrimitive::from_u16(direction) {
+                    Some(FetchOrientation::SQL_FETCH_FIRST) => Some(ODBC_BOTH_DSN),
+                    Some(FetchOrientation::SQL_FETCH_FIRST_USER) => Some(ODBC_USER_DSN),
+                    Some(FetchOrientation::SQL_FETCH_FIRST_SYSTEM) => Some(ODBC_SYSTEM_DSN),
+                    _ => None,
+                };
+                let direction = match config_mode {
+                    Some(config_mode) => {
+                        *env.data_source_mode.write().unwrap() = config_mode;
+                        FetchOrientation::SQL_FETCH_FIRST as USmallInt
+                    }
+                    None => direction,
+                };
+                let config_mode = *env.data_source_mode.read().unwrap();
+                let mut data_sources = Dsn::list_data_sources(config_mode);
+                let index = odbc_unwrap!(
+                    sql_enumeration_next(&env.next_data_source, direction, data_sources.len()),
+                    env_handle
+                );
+                match index {
+                    None => SqlReturn::NO_DATA,
+                    Some(index) => {
+                        let (dsn, driver) = data_sources.swap_remove(index);
+                        sql_enumeration_output(
+                            env_handle,
+                            function_name!(),
+                            $set_output_string,
+                            [
+                                (dsn, server_name, buffer_length_1, name_length_1),
+                                (driver, description, buffer_length_2, name_length_2),
+                            ],
+                        )
+                    }
+                }
+            },
+            environment_handle
+        );
+    }};
+}
+
+///
+/// [`SQLDataSources`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDataSources-function
+///
+/// This is the ANSI version of the SQLDataSources function. The driver manager normally
+/// implements it, so the driver only serves applications that link the driver directly.
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLDataSources(
+    environment_handle: HEnv,
+    direction: USmallInt,
+    server_name: *mut Char,
+    buffer_length_1: SmallInt,
+    name_length_1: *mut SmallInt,
+    description: *mut Char,
+    buffer_length_2: SmallInt,
+    name_length_2: *mut SmallInt,
+) -> SqlReturn {
+    sql_data_sources_impl!(
+        environment_handle,
+        direction,
+        server_name,
+        buffer_length_1,
+        name_length_1,
+        description,
+        buffer_length_2,
+        name_length_2,
+        i16_len::set_output_string,
+    )
 }
 
 ///
 /// [`SQLDataSourcesW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDataSources-function
 ///
-/// This function is implemented only by the Driver Manager.
+/// This is the WideChar version of the SQLDataSources function. The driver manager normally
+/// implements it, so the driver only serves applications that link the driver directly.
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
-/**
 #[no_mangle]
 #[named]
 pub unsafe extern "C" fn SQLDataSourcesW(
     environment_handle: HEnv,
-    _direction: USmallInt,
-    _server_name: *mut WideChar,
-    _buffer_length_1: SmallInt,
-    _name_length_1: *mut SmallInt,
-    _description: *mut WideChar,
-    _buffer_length_2: SmallInt,
-    _name_length_2: *mut SmallInt,
+    direction: USmallInt,
+    server_name: *mut WideChar,
+    buffer_length_1: SmallInt,
+    name_length_1: *mut SmallInt,
+    description: *mut WideChar,
+    buffer_length_2: SmallInt,
+    name_length_2: *mut SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(environment_handle)
+    sql_data_sources_impl!(
+        environment_handle,
+        direction,
+        server_name,
+        buffer_length_1,
+        name_length_1,
+        description,
+        buffer_length_2,
+        name_length_2,
+        i16_len::set_output_wstring,
+    )
 }
-*/
+
+macro_rules! sql_describe_col_impl {
+    (
+        $hstmt:ident,
+        $col_number:ident,
+        $col_name:ident,
+        $buffer_length:ident,
+        $name_length:ident,
+        $data_type:ident,
+        $col_size:ident,
+        $decimal_digits:ident,
+        $nullable:ident,
+        $set_output_string:path,
+    ) => {{
+        let hstmt = $hstmt;
+        let col_number = $col_number;
+        let col_name = $col_name;
+        let buffer_length = $buffer_length;
+        let name_length = $name_length;
+        let data_type = $data_type;
+        let col_size = $col_size;
+        let decimal_digits = $decimal_digits;
+        let nullable = $nullable;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let stmt_handle = try_mongo_handle!(hstmt);
+                let odbc_version = stmt_handle.get_odbc_version();
+                {
+                    let stmt = must_be_valid!(stmt_handle.as_statement());
+                    let mongo_stmt = stmt.mongo_statement.read().unwrap();
+                    if mongo_stmt.is_none() {
+                        stmt.errors.write().unwrap().push(ODBCError::NoResultSet);
+                        return SqlReturn::ERROR;
+                    }
+                    let max_string_length = stmt.get_max_string_length();
+                    let col_metadata = mongo_stmt
+                        .as_ref()
+                        .unwrap()
+                        .get_col_metadata(col_number, max_string_length);
+                    if let Ok(col_metadata) = col_metadata {
+                        *data_type = handle_sql_type(odbc_version, col_metadata.sql_type);
+                        *col_size = col_metadata.column_size.unwrap_or(0) as usize;
+                        *decimal_digits = col_metadata
+                            .decimal_digits
+                            .unwrap_or(0)
+                            .try_into()
+                            .unwrap_or(i16::MAX);
+                        *nullable = col_metadata.nullability as i16;
+                        return $set_output_string(
+                            &col_metadata.label,
+                            col_name,
+                            buffer_length as usize,
+                            name_length,
+                        );
+                    }
+                }
+                add_diag_info!(stmt_handle, ODBCError::InvalidColumnNumber(col_number));
+
+                SqlReturn::ERROR
+            },
+            hstmt
+        );
+    }};
+}
+
+///
+/// [`SQLDescribeCol`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDescribeCol-function
+///
+/// This is the ANSI version of the SQLDescribeCol function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLDescribeCol(
+    hstmt: HStmt,
+    col_number: USmallInt,
+    col_name: *mut Char,
+    buffer_length: SmallInt,
+    name_length: *mut SmallInt,
+    data_type: *mut SqlDataType,
+    col_size: *mut ULen,
+    decimal_digits: *mut SmallInt,
+    nullable: *mut SmallInt,
+) -> SqlReturn {
+    sql_describe_col_impl!(
+        hstmt,
+        col_number,
+        col_name,
+        buffer_length,
+        name_length,
+        data_type,
+        col_size,
+        decimal_digits,
+        nullable,
+        i16_len::set_output_string,
+    )
+}
+
 ///
 /// [`SQLDescribeColW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDescribeCol-function
 ///
@@ -996,46 +1844,18 @@ pub unsafe extern "C" fn SQLDescribeColW(
     decimal_digits: *mut SmallInt,
     nullable: *mut SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let stmt_handle = try_mongo_handle!(hstmt);
-            let odbc_version = stmt_handle.get_odbc_version();
-            {
-                let stmt = must_be_valid!(stmt_handle.as_statement());
-                let mongo_stmt = stmt.mongo_statement.read().unwrap();
-                if mongo_stmt.is_none() {
-                    stmt.errors.write().unwrap().push(ODBCError::NoResultSet);
-                    return SqlReturn::ERROR;
-                }
-                let max_string_length = stmt.get_max_string_length();
-                let col_metadata = mongo_stmt
-                    .as_ref()
-                    .unwrap()
-                    .get_col_metadata(col_number, max_string_length);
-                if let Ok(col_metadata) = col_metadata {
-                    *data_type = handle_sql_type(odbc_version, col_metadata.sql_type);
-                    *col_size = col_metadata.column_size.unwrap_or(0) as usize;
-                    *decimal_digits = col_metadata
-                        .decimal_digits
-                        .unwrap_or(0)
-                        .try_into()
-                        .unwrap_or(i16::MAX);
-                    *nullable = col_metadata.nullability as i16;
-                    return i16_len::set_output_wstring(
-                        &col_metadata.label,
-                        col_name,
-                        buffer_length as usize,
-                        name_length,
-                    );
-                }
-            }
-            add_diag_info!(stmt_handle, ODBCError::InvalidColumnNumber(col_number));
-
-            SqlReturn::ERROR
-        },
-        hstmt
-    );
+    sql_describe_col_impl!(
+        hstmt,
+        col_number,
+        col_name,
+        buffer_length,
+        name_length,
+        data_type,
+        col_size,
+        decimal_digits,
+        nullable,
+        i16_len::set_output_wstring,
+    )
 }
 
 ///
@@ -1048,13 +1868,58 @@ pub unsafe extern "C" fn SQLDescribeColW(
 #[named]
 pub unsafe extern "C" fn SQLDescribeParam(
     statement_handle: HStmt,
-    _parameter_number: USmallInt,
-    _data_type_ptr: *mut SqlDataType,
-    _parameter_size_ptr: *mut ULen,
-    _decimal_digits_ptr: *mut SmallInt,
-    _nullable_ptr: *mut SmallInt,
+    parameter_number: USmallInt,
+    data_type_ptr: *mut SqlDataType,
+    parameter_size_ptr: *mut ULen,
+    decimal_digits_ptr: *mut SmallInt,
+    nullable_ptr: *mut SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(statement_handle)
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(statement_handle);
+            let stmt = must_be_valid!((*mongo_handle).as_statement());
+
+            let param_count = stmt
+                .parameterized_query
+                .read()
+                .unwrap()
+                .as_deref()
+                .map_or(0, count_parameter_markers);
+            if parameter_number == 0 || usize::from(parameter_number) > param_count {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::InvalidDescriptorIndex(parameter_number)
+                );
+                return SqlReturn::ERROR;
+            }
+
+            // The server does not infer parameter types, so we describe a parameter the way the
+            // application bound it. Unbound parameters are described as strings.
+            let (data_type, parameter_size, decimal_digits) = match stmt
+                .bound_params
+                .read()
+                .unwrap()
+                .as_ref()
+                .and_then(|params| params.get(&parameter_number))
+            {
+                Some(param) => (
+                    FromPrimitive::from_i16(param.parameter_type)
+                        .unwrap_or(SqlDataType::SQL_UNKNOWN_TYPE),
+                    param.column_size,
+                    param.decimal_digits,
+                ),
+                None => (SqlDataType::SQL_WVARCHAR, 0, 0),
+            };
+            ptr_safe_write(data_type_ptr, data_type);
+            ptr_safe_write(parameter_size_ptr, parameter_size);
+            ptr_safe_write(decimal_digits_ptr, decimal_digits);
+            ptr_safe_write(nullable_ptr, Nullability::SQL_NULLABLE as SmallInt);
+            SqlReturn::SUCCESS
+        },
+        statement_handle
+    );
 }
 
 ///
@@ -1077,6 +1942,8 @@ pub unsafe extern "C" fn SQLDisconnect(connection_handle: HDbc) -> SqlReturn {
             // Close any open cursors on statements and drop all statements
             if let Ok(mut stmts) = conn.statements.write() {
                 stmts.iter().for_each(|stmt| {
+                    // Wait for any asynchronous function, which uses the statement, to finish.
+                    let _ = complete_async(&mut **stmt);
                     if let Some(stmt) = (*stmt).as_ref() {
                         if let Some(stmt) = stmt.as_statement() {
                             sql_stmt_close_cursor_helper(stmt);
@@ -1101,7 +1968,10 @@ pub unsafe extern "C" fn SQLDisconnect(connection_handle: HDbc) -> SqlReturn {
 }
 
 fn sql_driver_connect(conn: &Connection, odbc_uri_string: &str) -> Result<MongoConnection> {
-    let mut odbc_uri = ODBCUri::new(odbc_uri_string.to_string())?;
+    sql_connect_with_uri(conn, ODBCUri::new(odbc_uri_string.to_string())?)
+}
+
+fn sql_connect_with_uri(conn: &Connection, mut odbc_uri: ODBCUri) -> Result<MongoConnection> {
     let runtime = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -1129,6 +1999,16 @@ fn sql_driver_connect(conn: &Connection, odbc_uri_string: &str) -> Result<MongoC
         }
     }
 
+    if let Some(threshold) = odbc_uri.remove(&["static_cursor_spill_threshold"]) {
+        *conn.static_cursor_spill_threshold.write().unwrap() = threshold
+            .parse()
+            .map_err(|_| ODBCError::InvalidAttrValue("static_cursor_spill_threshold"))?;
+    }
+
+    if let Some(accurate_row_count) = odbc_uri.remove(&["accurate_row_count"]) {
+        *conn.accurate_row_count.write().unwrap() = accurate_row_count.eq("1");
+    }
+
     let mut conn_attrs = conn.attributes.write().unwrap();
     let database = if conn_attrs.current_catalog.is_some() {
         conn_attrs.current_catalog.as_deref().map(|s| s.to_string())
@@ -1153,6 +2033,120 @@ fn sql_driver_connect(conn: &Connection, odbc_uri_string: &str) -> Result<MongoC
     )?)
 }
 
+macro_rules! sql_driver_connect_impl {
+    (
+        $connection_handle:ident,
+        $in_connection_string:ident,
+        $string_length_1:ident,
+        $out_connection_string:ident,
+        $buffer_length:ident,
+        $string_length_2:ident,
+        $driver_completion:ident,
+        $input_text:path,
+        $set_output_string:path,
+    ) => {{
+        let connection_handle = $connection_handle;
+        let in_connection_string = $in_connection_string;
+        let string_length_1 = $string_length_1;
+        let out_connection_string = $out_connection_string;
+        let buffer_length = $buffer_length;
+        let string_length_2 = $string_length_2;
+        let driver_completion = $driver_completion;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let conn_handle = try_mongo_handle!(connection_handle);
+                trace_odbc!(
+                    info,
+                    conn_handle,
+                    format!("Connecting using {DRIVER_NAME} {} ", *DRIVER_ODBC_VERSION),
+                    function_name!()
+                );
+
+                // We will treat any valid option passed for DriverComplete as a no-op
+                // because we don't have any UI involved in the process and don't plan to add any in the future
+                match <DriverConnectOption as FromPrimitive>::from_u16(driver_completion) {
+                    Some(_) => {}
+                    None => {
+                        add_diag_info!(
+                            conn_handle,
+                            ODBCError::InvalidDriverCompletion(driver_completion)
+                        );
+                        return SqlReturn::ERROR;
+                    }
+                }
+
+                let conn = must_be_valid!((*conn_handle).as_connection());
+                let odbc_uri_string =
+                    $input_text(in_connection_string, string_length_1.into());
+                let mongo_connection =
+                    odbc_unwrap!(sql_driver_connect(conn, &odbc_uri_string), conn_handle);
+                *conn.mongo_connection.write().unwrap() = Some(mongo_connection);
+                // We know the mysql ODBC driver returns SUCCESS if the out_connection_string is NULL.
+                // We can also just return SUCCESS if the buffer_len is 0. Likely, users are not
+                // expecting to get back a warning when they pass an empty buffer to this, especially
+                // given that we only currently support DriverConnectOption::SQL_DRIVER_NO_PROMPT.
+                // given that we only currently support DriverConnectOption::NoPrompt.
+                if buffer_length <= 0 || out_connection_string.is_null() {
+                    // Only assign to string_length_2 if it is not null
+                    if !string_length_2.is_null() {
+                        *string_length_2 = odbc_uri_string
+                            .len()
+                            .try_into()
+                            .expect("odbc_uri_string.len exceeds i16");
+                    }
+                    return SqlReturn::SUCCESS;
+                }
+                let buffer_len = usize::try_from(buffer_length).unwrap();
+                let sql_return = $set_output_string(
+                    &odbc_uri_string,
+                    out_connection_string,
+                    buffer_len,
+                    string_length_2,
+                );
+                if sql_return == SqlReturn::SUCCESS_WITH_INFO {
+                    add_diag_info!(conn_handle, ODBCError::OutStringTruncated(buffer_len));
+                }
+                sql_return
+            },
+            connection_handle
+        );
+    }};
+}
+
+///
+/// [`SQLDriverConnect`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDriverConnect-function
+///
+/// This is the ANSI version of the SQLDriverConnect function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLDriverConnect(
+    connection_handle: HDbc,
+    _window_handle: HWnd,
+    in_connection_string: *const Char,
+    string_length_1: SmallInt,
+    out_connection_string: *mut Char,
+    buffer_length: SmallInt,
+    string_length_2: *mut SmallInt,
+    driver_completion: USmallInt,
+) -> SqlReturn {
+    sql_driver_connect_impl!(
+        connection_handle,
+        in_connection_string,
+        string_length_1,
+        out_connection_string,
+        buffer_length,
+        string_length_2,
+        driver_completion,
+        input_text_to_string_a,
+        i16_len::set_output_string,
+    )
+}
+
 ///
 /// [`SQLDriverConnectW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDriverConnect-function
 ///
@@ -1173,91 +2167,195 @@ pub unsafe extern "C" fn SQLDriverConnectW(
     string_length_2: *mut SmallInt,
     driver_completion: USmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let conn_handle = try_mongo_handle!(connection_handle);
-            trace_odbc!(
-                info,
-                conn_handle,
-                format!("Connecting using {DRIVER_NAME} {} ", *DRIVER_ODBC_VERSION),
-                function_name!()
-            );
+    sql_driver_connect_impl!(
+        connection_handle,
+        in_connection_string,
+        string_length_1,
+        out_connection_string,
+        buffer_length,
+        string_length_2,
+        driver_completion,
+        input_text_to_string_w,
+        i16_len::set_output_wstring,
+    )
+}
 
-            // We will treat any valid option passed for DriverComplete as a no-op
-            // because we don't have any UI involved in the process and don't plan to add any in the future
-            match <DriverConnectOption as FromPrimitive>::from_u16(driver_completion) {
-                Some(_) => {}
-                None => {
-                    add_diag_info!(
-                        conn_handle,
-                        ODBCError::InvalidDriverCompletion(driver_completion)
-                    );
-                    return SqlReturn::ERROR;
+macro_rules! sql_drivers_impl {
+    (
+        $henv:ident,
+        $direction:ident,
+        $driver_desc:ident,
+        $driver_desc_max:ident,
+        $out_driver_desc:ident,
+        $driver_attributes:ident,
+        $drvr_attr_max:ident,
+        $out_drvr_attr:ident,
+        $set_output_string:path,
+    ) => {{
+        let henv = $henv;
+        let direction = $direction;
+        let driver_desc = $driver_desc;
+        let driver_desc_max = $driver_desc_max;
+        let out_driver_desc = $out_driver_desc;
+        let driver_attributes = $driver_attributes;
+        let drvr_attr_max = $drvr_attr_max;
+        let out_drvr_attr = $out_drvr_attr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let env_handle = try_mongo_handle!(henv);
+                let env = must_be_valid!(env_handle.as_env());
+                let mut drivers = DriverSettings::list_drivers();
+                let index = odbc_unwrap!(
+                    sql_enumeration_next(&env.next_driver, direction, drivers.len()),
+                    env_handle
+                );
+                match index {
+                    None => SqlReturn::NO_DATA,
+                    Some(index) => {
+                        let (driver, attributes) = drivers.swap_remove(index);
+                        // The attributes are separated by null characters, and the list ends with
+                        // two of them once the output null terminator is written.
+                        let attributes = attributes
+                            .iter()
+                            .map(|attribute| format!("{attribute}\0"))
+                            .collect::<String>();
+                        sql_enumeration_output(
+                            env_handle,
+                            function_name!(),
+                            $set_output_string,
+                            [
+                                (driver, driver_desc, driver_desc_max, out_driver_desc),
+                                (attributes, driver_attributes, drvr_attr_max, out_drvr_attr),
+                            ],
+                        )
+                    }
                 }
-            }
+            },
+            henv
+        );
+    }};
+}
 
-            let conn = must_be_valid!((*conn_handle).as_connection());
-            let odbc_uri_string =
-                input_text_to_string_w(in_connection_string, string_length_1.into());
-            let mongo_connection =
-                odbc_unwrap!(sql_driver_connect(conn, &odbc_uri_string), conn_handle);
-            *conn.mongo_connection.write().unwrap() = Some(mongo_connection);
-            // We know the mysql ODBC driver returns SUCCESS if the out_connection_string is NULL.
-            // We can also just return SUCCESS if the buffer_len is 0. Likely, users are not
-            // expecting to get back a warning when they pass an empty buffer to this, especially
-            // given that we only currently support DriverConnectOption::SQL_DRIVER_NO_PROMPT.
-            // given that we only currently support DriverConnectOption::NoPrompt.
-            if buffer_length <= 0 || out_connection_string.is_null() {
-                // Only assign to string_length_2 if it is not null
-                if !string_length_2.is_null() {
-                    *string_length_2 = odbc_uri_string
-                        .len()
-                        .try_into()
-                        .expect("odbc_uri_string.len exceeds i16");
-                }
-                return SqlReturn::SUCCESS;
-            }
-            let buffer_len = usize::try_from(buffer_length).unwrap();
-            let sql_return = i16_len::set_output_wstring(
-                &odbc_uri_string,
-                out_connection_string,
-                buffer_len,
-                string_length_2,
-            );
-            if sql_return == SqlReturn::SUCCESS_WITH_INFO {
-                add_diag_info!(conn_handle, ODBCError::OutStringTruncated(buffer_len));
-            }
-            sql_return
-        },
-        connection_handle
-    );
+///
+/// [`SQLDrivers`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDrivers-function
+///
+/// This is the ANSI version of the SQLDrivers function. The driver manager normally
+/// implements it, so the driver only serves applications that link the driver directly.
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLDrivers(
+    henv: HEnv,
+    direction: USmallInt,
+    driver_desc: *mut Char,
+    driver_desc_max: SmallInt,
+    out_driver_desc: *mut SmallInt,
+    driver_attributes: *mut Char,
+    drvr_attr_max: SmallInt,
+    out_drvr_attr: *mut SmallInt,
+) -> SqlReturn {
+    sql_drivers_impl!(
+        henv,
+        direction,
+        driver_desc,
+        driver_desc_max,
+        out_driver_desc,
+        driver_attributes,
+        drvr_attr_max,
+        out_drvr_attr,
+        i16_len::set_output_string,
+    )
 }
 
 ///
 /// [`SQLDriversW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLDrivers-function
 ///
-/// This function is implemented only by the Driver Manager.
+/// This is the WideChar version of the SQLDrivers function. The driver manager normally
+/// implements it, so the driver only serves applications that link the driver directly.
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
-/**
 #[no_mangle]
 #[named]
 pub unsafe extern "C" fn SQLDriversW(
     henv: HEnv,
-    _direction: USmallInt,
-    _driver_desc: *mut WideChar,
-    _driver_desc_max: SmallInt,
-    _out_driver_desc: *mut SmallInt,
-    _driver_attributes: *mut WideChar,
-    _drvr_attr_max: SmallInt,
-    _out_drvr_attr: *mut SmallInt,
+    direction: USmallInt,
+    driver_desc: *mut WideChar,
+    driver_desc_max: SmallInt,
+    out_driver_desc: *mut SmallInt,
+    driver_attributes: *mut WideChar,
+    drvr_attr_max: SmallInt,
+    out_drvr_attr: *mut SmallInt,
+) -> SqlReturn {
+    sql_drivers_impl!(
+        henv,
+        direction,
+        driver_desc,
+        driver_desc_max,
+        out_driver_desc,
+        driver_attributes,
+        drvr_attr_max,
+        out_drvr_attr,
+        i16_len::set_output_wstring,
+    )
+}
+
+///
+/// sql_enumeration_next moves the position of an SQLDataSources or SQLDrivers enumeration of
+/// `len` entries in the given direction, and returns the index of the entry to return. It returns
+/// None once all entries were returned, and the next SQL_FETCH_NEXT starts over.
+///
+fn sql_enumeration_next(
+    next: &RwLock<usize>,
+    direction: USmallInt,
+    len: usize,
+) -> Result<Option<usize>> {
+    let mut next = next.write().unwrap();
+    match FromPrimitive::from_u16(direction) {
+        Some(FetchOrientation::SQL_FETCH_FIRST) => *next = 0,
+        Some(FetchOrientation::SQL_FETCH_NEXT) => {}
+        _ => return Err(ODBCError::InvalidRetrievalCode(direction)),
+    }
+    if *next >= len {
+        *next = 0;
+        return Ok(None);
+    }
+    *next += 1;
+    Ok(Some(*next - 1))
+}
+
+///
+/// sql_enumeration_output writes each value of an SQLDataSources or SQLDrivers entry to its
+/// output buffer, and warns if any of them was truncated.
+///
+unsafe fn sql_enumeration_output<C, const N: usize>(
+    env_handle: &mut MongoHandle,
+    function_name: &str,
+    set_output_string: unsafe fn(&str, *mut C, usize, *mut SmallInt) -> SqlReturn,
+    outputs: [(String, *mut C, SmallInt, *mut SmallInt); N],
 ) -> SqlReturn {
-    unsupported_function!(henv)
+    let mut sql_return = SqlReturn::SUCCESS;
+    for (value, output_ptr, buffer_length, length_ptr) in outputs {
+        let buffer_len = usize::try_from(buffer_length).unwrap_or(0);
+        if set_output_string(&value, output_ptr, buffer_len, length_ptr)
+            == SqlReturn::SUCCESS_WITH_INFO
+        {
+            add_diag_with_function!(
+                env_handle,
+                ODBCError::OutStringTruncated(buffer_len),
+                function_name.to_string()
+            );
+            sql_return = SqlReturn::SUCCESS_WITH_INFO;
+        }
+    }
+    sql_return
 }
-**/
+
 ///
 /// [`SQLEndTran`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLEndTran-function
 ///
@@ -1274,6 +2372,102 @@ pub unsafe extern "C" fn SQLEndTran(
     unimpl!(handle);
 }
 
+macro_rules! sql_exec_direct_impl {
+    (
+        $statement_handle:ident,
+        $statement_text:ident,
+        $text_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let statement_text = $statement_text;
+        let text_length = $text_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let query = sql_statement_text(statement_text, text_length, $input_text);
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let stmt = must_be_valid!(mongo_handle.as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    if stmt.data_at_exec.read().unwrap().is_some() {
+                        let mongo_handle = try_mongo_handle!(statement_handle);
+                        add_diag_info!(
+                            mongo_handle,
+                            ODBCError::FunctionSequenceError("the statement needs data")
+                        );
+                        return SqlReturn::ERROR;
+                    }
+                    // The query is executed by SQLParamData once all data-at-execution values are sent.
+                    if sql_needs_data(stmt, &query) {
+                        return SqlReturn::NEED_DATA;
+                    }
+                    stmt.more_results.write().unwrap().clear();
+                    *stmt.prepared_query.write().unwrap() = None;
+                    let is_batch = split_statements(&query).len() > 1;
+                    if is_batch && sql_paramset_size(stmt, &query) > 1 {
+                        let mongo_handle = try_mongo_handle!(statement_handle);
+                        add_diag_info!(
+                            mongo_handle,
+                            ODBCError::Unimplemented("`parameter arrays in batches`")
+                        );
+                        return SqlReturn::ERROR;
+                    }
+                    if sql_paramset_size(stmt, &query) > 1 {
+                        return sql_execute_param_array(stmt, connection, &query);
+                    }
+                    let query = odbc_unwrap!(sql_bind_parameters(stmt, &query), mongo_handle);
+                    if is_batch {
+                        return sql_execute_batch(stmt, connection, &query);
+                    }
+                    let mongo_statement =
+                        odbc_unwrap!(sql_prepare(stmt, &query, connection), mongo_handle);
+
+                    *stmt.mongo_statement.write().unwrap() = Some(mongo_statement);
+
+                    // set the statment state to executing so SQLCancel knows to search the op log for hanging queries
+                    *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
+
+                    odbc_unwrap!(sql_execute(stmt, connection), mongo_handle);
+
+                    // return the statement state to its original value
+                    *stmt.state.write().unwrap() = StatementState::Allocated;
+
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLExecDirect`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLExecDirect-function
+///
+/// This is the ANSI version of the SQLExecDirect function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLExecDirect(
+    statement_handle: HStmt,
+    statement_text: *const Char,
+    text_length: Integer,
+) -> SqlReturn {
+    sql_exec_direct_impl!(
+        statement_handle,
+        statement_text,
+        text_length,
+        input_text_to_string_a,
+    )
+}
+
 ///
 /// [`SQLExecDirectW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLExecDirect-function
 ///
@@ -1289,31 +2483,12 @@ pub unsafe extern "C" fn SQLExecDirectW(
     statement_text: *const WideChar,
     text_length: Integer,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let stmt = must_be_valid!(mongo_handle.as_statement());
-            let connection = must_be_valid!((*stmt.connection).as_connection());
-            let mongo_statement = odbc_unwrap!(
-                sql_prepare(statement_text, text_length, connection,),
-                mongo_handle
-            );
-
-            *stmt.mongo_statement.write().unwrap() = Some(Box::new(mongo_statement));
-
-            // set the statment state to executing so SQLCancel knows to search the op log for hanging queries
-            *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
-
-            odbc_unwrap!(sql_execute(stmt, connection), mongo_handle);
-
-            // return the statement state to its original value
-            *stmt.state.write().unwrap() = StatementState::Allocated;
-
-            SqlReturn::SUCCESS
-        },
-        statement_handle
-    );
+    sql_exec_direct_impl!(
+        statement_handle,
+        statement_text,
+        text_length,
+        input_text_to_string_w,
+    )
 }
 
 ///
@@ -1329,39 +2504,92 @@ pub unsafe extern "C" fn SQLExecute(statement_handle: HStmt) -> SqlReturn {
         debug,
         || {
             let mongo_handle = try_mongo_handle!(statement_handle);
-            let stmt = must_be_valid!(mongo_handle.as_statement());
-            let connection = must_be_valid!((*stmt.connection).as_connection());
-            // set the statment state to executing so SQLCancel knows to search the op log for hanging queries
-            *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
-            odbc_unwrap!(sql_execute(stmt, connection), mongo_handle);
-            // return the statement state to its original value
-            *stmt.state.write().unwrap() = StatementState::Allocated;
-            SqlReturn::SUCCESS
+            if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                return sql_return;
+            }
+            exec_async(mongo_handle, function_name!(), |statement_handle| {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                let stmt = must_be_valid!(mongo_handle.as_statement());
+                let connection = must_be_valid!((*stmt.connection).as_connection());
+                // A query with parameter markers is bound to the currently bound values, so
+                // applications can rebind and execute without calling SQLPrepare again.
+                if stmt.data_at_exec.read().unwrap().is_some() {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::FunctionSequenceError("the statement needs data")
+                    );
+                    return SqlReturn::ERROR;
+                }
+                stmt.more_results.write().unwrap().clear();
+                let parameterized_query = stmt.parameterized_query.read().unwrap().clone();
+                if let Some(query) = parameterized_query {
+                    // The query is executed by SQLParamData once all data-at-execution values are sent.
+                    if sql_needs_data(stmt, &query) {
+                        return SqlReturn::NEED_DATA;
+                    }
+                    if sql_paramset_size(stmt, &query) > 1 {
+                        return sql_execute_param_array(stmt, connection, &query);
+                    }
+                    let query = odbc_unwrap!(sql_bind_parameters(stmt, &query), mongo_handle);
+                    let mongo_query = odbc_unwrap!(
+                        sql_bind_prepared_query(stmt, &query, connection),
+                        mongo_handle
+                    );
+                    *stmt.mongo_statement.write().unwrap() =
+                        Some(sql_cursor(stmt, connection, mongo_query));
+                }
+                // set the statment state to executing so SQLCancel knows to search the op log for hanging queries
+                *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
+                odbc_unwrap!(sql_execute(stmt, connection), mongo_handle);
+                // return the statement state to its original value
+                *stmt.state.write().unwrap() = StatementState::Allocated;
+                SqlReturn::SUCCESS
+            })
         },
         statement_handle
     );
 }
 
 unsafe fn sql_execute(stmt: &Statement, connection: &Connection) -> Result<bool> {
+    sql_execute_statement(
+        stmt,
+        connection,
+        stmt.mongo_statement
+            .write()
+            .unwrap()
+            .as_mut()
+            .unwrap()
+            .as_mut(),
+    )
+}
+
+unsafe fn sql_execute_statement(
+    stmt: &Statement,
+    connection: &Connection,
+    mongo_statement: &mut dyn MongoStatement,
+) -> Result<bool> {
     let stmt_id = stmt.statement_id.read().unwrap().clone();
+    stmt.cancellation.reset();
+    mongo_statement.set_cancellation(stmt.cancellation.clone());
     let mongo_statement = {
         if let Some(mongo_connection) = connection.mongo_connection.read().unwrap().as_ref() {
-            let rowset_size = match u32::try_from(stmt.attributes.read().unwrap().row_array_size) {
+            let rowset_size = match u32::try_from(stmt.app_row_desc().array_size) {
                 Ok(size) => size,
                 Err(_) => unreachable!("Err should be impossible since SQLSetStmtAttrW sets row_array_size to u32::MAX if it's outside of the u32 range"),
             };
+            let max_rows = stmt.attributes.read().unwrap().max_rows as u64;
 
-            stmt.mongo_statement
-                .write()
-                .unwrap()
-                .as_mut()
-                .unwrap()
-                .execute(mongo_connection, stmt_id, rowset_size)
+            mongo_statement
+                .execute(mongo_connection, stmt_id, rowset_size, max_rows)
                 .map_err(|e| e.into())
         } else {
             Err(ODBCError::InvalidCursorState)
         }
     };
+    if mongo_statement.is_ok() {
+        *stmt.cursor_open.write().unwrap() = true;
+    }
 
     mongo_statement
 }
@@ -1377,11 +2605,104 @@ unsafe fn sql_execute(stmt: &Statement, connection: &Connection) -> Result<bool>
 pub unsafe extern "C" fn SQLFetch(statement_handle: HStmt) -> SqlReturn {
     panic_safe_exec_clear_diagnostics!(
         debug,
-        || { sql_fetch_helper(statement_handle, "SQLFetch") },
+        || {
+            let mongo_handle = try_mongo_handle!(statement_handle);
+            if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                return sql_return;
+            }
+            exec_async(mongo_handle, function_name!(), |statement_handle| {
+                sql_fetch_scroll_helper(
+                    statement_handle,
+                    FetchOrientation::SQL_FETCH_NEXT,
+                    0,
+                    "SQLFetch",
+                )
+            })
+        },
         statement_handle
     );
 }
 
+/// This is a helper function for SQLFetch and SQLFetchScroll that fetches the rowset selected by
+/// `fetch_orientation` and `fetch_offset`. The statement is executing during the fetch, so
+/// SQLCancel can interrupt it.
+unsafe fn sql_fetch_scroll_helper(
+    statement_handle: HStmt,
+    fetch_orientation: FetchOrientation,
+    fetch_offset: Len,
+    function_name: &str,
+) -> SqlReturn {
+    let mongo_handle = try_mongo_handle!(statement_handle);
+    let stmt = must_be_valid!(mongo_handle.as_statement());
+    // set the statement state to executing so SQLCancel can interrupt the fetch
+    stmt.cancellation.reset();
+    *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
+    let sql_return = sql_fetch_rowset(
+        statement_handle,
+        fetch_orientation,
+        fetch_offset,
+        function_name,
+    );
+    // Catalog functions open their cursor without executing a query, so fetching opens it too.
+    if stmt.mongo_statement.read().unwrap().is_some() {
+        *stmt.cursor_open.write().unwrap() = true;
+    }
+    *stmt.state.write().unwrap() = StatementState::Allocated;
+    sql_return
+}
+
+/// sql_fetch_rowset moves the cursor to the rowset selected by `fetch_orientation` and
+/// `fetch_offset`, then fetches that rowset. Only static cursors can move to a rowset other than
+/// the next one.
+unsafe fn sql_fetch_rowset(
+    statement_handle: HStmt,
+    fetch_orientation: FetchOrientation,
+    fetch_offset: Len,
+    function_name: &str,
+) -> SqlReturn {
+    let mongo_handle = try_mongo_handle!(statement_handle);
+    let stmt = must_be_valid!(mongo_handle.as_statement());
+    let connection = must_be_valid!((*stmt.connection).as_connection());
+    let rowset_size = stmt.app_row_desc().array_size;
+
+    let scroll_result: Result<ScrollResult> = match stmt.mongo_statement.write().unwrap().as_mut() {
+        Some(mongo_stmt) => mongo_stmt
+            .scroll(
+                connection.mongo_connection.read().unwrap().as_ref(),
+                fetch_orientation,
+                fetch_offset,
+                rowset_size,
+            )
+            .map_err(|e| e.into()),
+        None => Err(ODBCError::InvalidCursorState),
+    };
+
+    match scroll_result {
+        Ok(ScrollResult::Rowset) => sql_fetch_helper(statement_handle, function_name),
+        Ok(ScrollResult::RowsetStartsAtFirstRow) => {
+            let sql_return = sql_fetch_helper(statement_handle, function_name);
+            if sql_return == SqlReturn::SUCCESS || sql_return == SqlReturn::SUCCESS_WITH_INFO {
+                add_diag_with_function!(
+                    mongo_handle,
+                    ODBCError::FetchBeforeStart,
+                    function_name.to_string()
+                );
+                SqlReturn::SUCCESS_WITH_INFO
+            } else {
+                sql_return
+            }
+        }
+        Ok(ScrollResult::NoData) => {
+            ptr_safe_write(stmt.attributes.read().unwrap().rows_fetched_ptr, 0);
+            SqlReturn::NO_DATA
+        }
+        Err(e) => {
+            add_diag_with_function!(mongo_handle, e, function_name.to_string());
+            SqlReturn::ERROR
+        }
+    }
+}
+
 unsafe fn sql_fetch_helper(statement_handle: HStmt, function_name: &str) -> SqlReturn {
     let mongo_handle = try_mongo_handle!(statement_handle);
     let stmt = must_be_valid!(mongo_handle.as_statement());
@@ -1392,7 +2713,9 @@ unsafe fn sql_fetch_helper(statement_handle: HStmt, function_name: &str) -> SqlR
     // needed for rowsets with size > 1 to ensure that NO_DATA does not get returned if the rowset hits the end of the result set.
     let mut has_fetched_at_least_one_row = false;
 
-    let rowset_size = stmt.attributes.read().unwrap().row_array_size;
+    // The application row descriptor cannot change during the fetch, so it is read once.
+    let ard = stmt.app_row_desc();
+    let rowset_size = ard.array_size;
 
     // if rows_fetched_ptr is null, it was not set by the user and can be ignored.
     let has_rows_fetched_buffer = !stmt.attributes.read().unwrap().rows_fetched_ptr.is_null();
@@ -1414,13 +2737,17 @@ unsafe fn sql_fetch_helper(statement_handle: HStmt, function_name: &str) -> SqlR
 
     // Use `index` to figure out which buffer in the array of buffers to use.
     for index in 0..rowset_size {
-        // TODO: SQL-2014: Update SQLCancel and SQLFetch so SQLFetch can be cancelled correctly
-        // The main idea behind SQL-2014 is to check before each call to next and stop the execution if the statement is cancelled.
+        // The cancellation is checked before moving to each row, so SQLCancel stops the fetch
+        // even when the statement does not wait on the server for its rows.
         let move_to_next_result = {
             let connection = must_be_valid!((*stmt.connection).as_connection());
             match stmt.mongo_statement.write().unwrap().as_mut() {
-                Some(mongo_stmt) => mongo_stmt
-                    .next(connection.mongo_connection.read().unwrap().as_ref())
+                Some(mongo_stmt) => stmt
+                    .cancellation
+                    .check()
+                    .and_then(|_| {
+                        mongo_stmt.next(connection.mongo_connection.read().unwrap().as_ref())
+                    })
                     .map_err(|e| e.into()),
                 None => Err(ODBCError::InvalidCursorState),
             }
@@ -1487,7 +2814,7 @@ unsafe fn sql_fetch_helper(statement_handle: HStmt, function_name: &str) -> SqlR
             *stmt.var_data_cache.write().unwrap() = Some(HashMap::new());
 
             // If there are bound columns, then copy data from the result set into the bound buffers.
-            if let Some(bound_cols) = stmt.bound_cols.read().unwrap().as_ref() {
+            if ard.bound_cols.is_some() {
                 let (
                     encountered_error_during_col_binding,
                     encountered_success_with_info_during_col_binding,
@@ -1495,7 +2822,7 @@ unsafe fn sql_fetch_helper(statement_handle: HStmt, function_name: &str) -> SqlR
                     statement_handle,
                     index,
                     row_status_buffer,
-                    bound_cols,
+                    &ard,
                     function_name,
                 );
 
@@ -1525,6 +2852,11 @@ unsafe fn sql_fetch_helper(statement_handle: HStmt, function_name: &str) -> SqlR
             if matches!(error, ODBCError::InvalidCursorState) {
                 return SqlReturn::ERROR;
             }
+            // A cancelled fetch closes the cursor, so the statement can be executed again.
+            if matches!(error, ODBCError::Core(Error::QueryCancelled)) {
+                sql_stmt_close_cursor_helper(stmt);
+                return SqlReturn::ERROR;
+            }
 
             if has_row_status_array {
                 *row_status_buffer = RowStatus::SQL_ROW_ERROR as USmallInt;
@@ -1561,7 +2893,7 @@ unsafe fn sql_fetch_bound_buffers(
     statement_handle: HStmt,
     index: ULen,
     row_status_buffer: *mut USmallInt,
-    bound_cols: &HashMap<USmallInt, BoundColInfo>,
+    ard: &AppRowDesc,
     function_name: &str,
 ) -> (bool, bool) {
     let mongo_handle_for_sql_get_data_helper = match MongoHandleRef::try_from(statement_handle) {
@@ -1572,15 +2904,40 @@ unsafe fn sql_fetch_bound_buffers(
     let mut encountered_error_getting_data = false;
     let mut encountered_success_with_info_getting_data = false;
 
-    for (col, bound_col_info) in bound_cols.iter() {
+    let bind_offset = if ard.bind_offset_ptr.is_null() {
+        0
+    } else {
+        *ard.bind_offset_ptr
+    };
+
+    for (col, bound_col_info) in ard.bound_cols.iter().flatten() {
+        // Records set with SQLSetDescField may not have a data pointer yet, and are not bound.
+        if bound_col_info.target_buffer.is_null() {
+            continue;
+        }
+        // With column-wise binding, each column is bound to an array of buffers and an array of
+        // length/indicators. With row-wise binding, bind_type is the size of the structure each
+        // row is bound in. In both cases, the bind offset is added to every address.
+        let (value_stride, len_ind_stride) =
+            if ard.bind_type == BindType::SQL_BIND_BY_COLUMN as ULen {
+                (bound_col_info.buffer_length as ULen, size_of::<Len>())
+            } else {
+                (ard.bind_type, ard.bind_type)
+            };
+        let address = |ptr: *mut u8, stride: ULen| {
+            if ptr.is_null() {
+                ptr
+            } else {
+                ptr.wrapping_add(bind_offset + index * stride)
+            }
+        };
+
         // Set target_buffer to the correct buffer in the array of buffers
-        let target_buffer = (bound_col_info.target_buffer as ULen
-            + (index * (bound_col_info.buffer_length as ULen)))
-            as Pointer;
+        let target_buffer = address(bound_col_info.target_buffer.cast(), value_stride).cast();
 
         // Set length/indicator buffer to the correct buffer in the array of buffers
         let len_ind_buffer =
-            (bound_col_info.length_or_indicator as ULen + (index * size_of::<isize>())) as *mut Len;
+            address(bound_col_info.length_or_indicator.cast(), len_ind_stride).cast::<Len>();
 
         let sql_return = sql_get_data_helper(
             mongo_handle_for_sql_get_data_helper,
@@ -1614,43 +2971,206 @@ unsafe fn sql_fetch_bound_buffers(
     }
 
     (
-        encountered_error_getting_data,
-        encountered_success_with_info_getting_data,
-    )
+        encountered_error_getting_data,
+        encountered_success_with_info_getting_data,
+    )
+}
+
+///
+/// [`SQLFetchScroll`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLFetchScroll-function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLFetchScroll(
+    statement_handle: HStmt,
+    fetch_orientation: SmallInt,
+    fetch_offset: Len,
+) -> SqlReturn {
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(statement_handle);
+            if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                return sql_return;
+            }
+            exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                let stmt = must_be_valid!(mongo_handle.as_statement());
+                let (is_static, use_bookmarks, bookmark) = {
+                    let attributes = stmt.attributes.read().unwrap();
+                    (
+                        matches!(attributes.cursor_type, CursorType::SQL_CURSOR_STATIC),
+                        !matches!(attributes.use_bookmarks, UseBookmarks::Off),
+                        bookmark_from_ptr(attributes.fetch_bookmark_ptr),
+                    )
+                };
+                match FromPrimitive::from_i32(i32::from(fetch_orientation)) {
+                    Some(FetchOrientation::SQL_FETCH_NEXT) => sql_fetch_scroll_helper(
+                        statement_handle,
+                        FetchOrientation::SQL_FETCH_NEXT,
+                        fetch_offset,
+                        "SQLFetchScroll",
+                    ),
+                    Some(
+                        orientation @ (FetchOrientation::SQL_FETCH_FIRST
+                        | FetchOrientation::SQL_FETCH_LAST
+                        | FetchOrientation::SQL_FETCH_PRIOR
+                        | FetchOrientation::SQL_FETCH_ABSOLUTE
+                        | FetchOrientation::SQL_FETCH_RELATIVE),
+                    ) if is_static => sql_fetch_scroll_helper(
+                        statement_handle,
+                        orientation,
+                        fetch_offset,
+                        "SQLFetchScroll",
+                    ),
+                    // The rowset starts fetch_offset rows after the row of the bookmark.
+                    Some(FetchOrientation::SQL_FETCH_BOOKMARK) if is_static && use_bookmarks => {
+                        match bookmark {
+                            Some(row) => sql_fetch_scroll_helper(
+                                statement_handle,
+                                FetchOrientation::SQL_FETCH_BOOKMARK,
+                                row.saturating_add(fetch_offset),
+                                "SQLFetchScroll",
+                            ),
+                            None => {
+                                add_diag_info!(mongo_handle, ODBCError::InvalidBookmarkValue);
+                                SqlReturn::ERROR
+                            }
+                        }
+                    }
+                    _ => {
+                        add_diag_info!(
+                            mongo_handle,
+                            ODBCError::FetchTypeOutOfRange(fetch_orientation)
+                        );
+                        SqlReturn::ERROR
+                    }
+                }
+            })
+        },
+        statement_handle
+    );
+}
+
+macro_rules! sql_foreign_keys_impl {
+    (
+        $statement_handle:ident,
+        $pk_catalog_name:ident,
+        $pk_catalog_name_length:ident,
+        $pk_table_name:ident,
+        $pk_table_name_length:ident,
+        $fk_catalog_name:ident,
+        $fk_catalog_name_length:ident,
+        $fk_table_name:ident,
+        $fk_table_name_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let pk_catalog_name = $pk_catalog_name;
+        let pk_catalog_name_length = $pk_catalog_name_length;
+        let pk_table_name = $pk_table_name;
+        let pk_table_name_length = $pk_table_name_length;
+        let fk_catalog_name = $fk_catalog_name;
+        let fk_catalog_name_length = $fk_catalog_name_length;
+        let fk_table_name = $fk_table_name;
+        let fk_table_name_length = $fk_table_name_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let pk_catalog = $input_text(pk_catalog_name, pk_catalog_name_length.into());
+                let fk_catalog = $input_text(fk_catalog_name, fk_catalog_name_length.into());
+                // ignore schemas
+                let pk_table = $input_text(pk_table_name, pk_table_name_length.into());
+                let fk_table = $input_text(fk_table_name, fk_table_name_length.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    // Foreign keys reference collections of the same database, which is the current
+                    // database if no catalog is given.
+                    let catalog = match (pk_catalog.is_empty(), fk_catalog.is_empty()) {
+                        (false, _) => pk_catalog,
+                        (true, false) => fk_catalog,
+                        (true, true) => connection
+                            .attributes
+                            .read()
+                            .unwrap()
+                            .current_catalog
+                            .clone()
+                            .unwrap_or_default(),
+                    };
+                    let mongo_statement =
+                        match catalog.is_empty() || (pk_table.is_empty() && fk_table.is_empty()) {
+                            true => MongoForeignKeys::empty(),
+                            false => odbc_unwrap!(
+                                MongoForeignKeys::list_foreign_keys(
+                                    connection
+                                        .mongo_connection
+                                        .read()
+                                        .unwrap()
+                                        .as_ref()
+                                        .unwrap(),
+                                    &catalog,
+                                    &pk_table,
+                                    &fk_table,
+                                    stmt.attributes.read().unwrap().max_rows as u64,
+                                ),
+                                mongo_handle
+                            ),
+                        };
+                    set_catalog_result_set(stmt, Box::new(mongo_statement));
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
 }
 
 ///
-/// [`SQLFetchScroll`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLFetchScroll-function
+/// [`SQLForeignKeys`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLForeignKeys-function
+///
+/// This is the ANSI version of the SQLForeignKeys function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
 #[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLFetchScroll(
+pub unsafe extern "C" fn SQLForeignKeys(
     statement_handle: HStmt,
-    fetch_orientation: SmallInt,
-    _fetch_offset: Len,
+    pk_catalog_name: *const Char,
+    pk_catalog_name_length: SmallInt,
+    _pk_schema_name: *const Char,
+    _pk_schema_name_length: SmallInt,
+    pk_table_name: *const Char,
+    pk_table_name_length: SmallInt,
+    fk_catalog_name: *const Char,
+    fk_catalog_name_length: SmallInt,
+    _fk_schema_name: *const Char,
+    _fk_schema_name_length: SmallInt,
+    fk_table_name: *const Char,
+    fk_table_name_length: SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            match FromPrimitive::from_i32(i32::from(fetch_orientation)) {
-                Some(FetchOrientation::SQL_FETCH_NEXT) => {
-                    sql_fetch_helper(statement_handle, "SQLFetchScroll")
-                }
-                _ => {
-                    let stmt_handle = try_mongo_handle!(statement_handle);
-                    add_diag_info!(
-                        stmt_handle,
-                        ODBCError::FetchTypeOutOfRange(fetch_orientation)
-                    );
-                    SqlReturn::ERROR
-                }
-            }
-        },
-        statement_handle
-    );
+    sql_foreign_keys_impl!(
+        statement_handle,
+        pk_catalog_name,
+        pk_catalog_name_length,
+        pk_table_name,
+        pk_table_name_length,
+        fk_catalog_name,
+        fk_catalog_name_length,
+        fk_table_name,
+        fk_table_name_length,
+        input_text_to_string_a_allow_null,
+    )
 }
 
 ///
@@ -1665,31 +3185,31 @@ pub unsafe extern "C" fn SQLFetchScroll(
 #[no_mangle]
 pub unsafe extern "C" fn SQLForeignKeysW(
     statement_handle: HStmt,
-    _pk_catalog_name: *const WideChar,
-    _pk_catalog_name_length: SmallInt,
+    pk_catalog_name: *const WideChar,
+    pk_catalog_name_length: SmallInt,
     _pk_schema_name: *const WideChar,
     _pk_schema_name_length: SmallInt,
-    _pk_table_name: *const WideChar,
-    _pk_table_name_length: SmallInt,
-    _fk_catalog_name: *const WideChar,
-    _fk_catalog_name_length: SmallInt,
+    pk_table_name: *const WideChar,
+    pk_table_name_length: SmallInt,
+    fk_catalog_name: *const WideChar,
+    fk_catalog_name_length: SmallInt,
     _fk_schema_name: *const WideChar,
     _fk_schema_name_length: SmallInt,
-    _fk_table_name: *const WideChar,
-    _fk_table_name_length: SmallInt,
+    fk_table_name: *const WideChar,
+    fk_table_name_length: SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let stmt = must_be_valid!((*mongo_handle).as_statement());
-            let max_string_length = stmt.get_max_string_length();
-            let mongo_statement = MongoForeignKeys::empty(max_string_length);
-            *stmt.mongo_statement.write().unwrap() = Some(Box::new(mongo_statement));
-            SqlReturn::SUCCESS
-        },
-        statement_handle
-    );
+    sql_foreign_keys_impl!(
+        statement_handle,
+        pk_catalog_name,
+        pk_catalog_name_length,
+        pk_table_name,
+        pk_table_name_length,
+        fk_catalog_name,
+        fk_catalog_name_length,
+        fk_table_name,
+        fk_table_name_length,
+        input_text_to_string_w_allow_null,
+    )
 }
 
 ///
@@ -1712,6 +3232,11 @@ pub unsafe extern "C" fn SQLFreeHandle(handle_type: HandleType, handle: Handle)
         || {
             match sql_free_handle(handle_type, handle.cast()) {
                 Ok(_) => SqlReturn::SUCCESS,
+                Err(error @ ODBCError::InvalidUseOfAutomaticDescriptor) => {
+                    let mongo_handle = try_mongo_handle!(handle);
+                    add_diag_info!(mongo_handle, error);
+                    SqlReturn::ERROR
+                }
                 Err(_) => SqlReturn::INVALID_HANDLE,
             }
         },
@@ -1753,6 +3278,10 @@ fn sql_free_handle(handle_type: HandleType, handle: *mut MongoHandle) -> Result<
                     .as_statement()
                     .ok_or(ODBCError::InvalidHandleType(HANDLE_MUST_BE_STMT_ERROR))?
             };
+            // Wait for any asynchronous function, which uses the statement, to finish.
+            if let Some(operation) = stmt.async_operation.write().unwrap().take() {
+                let _ = operation.worker.join();
+            }
             // Ensure the cursor is closed on the statement before dropping it.
             sql_stmt_close_cursor_helper(stmt);
             // Actually reading this value would make ASAN fail, but this
@@ -1769,11 +3298,27 @@ fn sql_free_handle(handle_type: HandleType, handle: *mut MongoHandle) -> Result<
             }
         }
         HandleType::SQL_HANDLE_DESC => {
-            let _ = unsafe {
+            let desc = unsafe {
                 (*handle)
                     .as_descriptor()
                     .ok_or(ODBCError::InvalidHandleType(HANDLE_MUST_BE_DESC_ERROR))?
             };
+            // Implicitly allocated descriptors are freed with their statement.
+            if *desc.state.read().unwrap() == DescriptorState::ImplicitlyAllocated {
+                return Err(ODBCError::InvalidUseOfAutomaticDescriptor);
+            }
+            // Statements using the descriptor revert to their implicitly allocated descriptor.
+            if let Some(conn) = unsafe { (*desc.connection).as_connection() } {
+                conn.descriptors.write().unwrap().remove(&handle);
+                for stmt in conn.statements.read().unwrap().iter() {
+                    if let Some(stmt) = unsafe { (**stmt).as_statement() } {
+                        let mut attributes = stmt.attributes.write().unwrap();
+                        if attributes.app_row_desc == handle {
+                            attributes.app_row_desc = attributes.implicit_app_row_desc;
+                        }
+                    }
+                }
+            }
         }
     }
     // create the Box at the end to ensure Drop only occurs when there are no errors due
@@ -1791,6 +3336,10 @@ fn sql_free_handle(handle_type: HandleType, handle: *mut MongoHandle) -> Result<
 #[named]
 #[no_mangle]
 pub unsafe extern "C" fn SQLFreeStmt(statement_handle: HStmt, option: SmallInt) -> SqlReturn {
+    // The statement is freed before returning, so its handle cannot be used to trace the call.
+    if FromPrimitive::from_i16(option) == Some(FreeStmtOption::SQL_DROP) {
+        return SQLFreeHandle(HandleType::SQL_HANDLE_STMT, statement_handle.cast());
+    }
     panic_safe_exec_clear_diagnostics!(
         debug,
         || {
@@ -1800,6 +3349,8 @@ pub unsafe extern "C" fn SQLFreeStmt(statement_handle: HStmt, option: SmallInt)
             match FromPrimitive::from_i16(option) {
                 // Drop all pending results from the cursor and close the cursor.
                 Some(FreeStmtOption::SQL_CLOSE) => {
+                    stmt.more_results.write().unwrap().clear();
+                    *stmt.cursor_open.write().unwrap() = false;
                     let mut mongo_statement = stmt.mongo_statement.write().unwrap();
                     match mongo_statement.as_mut() {
                         // No-op when the mongo_statement is not set. This is typically an
@@ -1814,11 +3365,15 @@ pub unsafe extern "C" fn SQLFreeStmt(statement_handle: HStmt, option: SmallInt)
                 }
                 // Release all column buffers bound by SQLBindCol by removing the bound_cols map.
                 Some(FreeStmtOption::SQL_UNBIND) => {
-                    *stmt.bound_cols.write().unwrap() = None;
+                    stmt.update_app_row_desc(|ard| ard.bound_cols = None);
+                    SqlReturn::SUCCESS
+                }
+                // Release all parameter buffers bound by SQLBindParameter by removing the
+                // bound_params map.
+                Some(FreeStmtOption::SQL_RESET_PARAMS) => {
+                    *stmt.bound_params.write().unwrap() = None;
                     SqlReturn::SUCCESS
                 }
-                // We do not implement SQLBindParameter, so this is a no-op.
-                Some(FreeStmtOption::SQL_RESET_PARAMS) => SqlReturn::SUCCESS,
                 _ => SqlReturn::ERROR,
             }
         },
@@ -1826,6 +3381,73 @@ pub unsafe extern "C" fn SQLFreeStmt(statement_handle: HStmt, option: SmallInt)
     )
 }
 
+macro_rules! sql_get_connect_attr_impl {
+    (
+        $connection_handle:ident,
+        $attribute:ident,
+        $value_ptr:ident,
+        $buffer_length:ident,
+        $string_length_ptr:ident,
+        $set_output_string:path,
+    ) => {{
+        let connection_handle = $connection_handle;
+        let attribute = $attribute;
+        let value_ptr = $value_ptr;
+        let buffer_length = $buffer_length;
+        let string_length_ptr = $string_length_ptr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let conn_handle = try_mongo_handle!(connection_handle);
+
+                match FromPrimitive::from_i32(attribute) {
+                    Some(valid_attr) => sql_get_connect_attr_helper(
+                        conn_handle,
+                        valid_attr,
+                        value_ptr,
+                        buffer_length,
+                        string_length_ptr,
+                        $set_output_string,
+                        function_name!(),
+                    ),
+                    None => {
+                        add_diag_info!(conn_handle, ODBCError::InvalidAttrIdentifier(attribute));
+                        SqlReturn::ERROR
+                    }
+                }
+            },
+            connection_handle
+        )
+    }};
+}
+
+///
+/// [`SQLGetConnectAttr`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetConnectAttr-function
+///
+/// This is the ANSI version of the SQLGetConnectAttr function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetConnectAttr(
+    connection_handle: HDbc,
+    attribute: Integer,
+    value_ptr: Pointer,
+    buffer_length: Integer,
+    string_length_ptr: *mut Integer,
+) -> SqlReturn {
+    sql_get_connect_attr_impl!(
+        connection_handle,
+        attribute,
+        value_ptr,
+        buffer_length,
+        string_length_ptr,
+        i32_len::set_output_string_as_bytes,
+    )
+}
+
 ///
 /// [`SQLGetConnectAttrW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetConnectAttr-function
 ///
@@ -1843,35 +3465,24 @@ pub unsafe extern "C" fn SQLGetConnectAttrW(
     buffer_length: Integer,
     string_length_ptr: *mut Integer,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let conn_handle = try_mongo_handle!(connection_handle);
-
-            match FromPrimitive::from_i32(attribute) {
-                Some(valid_attr) => sql_get_connect_attrw_helper(
-                    conn_handle,
-                    valid_attr,
-                    value_ptr,
-                    buffer_length,
-                    string_length_ptr,
-                ),
-                None => {
-                    add_diag_info!(conn_handle, ODBCError::InvalidAttrIdentifier(attribute));
-                    SqlReturn::ERROR
-                }
-            }
-        },
-        connection_handle
+    sql_get_connect_attr_impl!(
+        connection_handle,
+        attribute,
+        value_ptr,
+        buffer_length,
+        string_length_ptr,
+        i32_len::set_output_wstring_as_bytes,
     )
 }
 
-unsafe fn sql_get_connect_attrw_helper(
+unsafe fn sql_get_connect_attr_helper(
     conn_handle: &mut MongoHandle,
     attribute: ConnectionAttribute,
     value_ptr: Pointer,
     buffer_length: Integer,
     string_length_ptr: *mut Integer,
+    set_output_string: unsafe fn(&str, Pointer, usize, *mut Integer) -> SqlReturn,
+    function_name: &str,
 ) -> SqlReturn {
     let mut err = None;
 
@@ -1887,12 +3498,9 @@ unsafe fn sql_get_connect_attrw_helper(
                 let current_catalog = attributes.current_catalog.as_deref();
                 match current_catalog {
                     None => SqlReturn::NO_DATA,
-                    Some(cc) => i32_len::set_output_wstring_as_bytes(
-                        cc,
-                        value_ptr,
-                        buffer_length as usize,
-                        string_length_ptr,
-                    ),
+                    Some(cc) => {
+                        set_output_string(cc, value_ptr, buffer_length as usize, string_length_ptr)
+                    }
                 }
             }
             ConnectionAttribute::SQL_ATTR_LOGIN_TIMEOUT => {
@@ -1924,11 +3532,71 @@ unsafe fn sql_get_connect_attrw_helper(
     };
 
     if let Some(e) = err {
-        add_diag_with_function!(conn_handle, e, "SQLGetConnectAttrW");
+        add_diag_with_function!(conn_handle, e, function_name);
     }
     sql_return
 }
 
+macro_rules! sql_get_cursor_name_impl {
+    (
+        $statement_handle:ident,
+        $cursor_name:ident,
+        $buffer_length:ident,
+        $name_length_ptr:ident,
+        $set_output_string:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let cursor_name = $cursor_name;
+        let buffer_length = $buffer_length;
+        let name_length_ptr = $name_length_ptr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                let stmt = must_be_valid!(mongo_handle.as_statement());
+                let buffer_length = odbc_unwrap!(
+                    usize::try_from(buffer_length)
+                        .map_err(|_| ODBCError::InvalidStringOrBufferLength(buffer_length.into())),
+                    mongo_handle
+                );
+                let name = stmt.cursor_name.read().unwrap().clone();
+                let sql_return =
+                    $set_output_string(&name, cursor_name, buffer_length, name_length_ptr);
+                if sql_return == SqlReturn::SUCCESS_WITH_INFO {
+                    add_diag_info!(mongo_handle, ODBCError::OutStringTruncated(buffer_length));
+                }
+                sql_return
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLGetCursorName`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetCursorName-function
+///
+/// This is the ANSI version of the SQLGetCursorName function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetCursorName(
+    statement_handle: HStmt,
+    cursor_name: *mut Char,
+    buffer_length: SmallInt,
+    name_length_ptr: *mut SmallInt,
+) -> SqlReturn {
+    sql_get_cursor_name_impl!(
+        statement_handle,
+        cursor_name,
+        buffer_length,
+        name_length_ptr,
+        i16_len::set_output_string,
+    )
+}
+
 ///
 /// [`SQLGetCursorNameW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetCursorName-function
 ///
@@ -1941,11 +3609,17 @@ unsafe fn sql_get_connect_attrw_helper(
 #[no_mangle]
 pub unsafe extern "C" fn SQLGetCursorNameW(
     statement_handle: HStmt,
-    _cursor_name: *mut WideChar,
-    _buffer_length: SmallInt,
-    _name_length_ptr: *mut SmallInt,
+    cursor_name: *mut WideChar,
+    buffer_length: SmallInt,
+    name_length_ptr: *mut SmallInt,
 ) -> SqlReturn {
-    unimpl!(statement_handle);
+    sql_get_cursor_name_impl!(
+        statement_handle,
+        cursor_name,
+        buffer_length,
+        name_length_ptr,
+        i16_len::set_output_wstring,
+    )
 }
 
 ///
@@ -1971,7 +3645,7 @@ pub unsafe extern "C" fn SQLGetData(
             let stmt = must_be_valid!((*mongo_handle).as_statement());
 
             // Make sure that SQLGetData only runs when dealing with rowsets of size 1.
-            if stmt.attributes.read().unwrap().row_array_size != 1 {
+            if stmt.app_row_desc().array_size != 1 {
                 let mongo_handle = try_mongo_handle!(statement_handle);
                 add_diag_info!(
                     mongo_handle,
@@ -2034,6 +3708,21 @@ unsafe fn sql_get_data_helper(
             );
         }
         let stmt = (*mongo_handle).as_statement().unwrap();
+        if col_or_param_num == 0
+            && !matches!(
+                stmt.attributes.read().unwrap().use_bookmarks,
+                UseBookmarks::Off
+            )
+        {
+            return sql_get_bookmark(
+                mongo_handle,
+                target_type,
+                target_value_ptr,
+                buffer_length,
+                str_len_or_ind_ptr,
+                function_name,
+            );
+        }
         let mut mongo_stmt = stmt.mongo_statement.write().unwrap();
         let max_string_length = stmt.get_max_string_length();
         let bson = match mongo_stmt.as_mut() {
@@ -2069,24 +3758,278 @@ unsafe fn sql_get_data_helper(
 }
 
 ///
-/// [`SQLGetDescFieldW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDescField-function
+/// sql_get_bookmark writes the bookmark of the current row, which is column 0 when bookmarks are
+/// enabled. Bookmarks are variable-length bookmarks, so they can only be retrieved as binary data.
+///
+unsafe fn sql_get_bookmark(
+    mongo_handle: &mut MongoHandle,
+    target_type: CDataType,
+    target_value_ptr: Pointer,
+    buffer_length: Len,
+    str_len_or_ind_ptr: *mut Len,
+    function_name: &str,
+) -> SqlReturn {
+    let bookmark = {
+        let stmt = must_be_valid!((*mongo_handle).as_statement());
+        match stmt.mongo_statement.read().unwrap().as_ref() {
+            _ if target_type != CDataType::SQL_C_BINARY => {
+                Err(ODBCError::InvalidTargetType(target_type as SmallInt))
+            }
+            None => Err(ODBCError::InvalidCursorState),
+            Some(mongo_stmt) => mongo_stmt.get_bookmark().map_err(ODBCError::Core),
+        }
+    };
+    match bookmark {
+        Ok(row) => crate::api::data::format_binary(
+            mongo_handle,
+            0,
+            0,
+            target_value_ptr,
+            buffer_length,
+            str_len_or_ind_ptr,
+            bookmark_to_bytes(row),
+            function_name,
+        ),
+        Err(e) => {
+            add_diag_with_function!(mongo_handle, e, function_name);
+            SqlReturn::ERROR
+        }
+    }
+}
+
+/// Bookmarks hold the row number of a static cursor row as a little-endian u64.
+fn bookmark_to_bytes(row: usize) -> Vec<u8> {
+    (row as u64).to_le_bytes().to_vec()
+}
+
+/// bookmark_from_ptr reads the bookmark pointed to by SQL_ATTR_FETCH_BOOKMARK_PTR, returning None
+/// if there is no bookmark or it is not a valid row number.
+unsafe fn bookmark_from_ptr(bookmark_ptr: *const Len) -> Option<isize> {
+    if bookmark_ptr.is_null() {
+        return None;
+    }
+    let row = u64::from_le_bytes(std::ptr::read_unaligned(bookmark_ptr.cast::<[u8; 8]>()));
+    isize::try_from(row).ok().filter(|row| *row > 0)
+}
+
+macro_rules! sql_get_desc_field_impl {
+    (
+        $descriptor_handle:ident,
+        $record_number:ident,
+        $field_identifier:ident,
+        $value_ptr:ident,
+        $buffer_length:ident,
+        $string_length_ptr:ident,
+        $set_output_string:path,
+    ) => {{
+        let descriptor_handle = $descriptor_handle;
+        let record_number = $record_number;
+        let field_identifier = $field_identifier;
+        let value_ptr = $value_ptr;
+        let buffer_length = $buffer_length;
+        let string_length_ptr = $string_length_ptr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(descriptor_handle);
+                let odbc_version = mongo_handle.get_odbc_version();
+                let desc = must_be_desc!(mongo_handle);
+                let value = match FromPrimitive::from_i16(field_identifier) {
+                    Some(field) => desc::get_desc_field(desc, odbc_version, record_number, field),
+                    None => Err(ODBCError::InvalidFieldDescriptor(field_identifier as u16)),
+                };
+                match value {
+                    Ok(Some(DescField::SmallInt(value))) => {
+                        i32_len::set_output_fixed_data(&value, value_ptr, string_length_ptr)
+                    }
+                    Ok(Some(DescField::Integer(value))) => {
+                        i32_len::set_output_fixed_data(&value, value_ptr, string_length_ptr)
+                    }
+                    Ok(Some(DescField::Len(value))) => {
+                        i32_len::set_output_fixed_data(&value, value_ptr, string_length_ptr)
+                    }
+                    Ok(Some(DescField::ULen(value))) => {
+                        i32_len::set_output_fixed_data(&value, value_ptr, string_length_ptr)
+                    }
+                    Ok(Some(DescField::Pointer(value))) => {
+                        i32_len::set_output_fixed_data(&value, value_ptr, string_length_ptr)
+                    }
+                    Ok(Some(DescField::String(value))) => {
+                        let buffer_len = usize::try_from(buffer_length).unwrap_or(0);
+                        let sql_return =
+                            $set_output_string(&value, value_ptr, buffer_len, string_length_ptr);
+                        if sql_return == SqlReturn::SUCCESS_WITH_INFO {
+                            add_diag_info!(mongo_handle, ODBCError::OutStringTruncated(buffer_len));
+                        }
+                        sql_return
+                    }
+                    Ok(None) => SqlReturn::NO_DATA,
+                    Err(error) => {
+                        add_diag_info!(mongo_handle, error);
+                        SqlReturn::ERROR
+                    }
+                }
+            },
+            descriptor_handle
+        )
+    }};
+}
+
+///
+/// [`SQLGetDescField`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDescField-function
+///
+/// This is the ANSI version of the SQLGetDescField function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLGetDescField(
+    descriptor_handle: HDesc,
+    record_number: SmallInt,
+    field_identifier: SmallInt,
+    value_ptr: Pointer,
+    buffer_length: Integer,
+    string_length_ptr: *mut Integer,
+) -> SqlReturn {
+    sql_get_desc_field_impl!(
+        descriptor_handle,
+        record_number,
+        field_identifier,
+        value_ptr,
+        buffer_length,
+        string_length_ptr,
+        i32_len::set_output_string_as_bytes,
+    )
+}
+
+///
+/// [`SQLGetDescFieldW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDescField-function
+///
+/// This is the WideChar version of the SQLGetDescField function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLGetDescFieldW(
+    descriptor_handle: HDesc,
+    record_number: SmallInt,
+    field_identifier: SmallInt,
+    value_ptr: Pointer,
+    buffer_length: Integer,
+    string_length_ptr: *mut Integer,
+) -> SqlReturn {
+    sql_get_desc_field_impl!(
+        descriptor_handle,
+        record_number,
+        field_identifier,
+        value_ptr,
+        buffer_length,
+        string_length_ptr,
+        i32_len::set_output_wstring_as_bytes,
+    )
+}
+
+macro_rules! sql_get_desc_rec_impl {
+    (
+        $descriptor_handle:ident,
+        $record_number:ident,
+        $name:ident,
+        $buffer_length:ident,
+        $string_length_ptr:ident,
+        $type_ptr:ident,
+        $sub_type_ptr:ident,
+        $length_ptr:ident,
+        $precision_ptr:ident,
+        $scale_ptr:ident,
+        $nullable_ptr:ident,
+        $set_output_string:path,
+    ) => {{
+        let descriptor_handle = $descriptor_handle;
+        let record_number = $record_number;
+        let name = $name;
+        let buffer_length = $buffer_length;
+        let string_length_ptr = $string_length_ptr;
+        let type_ptr = $type_ptr;
+        let sub_type_ptr = $sub_type_ptr;
+        let length_ptr = $length_ptr;
+        let precision_ptr = $precision_ptr;
+        let scale_ptr = $scale_ptr;
+        let nullable_ptr = $nullable_ptr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(descriptor_handle);
+                let odbc_version = mongo_handle.get_odbc_version();
+                let desc = must_be_desc!(mongo_handle);
+                match desc::get_desc_rec(desc, odbc_version, record_number) {
+                    Ok(Some(rec)) => {
+                        ptr_safe_write(type_ptr, rec.desc_type);
+                        ptr_safe_write(sub_type_ptr, rec.sub_type);
+                        ptr_safe_write(length_ptr, rec.length);
+                        ptr_safe_write(precision_ptr, rec.precision);
+                        ptr_safe_write(scale_ptr, rec.scale);
+                        ptr_safe_write(nullable_ptr, rec.nullable);
+                        let buffer_len = usize::try_from(buffer_length).unwrap_or(0);
+                        let sql_return =
+                            $set_output_string(&rec.name, name, buffer_len, string_length_ptr);
+                        if sql_return == SqlReturn::SUCCESS_WITH_INFO {
+                            add_diag_info!(mongo_handle, ODBCError::OutStringTruncated(buffer_len));
+                        }
+                        sql_return
+                    }
+                    Ok(None) => SqlReturn::NO_DATA,
+                    Err(error) => {
+                        add_diag_info!(mongo_handle, error);
+                        SqlReturn::ERROR
+                    }
+                }
+            },
+            descriptor_handle
+        )
+    }};
+}
+
+///
+/// [`SQLGetDescRec`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDescRec-function
 ///
-/// This is the WideChar version of the SQLGetDescField function
+/// This is the ANSI version of the SQLGetDescRec function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
 #[no_mangle]
 #[named]
-pub unsafe extern "C" fn SQLGetDescFieldW(
-    _descriptor_handle: HDesc,
-    _record_number: SmallInt,
-    _field_identifier: SmallInt,
-    _value_ptr: Pointer,
-    _buffer_length: Integer,
-    _string_length_ptr: *mut Integer,
+pub unsafe extern "C" fn SQLGetDescRec(
+    descriptor_handle: HDesc,
+    record_number: SmallInt,
+    name: *mut Char,
+    buffer_length: SmallInt,
+    string_length_ptr: *mut SmallInt,
+    type_ptr: *mut SmallInt,
+    sub_type_ptr: *mut SmallInt,
+    length_ptr: *mut Len,
+    precision_ptr: *mut SmallInt,
+    scale_ptr: *mut SmallInt,
+    nullable_ptr: *mut SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(_descriptor_handle)
+    sql_get_desc_rec_impl!(
+        descriptor_handle,
+        record_number,
+        name,
+        buffer_length,
+        string_length_ptr,
+        type_ptr,
+        sub_type_ptr,
+        length_ptr,
+        precision_ptr,
+        scale_ptr,
+        nullable_ptr,
+        i16_len::set_output_string,
+    )
 }
 
 ///
@@ -2100,19 +4043,143 @@ pub unsafe extern "C" fn SQLGetDescFieldW(
 #[no_mangle]
 #[named]
 pub unsafe extern "C" fn SQLGetDescRecW(
-    _descriptor_handle: HDesc,
-    _record_number: SmallInt,
-    _name: *mut WideChar,
-    _buffer_length: SmallInt,
-    _string_length_ptr: *mut SmallInt,
-    _type_ptr: *mut SmallInt,
-    _sub_type_ptr: *mut SmallInt,
-    _length_ptr: *mut Len,
-    _precision_ptr: *mut SmallInt,
-    _scale_ptr: *mut SmallInt,
-    _nullable_ptr: *mut SmallInt,
+    descriptor_handle: HDesc,
+    record_number: SmallInt,
+    name: *mut WideChar,
+    buffer_length: SmallInt,
+    string_length_ptr: *mut SmallInt,
+    type_ptr: *mut SmallInt,
+    sub_type_ptr: *mut SmallInt,
+    length_ptr: *mut Len,
+    precision_ptr: *mut SmallInt,
+    scale_ptr: *mut SmallInt,
+    nullable_ptr: *mut SmallInt,
+) -> SqlReturn {
+    sql_get_desc_rec_impl!(
+        descriptor_handle,
+        record_number,
+        name,
+        buffer_length,
+        string_length_ptr,
+        type_ptr,
+        sub_type_ptr,
+        length_ptr,
+        precision_ptr,
+        scale_ptr,
+        nullable_ptr,
+        i16_len::set_output_wstring,
+    )
+}
+
+macro_rules! sql_get_diag_field_impl {
+    (
+        $handle_type:ident,
+        $handle:ident,
+        $record_number:ident,
+        $diag_identifier:ident,
+        $diag_info_ptr:ident,
+        $buffer_length:ident,
+        $string_length_ptr:ident,
+        $get_diag_field:path,
+    ) => {{
+        let handle_type = $handle_type;
+        let handle = $handle;
+        let record_number = $record_number;
+        let diag_identifier = $diag_identifier;
+        let diag_info_ptr = $diag_info_ptr;
+        let buffer_length = $buffer_length;
+        let string_length_ptr = $string_length_ptr;
+        panic_safe_exec_keep_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = handle.cast::<MongoHandle>();
+                let odbc_version = (*mongo_handle).get_odbc_version();
+                let get_error = |errors: &Vec<ODBCError>, diag_identifier: DiagType| -> SqlReturn {
+                    $get_diag_field(
+                        errors,
+                        diag_identifier,
+                        odbc_version,
+                        diag_info_ptr,
+                        record_number,
+                        buffer_length,
+                        string_length_ptr,
+                    )
+                };
+
+                match FromPrimitive::from_i16(diag_identifier) {
+                    Some(diag_identifier) => {
+                        match diag_identifier {
+                            // some diagnostics are statement specific; return error if another handle is passed
+                            DiagType::SQL_DIAG_ROW_COUNT | DiagType::SQL_DIAG_ROW_NUMBER => {
+                                if handle_type != HandleType::SQL_HANDLE_STMT {
+                                    return SqlReturn::ERROR;
+                                }
+                                get_stmt_diag_field(diag_identifier, diag_info_ptr)
+                            }
+                            DiagType::SQL_DIAG_NUMBER
+                            | DiagType::SQL_DIAG_MESSAGE_TEXT
+                            | DiagType::SQL_DIAG_NATIVE
+                            | DiagType::SQL_DIAG_SQLSTATE
+                            | DiagType::SQL_DIAG_RETURNCODE => match handle_type {
+                                HandleType::SQL_HANDLE_ENV => {
+                                    let env = must_be_env!(mongo_handle);
+                                    get_error(&env.errors.read().unwrap(), diag_identifier)
+                                }
+                                HandleType::SQL_HANDLE_DBC => {
+                                    let dbc = must_be_conn!(mongo_handle);
+                                    get_error(&dbc.errors.read().unwrap(), diag_identifier)
+                                }
+                                HandleType::SQL_HANDLE_STMT => {
+                                    let stmt = must_be_stmt!(mongo_handle);
+                                    get_error(&stmt.errors.read().unwrap(), diag_identifier)
+                                }
+                                HandleType::SQL_HANDLE_DESC => {
+                                    let desc = must_be_desc!(mongo_handle);
+                                    get_error(&desc.errors.read().unwrap(), diag_identifier)
+                                }
+                            },
+                            // TODO: SQL-1152: Implement additional diag types
+                            // this condition should only occur if the _diag_identifier is not in the spec
+                            _ => SqlReturn::ERROR,
+                        }
+                    }
+                    None => SqlReturn::ERROR,
+                }
+            },
+            handle
+        )
+    }};
+}
+
+///
+/// [`SQLGetDiagField`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDiagField-function
+///
+/// This is the ANSI version of the SQLGetDiagField function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetDiagField(
+    handle_type: HandleType,
+    handle: Handle,
+    record_number: SmallInt,
+    diag_identifier: SmallInt,
+    diag_info_ptr: Pointer,
+    buffer_length: SmallInt,
+    string_length_ptr: *mut SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(_descriptor_handle)
+    sql_get_diag_field_impl!(
+        handle_type,
+        handle,
+        record_number,
+        diag_identifier,
+        diag_info_ptr,
+        buffer_length,
+        string_length_ptr,
+        get_diag_field,
+    )
 }
 
 ///
@@ -2126,7 +4193,7 @@ pub unsafe extern "C" fn SQLGetDescRecW(
 #[named]
 #[no_mangle]
 pub unsafe extern "C" fn SQLGetDiagFieldW(
-    _handle_type: HandleType,
+    handle_type: HandleType,
     handle: Handle,
     record_number: SmallInt,
     diag_identifier: SmallInt,
@@ -2134,64 +4201,15 @@ pub unsafe extern "C" fn SQLGetDiagFieldW(
     buffer_length: SmallInt,
     string_length_ptr: *mut SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_keep_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = handle.cast::<MongoHandle>();
-            let odbc_version = (*mongo_handle).get_odbc_version();
-            let get_error = |errors: &Vec<ODBCError>, diag_identifier: DiagType| -> SqlReturn {
-                get_diag_fieldw(
-                    errors,
-                    diag_identifier,
-                    odbc_version,
-                    diag_info_ptr,
-                    record_number,
-                    buffer_length,
-                    string_length_ptr,
-                )
-            };
-
-            match FromPrimitive::from_i16(diag_identifier) {
-                Some(diag_identifier) => {
-                    match diag_identifier {
-                        // some diagnostics are statement specific; return error if another handle is passed
-                        DiagType::SQL_DIAG_ROW_COUNT | DiagType::SQL_DIAG_ROW_NUMBER => {
-                            if _handle_type != HandleType::SQL_HANDLE_STMT {
-                                return SqlReturn::ERROR;
-                            }
-                            get_stmt_diag_field(diag_identifier, diag_info_ptr)
-                        }
-                        DiagType::SQL_DIAG_NUMBER
-                        | DiagType::SQL_DIAG_MESSAGE_TEXT
-                        | DiagType::SQL_DIAG_NATIVE
-                        | DiagType::SQL_DIAG_SQLSTATE
-                        | DiagType::SQL_DIAG_RETURNCODE => match _handle_type {
-                            HandleType::SQL_HANDLE_ENV => {
-                                let env = must_be_env!(mongo_handle);
-                                get_error(&env.errors.read().unwrap(), diag_identifier)
-                            }
-                            HandleType::SQL_HANDLE_DBC => {
-                                let dbc = must_be_conn!(mongo_handle);
-                                get_error(&dbc.errors.read().unwrap(), diag_identifier)
-                            }
-                            HandleType::SQL_HANDLE_STMT => {
-                                let stmt = must_be_stmt!(mongo_handle);
-                                get_error(&stmt.errors.read().unwrap(), diag_identifier)
-                            }
-                            HandleType::SQL_HANDLE_DESC => {
-                                let desc = must_be_desc!(mongo_handle);
-                                get_error(&desc.errors.read().unwrap(), diag_identifier)
-                            }
-                        },
-                        // TODO: SQL-1152: Implement additional diag types
-                        // this condition should only occur if the _diag_identifier is not in the spec
-                        _ => SqlReturn::ERROR,
-                    }
-                }
-                None => SqlReturn::ERROR,
-            }
-        },
-        handle
+    sql_get_diag_field_impl!(
+        handle_type,
+        handle,
+        record_number,
+        diag_identifier,
+        diag_info_ptr,
+        buffer_length,
+        string_length_ptr,
+        get_diag_fieldw,
     )
 }
 
@@ -2247,6 +4265,39 @@ macro_rules! sql_get_diag_rec_impl {
     }};
 }
 
+///
+/// [`SQLGetDiagRec`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDiagRec-function
+///
+/// This is the ANSI version of the SQLGetDiagRec function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetDiagRec(
+    handle_type: HandleType,
+    handle: Handle,
+    rec_number: SmallInt,
+    state: *mut Char,
+    native_error_ptr: *mut Integer,
+    message_text: *mut Char,
+    buffer_length: SmallInt,
+    text_length_ptr: *mut SmallInt,
+) -> SqlReturn {
+    sql_get_diag_rec_impl!(
+        handle_type,
+        handle,
+        rec_number,
+        state,
+        native_error_ptr,
+        message_text,
+        buffer_length,
+        text_length_ptr,
+        get_diag_rec
+    )
+}
+
 ///
 /// [`SQLGetDiagRecW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetDiagRec-function
 ///
@@ -2354,6 +4405,8 @@ macro_rules! sql_get_info_helper {
  $info_value_ptr:ident,
  $buffer_length:ident,
  $string_length_ptr:ident,
+ $set_output_string:path,
+ $set_output_string_as_bytes:path,
  ) => {{
     use constants::*;
     use definitions::InfoType;
@@ -2371,19 +4424,19 @@ macro_rules! sql_get_info_helper {
                 debug,
                 conn_handle,
                 format!("InfoType {some_info_type:?}"),
-                "SQLGetInfoW"
+                function_name!()
             );
             match some_info_type {
                 InfoType::SQL_DRIVER_NAME => {
                     // This Driver Name is consistent with the name used for our JDBC driver.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         DRIVER_NAME,
                         info_value_ptr,
                         buffer_length as usize,
                         string_length_ptr,
                     )
                 }
-                InfoType::SQL_DRIVER_VER => i16_len::set_output_wstring_as_bytes(
+                InfoType::SQL_DRIVER_VER => $set_output_string_as_bytes(
                     DRIVER_ODBC_VERSION.as_str(),
                     info_value_ptr,
                     buffer_length as usize,
@@ -2391,14 +4444,14 @@ macro_rules! sql_get_info_helper {
                 ),
                 InfoType::SQL_DRIVER_ODBC_VER => {
                     // This driver supports version 3.8.
-                    i16_len::set_output_wstring(
+                    $set_output_string(
                         ODBC_VERSION,
-                        info_value_ptr.cast::<WideChar>(),
+                        info_value_ptr.cast(),
                         buffer_length as usize,
                         string_length_ptr,
                     )
                 }
-                InfoType::SQL_SEARCH_PATTERN_ESCAPE => i16_len::set_output_wstring_as_bytes(
+                InfoType::SQL_SEARCH_PATTERN_ESCAPE => $set_output_string_as_bytes(
                     r"\",
                     info_value_ptr,
                     buffer_length as usize,
@@ -2406,7 +4459,7 @@ macro_rules! sql_get_info_helper {
                 ),
                 InfoType::SQL_DBMS_NAME => {
                     // The underlying DBMS is MongoDB Atlas.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         DBMS_NAME,
                         info_value_ptr,
                         buffer_length as usize,
@@ -2424,7 +4477,7 @@ macro_rules! sql_get_info_helper {
                         .unwrap()
                         .get_adf_version();
                     match version {
-                        Ok(version) => i16_len::set_output_wstring_as_bytes(
+                        Ok(version) => $set_output_string_as_bytes(
                             version.as_str(),
                             info_value_ptr,
                             buffer_length as usize,
@@ -2445,7 +4498,7 @@ macro_rules! sql_get_info_helper {
                     // MongoSQL supports ` and " as identifier delimiters. The "
                     // character is the SQL-92 standard, but we instead return `
                     // to be consistent with our JDBC driver.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         "`",
                         info_value_ptr,
                         buffer_length as usize,
@@ -2463,7 +4516,7 @@ macro_rules! sql_get_info_helper {
                     // Therefore, a "schema" may map to MongoSQL's "database".
                     // However, we choose to use "catalog" to represent MongoSQL
                     // databases, and we omit support for "schema".
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         "",
                         info_value_ptr,
                         buffer_length as usize,
@@ -2483,7 +4536,7 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_CATALOG_NAME_SEPARATOR => {
                     // The name separator used by MongoSQL is '.'.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         ".",
                         info_value_ptr,
                         buffer_length as usize,
@@ -2492,7 +4545,7 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_CATALOG_TERM => {
                     // MongoSQL uses the term "database".
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         "database",
                         info_value_ptr,
                         buffer_length as usize,
@@ -2615,7 +4668,7 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_COLUMN_ALIAS => {
                     // MongoSQL does support column aliases.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         COLUMN_ALIAS_INFO_Y,
                         info_value_ptr,
                         buffer_length as usize,
@@ -2634,7 +4687,7 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_ORDER_BY_COLUMNS_IN_SELECT => {
                     // MongoSQL does require ORDER BY columns to be in the SELECT list.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         COLUMN_ALIAS_INFO_Y,
                         info_value_ptr,
                         buffer_length as usize,
@@ -2662,7 +4715,7 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_DATA_SOURCE_READ_ONLY => {
                     // MongoSQL is read-only.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         COLUMN_ALIAS_INFO_Y,
                         info_value_ptr,
                         buffer_length as usize,
@@ -2690,7 +4743,7 @@ macro_rules! sql_get_info_helper {
                     // than [A-Za-z0-9_]. It is unrealistic to return a string with
                     // all of those characters, so here we choose to return a string
                     // containing what we believe to be most common special characters.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         "`\"'.$+-*/|:<>!={}[]()",
                         info_value_ptr,
                         buffer_length as usize,
@@ -2778,7 +4831,7 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_CATALOG_NAME => {
                     // MongoSQL does support catalog (database) names.
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         COLUMN_ALIAS_INFO_Y,
                         info_value_ptr,
                         buffer_length as usize,
@@ -2791,6 +4844,11 @@ macro_rules! sql_get_info_helper {
                     // Let's report the max value for SQLUSMALLINT.
                     i16_len::set_output_fixed_data(&u16::MAX, info_value_ptr, string_length_ptr)
                 }
+                InfoType::SQL_MAX_CURSOR_NAME_LEN => i16_len::set_output_fixed_data(
+                    &MAX_CURSOR_NAME_LEN,
+                    info_value_ptr,
+                    string_length_ptr,
+                ),
                 // Since we don't support transaction, Commit and Rollback are not supported.
                 InfoType::SQL_CURSOR_COMMIT_BEHAVIOR | InfoType::SQL_CURSOR_ROLLBACK_BEHAVIOR => {
                     i16_len::set_output_fixed_data(
@@ -2801,7 +4859,6 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_DEFAULT_TXN_ISOLATION
                 | InfoType::SQL_DTC_TRANSITION_COST
-                | InfoType::SQL_BOOKMARK_PERSISTENCE
                 | InfoType::SQL_POS_OPERATIONS
                 | InfoType::SQL_STATIC_SENSITIVITY
                 | InfoType::SQL_TXN_CAPABLE => {
@@ -2817,24 +4874,36 @@ macro_rules! sql_get_info_helper {
                 }
                 InfoType::SQL_FORWARD_ONLY_CURSOR_ATTRIBUTES1
                 | InfoType::SQL_KEYSET_CURSOR_ATTRIBUTES1
-                | InfoType::SQL_DYNAMIC_CURSOR_ATTRIBUTES1
-                | InfoType::SQL_STATIC_CURSOR_ATTRIBUTES1 => {
+                | InfoType::SQL_DYNAMIC_CURSOR_ATTRIBUTES1 => {
                     i16_len::set_output_fixed_data(
                         &SQL_CA1_NEXT,
                         info_value_ptr,
                         string_length_ptr,
                     )
                 }
+                InfoType::SQL_STATIC_CURSOR_ATTRIBUTES1 => i16_len::set_output_fixed_data(
+                    &MONGO_STATIC_CA1_SUPPORT,
+                    info_value_ptr,
+                    string_length_ptr,
+                ),
                 InfoType::SQL_FORWARD_ONLY_CURSOR_ATTRIBUTES2
                 | InfoType::SQL_KEYSET_CURSOR_ATTRIBUTES2
-                | InfoType::SQL_DYNAMIC_CURSOR_ATTRIBUTES2
-                | InfoType::SQL_STATIC_CURSOR_ATTRIBUTES2 => {
+                | InfoType::SQL_DYNAMIC_CURSOR_ATTRIBUTES2 => {
                     i16_len::set_output_fixed_data(
                         &MONGO_CA2_SUPPORT,
                         info_value_ptr,
                         string_length_ptr,
                     )
                 }
+                InfoType::SQL_STATIC_CURSOR_ATTRIBUTES2 => i16_len::set_output_fixed_data(
+                    &MONGO_STATIC_CA2_SUPPORT,
+                    info_value_ptr,
+                    string_length_ptr,
+                ),
+                // Bookmarks are row numbers of a static cursor, so they stay valid as it scrolls.
+                InfoType::SQL_BOOKMARK_PERSISTENCE => {
+                    i16_len::set_output_fixed_data(&SQL_BP_SCROLL, info_value_ptr, string_length_ptr)
+                }
                 InfoType::SQL_SCROLL_OPTIONS => {
                     i16_len::set_output_fixed_data(
                         &MONGO_SO_SUPPORT,
@@ -2843,7 +4912,7 @@ macro_rules! sql_get_info_helper {
                     )
                 }
                 InfoType::SQL_NEED_LONG_DATA_LEN => {
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         COLUMN_ALIAS_INFO_Y,
                         info_value_ptr,
                         buffer_length as usize,
@@ -2861,7 +4930,7 @@ macro_rules! sql_get_info_helper {
                     let conn = must_be_valid!((*conn_handle).as_connection());
                     let attributes = conn.attributes.read().unwrap();
                     if attributes.current_catalog.is_some() {
-                        i16_len::set_output_wstring_as_bytes(
+                        $set_output_string_as_bytes(
                             attributes.current_catalog.as_ref().unwrap().as_str(),
                             info_value_ptr,
                             buffer_length as usize,
@@ -2886,8 +4955,33 @@ macro_rules! sql_get_info_helper {
                         string_length_ptr,
                     )
                 }
+                // Each parameter set of a parameter array is executed as its own query, producing
+                // one result set per set.
+                InfoType::SQL_PARAM_ARRAY_SELECTS => {
+                    i16_len::set_output_fixed_data(&SQL_PAS_BATCH, info_value_ptr, string_length_ptr)
+                }
+                InfoType::SQL_PARAM_ARRAY_ROW_COUNTS => {
+                    i16_len::set_output_fixed_data(
+                        &SQL_PARC_NO_BATCH,
+                        info_value_ptr,
+                        string_length_ptr,
+                    )
+                }
+                InfoType::SQL_ASYNC_MODE => i16_len::set_output_fixed_data(
+                    &SQL_AM_STATEMENT,
+                    info_value_ptr,
+                    string_length_ptr,
+                ),
+                InfoType::SQL_MAX_ASYNC_CONCURRENT_STATEMENTS => {
+                    // The number of asynchronous statements on a connection is not limited.
+                    i16_len::set_output_fixed_data(
+                        &MAX_COLUMNS_U32_ZERO,
+                        info_value_ptr,
+                        string_length_ptr,
+                    )
+                }
                 InfoType::SQL_KEYWORDS => {
-                    i16_len::set_output_wstring_as_bytes(
+                    $set_output_string_as_bytes(
                         KEYWORDS.as_str(),
                         info_value_ptr,
                         buffer_length as usize,
@@ -2911,12 +5005,44 @@ macro_rules! sql_get_info_helper {
     };
 
     if let Some(error) = err {
-        add_diag_with_function!(conn_handle, error, "SQLGetInfoW");
+        add_diag_with_function!(conn_handle, error, function_name!());
     }
     sql_return
 }}
 }
 
+///
+/// [`SQLGetInfo`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetInfo-function
+///
+/// This is the ANSI version of the SQLGetInfo function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetInfo(
+    connection_handle: HDbc,
+    info_type: USmallInt,
+    info_value_ptr: Pointer,
+    buffer_length: SmallInt,
+    string_length_ptr: *mut SmallInt,
+) -> SqlReturn {
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || sql_get_info_helper!(
+            connection_handle,
+            info_type,
+            info_value_ptr,
+            buffer_length,
+            string_length_ptr,
+            i16_len::set_output_string,
+            i16_len::set_output_string_as_bytes,
+        ),
+        connection_handle
+    )
+}
+
 ///
 /// [`SQLGetInfoW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetInfo-function
 ///
@@ -2942,11 +5068,39 @@ pub unsafe extern "C" fn SQLGetInfoW(
             info_value_ptr,
             buffer_length,
             string_length_ptr,
+            i16_len::set_output_wstring,
+            i16_len::set_output_wstring_as_bytes,
         ),
         connection_handle
     )
 }
 
+///
+/// [`SQLGetStmtAttr`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetStmtAttr-function
+///
+/// This is the ANSI version of the SQLGetStmtAttr function. None of the statement
+/// attributes are strings, so it is the same as SQLGetStmtAttrW.
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetStmtAttr(
+    handle: HStmt,
+    attribute: Integer,
+    value_ptr: Pointer,
+    buffer_length: Integer,
+    string_length_ptr: *mut Integer,
+) -> SqlReturn {
+    SQLGetStmtAttrW(
+        handle,
+        attribute,
+        value_ptr,
+        buffer_length,
+        string_length_ptr,
+    )
+}
+
 ///
 /// [`SQLGetStmtAttrW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetStmtAttr-function
 ///
@@ -3043,7 +5197,7 @@ unsafe fn sql_get_stmt_attrw_helper(
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_ASYNC_ENABLE => {
-                *value_ptr.cast::<AsyncEnable>() = stmt.attributes.read().unwrap().async_enable;
+                *value_ptr.cast::<ULen>() = stmt.attributes.read().unwrap().async_enable as ULen;
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_CONCURRENCY => {
@@ -3111,12 +5265,12 @@ unsafe fn sql_get_stmt_attrw_helper(
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_ROW_BIND_OFFSET_PTR => {
-                *value_ptr.cast() = stmt.attributes.read().unwrap().row_bind_offset_ptr;
+                *value_ptr.cast() = stmt.app_row_desc().bind_offset_ptr;
                 ptr_safe_write(string_length_ptr, size_of::<*mut ULen>() as Integer);
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_ROW_BIND_TYPE => {
-                *value_ptr.cast::<ULen>() = stmt.attributes.read().unwrap().row_bind_type;
+                *value_ptr.cast::<ULen>() = stmt.app_row_desc().bind_type;
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_ROW_NUMBER => {
@@ -3139,7 +5293,7 @@ unsafe fn sql_get_stmt_attrw_helper(
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_ROW_ARRAY_SIZE | StatementAttribute::SQL_ROWSET_SIZE => {
-                *value_ptr.cast::<ULen>() = stmt.attributes.read().unwrap().row_array_size;
+                *value_ptr.cast::<ULen>() = stmt.app_row_desc().array_size;
                 SqlReturn::SUCCESS
             }
             StatementAttribute::SQL_ATTR_SIMULATE_CURSOR => {
@@ -3181,6 +5335,20 @@ unsafe fn sql_get_stmt_attrw_helper(
     sql_return
 }
 
+///
+/// [`SQLGetTypeInfo`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetTypeInfo-function
+///
+/// This is the ANSI version of the SQLGetTypeInfo function. It has no string
+/// arguments, so it is the same as SQLGetTypeInfoW.
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+pub unsafe extern "C" fn SQLGetTypeInfo(handle: HStmt, data_type: SmallInt) -> SqlReturn {
+    SQLGetTypeInfoW(handle, data_type)
+}
+
 ///
 /// [`SQLGetTypeInfoW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLGetTypeInfo-function
 ///
@@ -3194,45 +5362,159 @@ pub unsafe extern "C" fn SQLGetTypeInfoW(handle: HStmt, data_type: SmallInt) ->
         debug,
         || {
             let mongo_handle = try_mongo_handle!(handle);
-            let odbc_version = mongo_handle.get_odbc_version();
-            match FromPrimitive::from_i16(data_type) {
-                Some(sql_data_type) => {
-                    let sql_data_type = handle_sql_type(odbc_version, sql_data_type);
-                    let stmt = must_be_valid!((*mongo_handle).as_statement());
-                    let type_mode = if stmt.connection.is_null() {
-                        TypeMode::Standard
-                    } else {
-                        let connection = must_be_valid!((*stmt.connection).as_connection());
-                        *connection.type_mode.read().unwrap()
-                    };
-                    let types_info = MongoTypesInfo::new(sql_data_type, type_mode);
-                    *stmt.mongo_statement.write().unwrap() = Some(Box::new(types_info));
-                    SqlReturn::SUCCESS
-                }
-                None => {
-                    add_diag_info!(
-                        mongo_handle,
-                        ODBCError::InvalidSqlType(data_type.to_string())
-                    );
-                    SqlReturn::ERROR
-                }
+            if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                return sql_return;
             }
+            exec_async(mongo_handle, function_name!(), move |handle| {
+                let mongo_handle = try_mongo_handle!(handle);
+                let odbc_version = mongo_handle.get_odbc_version();
+                match FromPrimitive::from_i16(data_type) {
+                    Some(sql_data_type) => {
+                        let sql_data_type = handle_sql_type(odbc_version, sql_data_type);
+                        let stmt = must_be_valid!((*mongo_handle).as_statement());
+                        let type_mode = if stmt.connection.is_null() {
+                            TypeMode::Standard
+                        } else {
+                            let connection = must_be_valid!((*stmt.connection).as_connection());
+                            *connection.type_mode.read().unwrap()
+                        };
+                        let types_info = MongoTypesInfo::new(sql_data_type, type_mode);
+                        set_catalog_result_set(stmt, Box::new(types_info));
+                        SqlReturn::SUCCESS
+                    }
+                    None => {
+                        add_diag_info!(
+                            mongo_handle,
+                            ODBCError::InvalidSqlType(data_type.to_string())
+                        );
+                        SqlReturn::ERROR
+                    }
+                }
+            })
         },
         handle
     )
 }
 
 ///
-/// [`SQLMoreResults`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLMoreResults-function
+/// [`SQLMoreResults`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLMoreResults-function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLMoreResults(handle: HStmt) -> SqlReturn {
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(handle);
+            if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                return sql_return;
+            }
+            exec_async(mongo_handle, function_name!(), |statement_handle| {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                let stmt = must_be_valid!(mongo_handle.as_statement());
+                // Close the current result set and execute the next one, if any.
+                sql_stmt_close_cursor_helper(stmt);
+                *stmt.var_data_cache.write().unwrap() = None;
+                if stmt.more_results.read().unwrap().is_empty() {
+                    return SqlReturn::NO_DATA;
+                }
+                let connection = must_be_valid!((*stmt.connection).as_connection());
+                sql_execute_next_result(stmt, connection)
+            })
+        },
+        handle
+    );
+}
+
+macro_rules! sql_native_sql_impl {
+    (
+        $connection_handle:ident,
+        $in_statement_text:ident,
+        $in_statement_len:ident,
+        $out_statement_text:ident,
+        $buffer_len:ident,
+        $out_statement_len:ident,
+        $input_text:path,
+        $set_output_string:path,
+        $output_len:expr,
+    ) => {{
+        let connection_handle = $connection_handle;
+        let in_statement_text = $in_statement_text;
+        let in_statement_len = $in_statement_len;
+        let out_statement_text = $out_statement_text;
+        let buffer_len = $buffer_len;
+        let out_statement_len = $out_statement_len;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let conn_handle = try_mongo_handle!(connection_handle);
+                let _ = must_be_valid!((*conn_handle).as_connection());
+                let query = $input_text(
+                    in_statement_text,
+                    in_statement_len
+                        .try_into()
+                        .expect("i32 exceeded max isize on this platform"),
+                );
+                // The native SQL is the query the driver sends, with its escape sequences rewritten.
+                let native_sql = odbc_unwrap!(rewrite_escape_sequences(&query), conn_handle);
+                // Without an output buffer, the application is only asking for the length.
+                if out_statement_text.is_null() {
+                    let len: usize = $output_len(&native_sql);
+                    ptr_safe_write(
+                        out_statement_len,
+                        len.try_into().expect("Data too large to fit"),
+                    );
+                    return SqlReturn::SUCCESS;
+                }
+                let buffer_len = usize::try_from(buffer_len).unwrap_or(0);
+                let sql_return = $set_output_string(
+                    &native_sql,
+                    out_statement_text,
+                    buffer_len,
+                    out_statement_len,
+                );
+                if sql_return == SqlReturn::SUCCESS_WITH_INFO {
+                    add_diag_info!(conn_handle, ODBCError::OutStringTruncated(buffer_len));
+                }
+                sql_return
+            },
+            connection_handle
+        );
+    }};
+}
+
+///
+/// [`SQLNativeSql`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLNativeSql-function
+///
+/// This is the ANSI version of the SQLNativeSql function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
+#[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLMoreResults(_handle: HStmt) -> SqlReturn {
-    // For now, we never allow more than one result from a query (i.e., we only support one query
-    // at a time).
-    SqlReturn::NO_DATA
+pub unsafe extern "C" fn SQLNativeSql(
+    connection_handle: HDbc,
+    in_statement_text: *const Char,
+    in_statement_len: Integer,
+    out_statement_text: *mut Char,
+    buffer_len: Integer,
+    out_statement_len: *mut Integer,
+) -> SqlReturn {
+    sql_native_sql_impl!(
+        connection_handle,
+        in_statement_text,
+        in_statement_len,
+        out_statement_text,
+        buffer_len,
+        out_statement_len,
+        input_text_to_string_a,
+        i32_len::set_output_string,
+        |sql: &str| sql.len(),
+    )
 }
 
 ///
@@ -3247,13 +5529,23 @@ pub unsafe extern "C" fn SQLMoreResults(_handle: HStmt) -> SqlReturn {
 #[no_mangle]
 pub unsafe extern "C" fn SQLNativeSqlW(
     connection_handle: HDbc,
-    _in_statement_text: *const WideChar,
-    _in_statement_len: Integer,
-    _out_statement_text: *mut WideChar,
-    _buffer_len: Integer,
-    _out_statement_len: *mut Integer,
+    in_statement_text: *const WideChar,
+    in_statement_len: Integer,
+    out_statement_text: *mut WideChar,
+    buffer_len: Integer,
+    out_statement_len: *mut Integer,
 ) -> SqlReturn {
-    unimpl!(connection_handle);
+    sql_native_sql_impl!(
+        connection_handle,
+        in_statement_text,
+        in_statement_len,
+        out_statement_text,
+        buffer_len,
+        out_statement_len,
+        input_text_to_string_w,
+        i32_len::set_output_wstring,
+        |sql: &str| cstr::to_widechar_vec(sql).len(),
+    )
 }
 
 ///
@@ -3266,9 +5558,41 @@ pub unsafe extern "C" fn SQLNativeSqlW(
 #[named]
 pub unsafe extern "C" fn SQLNumParams(
     statement_handle: HStmt,
-    _param_count_ptr: *mut SmallInt,
+    param_count_ptr: *mut SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(statement_handle)
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(statement_handle);
+            let stmt = must_be_valid!((*mongo_handle).as_statement());
+
+            if stmt.mongo_statement.read().unwrap().is_none()
+                && stmt.parameterized_query.read().unwrap().is_none()
+            {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                add_diag_info!(
+                    mongo_handle,
+                    ODBCError::FunctionSequenceError("the statement has not been prepared")
+                );
+                return SqlReturn::ERROR;
+            }
+
+            let param_count = stmt
+                .parameterized_query
+                .read()
+                .unwrap()
+                .as_deref()
+                .map_or(0, count_parameter_markers);
+            let param_count = odbc_unwrap!(
+                SmallInt::try_from(param_count)
+                    .map_err(|_| ODBCError::General("parameter count exceeded i16 maximum")),
+                mongo_handle
+            );
+            ptr_safe_write(param_count_ptr, param_count);
+            SqlReturn::SUCCESS
+        },
+        statement_handle
+    );
 }
 
 ///
@@ -3318,8 +5642,114 @@ pub unsafe extern "C" fn SQLNumResultCols(
 ///
 #[no_mangle]
 #[named]
-pub unsafe extern "C" fn SQLParamData(hstmt: HStmt, _value_ptr_ptr: *mut Pointer) -> SqlReturn {
-    unsupported_function!(hstmt)
+pub unsafe extern "C" fn SQLParamData(hstmt: HStmt, value_ptr_ptr: *mut Pointer) -> SqlReturn {
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(hstmt);
+            let stmt = must_be_valid!((*mongo_handle).as_statement());
+            let connection = must_be_valid!((*stmt.connection).as_connection());
+
+            match *stmt.state.read().unwrap() {
+                StatementState::FunctionNeedsDataNoParam
+                | StatementState::FunctionNeedsDataPutCalled => {}
+                _ => {
+                    let mongo_handle = try_mongo_handle!(hstmt);
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::FunctionSequenceError("no data is needed")
+                    );
+                    return SqlReturn::ERROR;
+                }
+            }
+
+            // Request the next data-at-execution parameter, identified to the application by
+            // the value pointer it bound the parameter with.
+            let next_param = stmt.data_at_exec.write().unwrap().as_mut().and_then(|dae| {
+                dae.current = dae.pending.pop_front();
+                dae.current
+            });
+            if let Some(param_number) = next_param {
+                let value_ptr = stmt
+                    .bound_params
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .and_then(|params| params.get(&param_number))
+                    .map_or(null_mut(), |param| param.parameter_value_ptr);
+                ptr_safe_write(value_ptr_ptr, value_ptr);
+                *stmt.state.write().unwrap() = StatementState::FunctionNeedsDataNoPut;
+                return SqlReturn::NEED_DATA;
+            }
+
+            // All the data has been sent, so the statement can finally be executed.
+            *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
+            let result = sql_execute_data_at_exec(stmt, connection);
+            *stmt.data_at_exec.write().unwrap() = None;
+            *stmt.state.write().unwrap() = StatementState::Allocated;
+            odbc_unwrap!(result, mongo_handle);
+            SqlReturn::SUCCESS
+        },
+        hstmt
+    );
+}
+
+macro_rules! sql_prepare_impl {
+    (
+        $statement_handle:ident,
+        $statement_text:ident,
+        $text_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let statement_text = $statement_text;
+        let text_length = $text_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                let stmt = must_be_valid!(mongo_handle.as_statement());
+                let connection = must_be_valid!((*stmt.connection).as_connection());
+                let query = sql_statement_text(statement_text, text_length, $input_text);
+                *stmt.prepared_query.write().unwrap() = None;
+                // Parameter values may not be bound yet, so a query with parameter markers is only
+                // prepared when it is first executed, and has no result set metadata until then.
+                if sql_record_parameterized_query(stmt, &query) > 0 {
+                    *stmt.mongo_statement.write().unwrap() = None;
+                    return SqlReturn::SUCCESS;
+                }
+                let mongo_statement =
+                    odbc_unwrap!(sql_prepare(stmt, &query, connection), mongo_handle);
+
+                *stmt.mongo_statement.write().unwrap() = Some(mongo_statement);
+                SqlReturn::SUCCESS
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLPrepare`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLPrepare-function
+///
+/// This is the ANSI version of the SQLPrepare function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLPrepare(
+    statement_handle: HStmt,
+    statement_text: *const Char,
+    text_length: Integer,
+) -> SqlReturn {
+    sql_prepare_impl!(
+        statement_handle,
+        statement_text,
+        text_length,
+        input_text_to_string_a,
+    )
 }
 
 ///
@@ -3330,99 +5760,524 @@ pub unsafe extern "C" fn SQLParamData(hstmt: HStmt, _value_ptr_ptr: *mut Pointer
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
-#[no_mangle]
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLPrepareW(
+    statement_handle: HStmt,
+    statement_text: *const WideChar,
+    text_length: Integer,
+) -> SqlReturn {
+    sql_prepare_impl!(
+        statement_handle,
+        statement_text,
+        text_length,
+        input_text_to_string_w,
+    )
+}
+
+fn sql_statement_text<C>(
+    statement_text: *const C,
+    text_length: Integer,
+    input_text: unsafe fn(*const C, isize) -> String,
+) -> String {
+    let query = unsafe {
+        input_text(
+            statement_text,
+            text_length
+                .try_into()
+                .expect("i32 exceeded max isize on this platform"),
+        )
+    };
+    query.strip_suffix(';').unwrap_or(&query).to_string()
+}
+
+///
+/// sql_record_parameterized_query records whether `query` contains parameter markers on the
+/// statement, and returns the number of markers.
+///
+fn sql_record_parameterized_query(stmt: &Statement, query: &str) -> usize {
+    let param_count = count_parameter_markers(query);
+    *stmt.parameterized_query.write().unwrap() = (param_count > 0).then(|| query.to_string());
+    param_count
+}
+
+///
+/// sql_bind_parameters records whether `query` contains parameter markers on the statement and
+/// returns the query with every marker replaced by the value bound with SQLBindParameter.
+///
+unsafe fn sql_bind_parameters(stmt: &Statement, query: &str) -> Result<String> {
+    let param_count = sql_record_parameterized_query(stmt, query);
+    if param_count == 0 {
+        return Ok(query.to_string());
+    }
+    let values = sql_parameter_values(stmt, param_count, 0)?;
+    substitute_parameters(query, &values).map_err(|e| e.into())
+}
+
+///
+/// sql_parameter_values reads the values of the first `param_count` parameters for one parameter
+/// set. Data sent with SQLPutData takes the place of the bound buffer of a data-at-execution
+/// parameter.
+///
+unsafe fn sql_parameter_values(
+    stmt: &Statement,
+    param_count: usize,
+    set: usize,
+) -> Result<Vec<Bson>> {
+    let (bind_type, bind_offset_ptr) = {
+        let attributes = stmt.attributes.read().unwrap();
+        (attributes.param_bind_type, attributes.param_bind_offset_ptr)
+    };
+    let bound_params = stmt.bound_params.read().unwrap();
+    let data_at_exec = stmt.data_at_exec.read().unwrap();
+    (1..=param_count)
+        .map(|param_number| {
+            let param_number = USmallInt::try_from(param_number)
+                .map_err(|_| ODBCError::General("parameter count exceeded u16 maximum"))?;
+            let param = bound_params
+                .as_ref()
+                .and_then(|params| params.get(&param_number))
+                .ok_or(ODBCError::UnboundParameter(param_number))?;
+            if let Some(data) = data_at_exec
+                .as_ref()
+                .and_then(|dae| dae.values.get(&param_number))
+            {
+                return data_at_exec_value_to_bson(param, data.as_deref());
+            }
+            let param = param_set_binding(param, set, bind_type, bind_offset_ptr)?;
+            // Data-at-execution parameters are only requested for a single parameter set.
+            if is_data_at_exec(&param) {
+                return Err(ODBCError::Unimplemented(
+                    "`data-at-execution parameters in parameter arrays`",
+                ));
+            }
+            param_value_to_bson(&param)
+        })
+        .collect()
+}
+
+///
+/// sql_paramset_size returns the number of parameter sets `query` is executed with. A query
+/// without parameter markers is always executed once.
+///
+fn sql_paramset_size(stmt: &Statement, query: &str) -> usize {
+    if count_parameter_markers(query) == 0 {
+        1
+    } else {
+        stmt.attributes.read().unwrap().paramset_size
+    }
+}
+
+///
+/// sql_execute_param_array executes `query` with the first parameter set of the bound parameter
+/// arrays, and SQLMoreResults executes the other sets in order. The values of every set are read
+/// now, as the application may reuse its buffers once the execution returns. Until a set is
+/// executed, its status in the parameter status array is SQL_PARAM_UNUSED.
+///
+unsafe fn sql_execute_param_array(
+    stmt: &Statement,
+    connection: &Connection,
+    query: &str,
+) -> SqlReturn {
+    let (paramset_size, status_ptr, processed_ptr) = {
+        let attributes = stmt.attributes.read().unwrap();
+        (
+            attributes.paramset_size,
+            attributes.param_status_ptr,
+            attributes.param_processed_ptr,
+        )
+    };
+    *stmt.parameterized_query.write().unwrap() = Some(query.to_string());
+    let param_count = count_parameter_markers(query);
+
+    let pending = (0..paramset_size)
+        .map(|set| {
+            if !status_ptr.is_null() {
+                *status_ptr.add(set) = ParamStatus::SQL_PARAM_UNUSED as USmallInt;
+            }
+            let query = sql_parameter_values(stmt, param_count, set)
+                .and_then(|values| substitute_parameters(query, &values).map_err(|e| e.into()));
+            PendingResult::ParamSet(set, query)
+        })
+        .collect();
+    *stmt.more_results.write().unwrap() = pending;
+    ptr_safe_write(processed_ptr, 0);
+    sql_execute_next_result(stmt, connection)
+}
+
+///
+/// sql_execute_batch executes the first statement of a `;` separated batch of queries, and
+/// SQLMoreResults executes the other statements in order.
+///
+unsafe fn sql_execute_batch(stmt: &Statement, connection: &Connection, batch: &str) -> SqlReturn {
+    *stmt.more_results.write().unwrap() = split_statements(batch)
+        .into_iter()
+        .map(|query| PendingResult::BatchStatement(query.to_string()))
+        .collect();
+    sql_execute_next_result(stmt, connection)
+}
+
+///
+/// sql_execute_next_result executes the pending results of the statement, in order, until one
+/// succeeds and becomes the statement's result set. A parameter set that fails has its status set
+/// to SQL_PARAM_ERROR and its error reported with its set number, and the next set is executed. A
+/// statement of a batch that fails ends the batch. NO_DATA is returned when nothing is pending.
+///
+unsafe fn sql_execute_next_result(stmt: &Statement, connection: &Connection) -> SqlReturn {
+    // set the statment state to executing so SQLCancel knows to search the op log for hanging queries
+    *stmt.state.write().unwrap() = StatementState::SynchronousQueryExecuting;
+    let mut errors = vec![];
+    let sql_return = loop {
+        let next_result = stmt.more_results.write().unwrap().pop_front();
+        let result = match next_result {
+            Some(PendingResult::ParamSet(set, query)) => {
+                let result =
+                    query.and_then(|query| sql_execute_param_set(stmt, connection, &query));
+                let (status_ptr, processed_ptr) = {
+                    let attributes = stmt.attributes.read().unwrap();
+                    (attributes.param_status_ptr, attributes.param_processed_ptr)
+                };
+                if !status_ptr.is_null() {
+                    *status_ptr.add(set) = match result {
+                        Ok(_) => ParamStatus::SQL_PARAM_SUCCESS,
+                        Err(_) => ParamStatus::SQL_PARAM_ERROR,
+                    } as USmallInt;
+                }
+                ptr_safe_write(processed_ptr, set + 1);
+                result.map_err(|e| ODBCError::ParameterSetFailed(set + 1, Box::new(e)))
+            }
+            Some(PendingResult::BatchStatement(query)) => {
+                let result =
+                    sql_prepare(stmt, &query, connection).and_then(|mut mongo_statement| {
+                        sql_execute_statement(stmt, connection, mongo_statement.as_mut())?;
+                        Ok(mongo_statement)
+                    });
+                if result.is_err() {
+                    stmt.more_results.write().unwrap().clear();
+                }
+                result
+            }
+            None if errors.is_empty() => break SqlReturn::NO_DATA,
+            None => {
+                *stmt.mongo_statement.write().unwrap() = None;
+                break SqlReturn::ERROR;
+            }
+        };
+        match result {
+            Ok(mongo_statement) => {
+                *stmt.mongo_statement.write().unwrap() = Some(mongo_statement);
+                break if errors.is_empty() {
+                    SqlReturn::SUCCESS
+                } else {
+                    SqlReturn::SUCCESS_WITH_INFO
+                };
+            }
+            Err(e) => errors.push(e),
+        }
+    };
+    stmt.errors.write().unwrap().extend(errors);
+    // return the statement state to its original value
+    *stmt.state.write().unwrap() = StatementState::Allocated;
+    sql_return
+}
+
+///
+/// sql_execute_param_set executes the query of one parameter set.
+///
+unsafe fn sql_execute_param_set(
+    stmt: &Statement,
+    connection: &Connection,
+    query: &str,
+) -> Result<Box<dyn MongoStatement>> {
+    let mongo_query = sql_bind_prepared_query(stmt, query, connection)?;
+    let mut mongo_statement = sql_cursor(stmt, connection, mongo_query);
+    sql_execute_statement(stmt, connection, mongo_statement.as_mut())?;
+    Ok(mongo_statement)
+}
+
+///
+/// sql_needs_data checks whether any parameter of `query` is a data-at-execution parameter. If so,
+/// the statement is put in the need data state and the execution is deferred until SQLParamData
+/// has collected the values of all such parameters.
+///
+unsafe fn sql_needs_data(stmt: &Statement, query: &str) -> bool {
+    // Parameter arrays are executed without data-at-execution parameters.
+    if sql_paramset_size(stmt, query) > 1 {
+        return false;
+    }
+    let param_count = count_parameter_markers(query);
+    let (bind_type, bind_offset_ptr) = {
+        let attributes = stmt.attributes.read().unwrap();
+        (attributes.param_bind_type, attributes.param_bind_offset_ptr)
+    };
+    let pending: VecDeque<USmallInt> = match stmt.bound_params.read().unwrap().as_ref() {
+        Some(bound_params) => (1..=param_count)
+            .filter_map(|param_number| USmallInt::try_from(param_number).ok())
+            .filter(|param_number| {
+                bound_params.get(param_number).is_some_and(|param| {
+                    param_set_binding(param, 0, bind_type, bind_offset_ptr)
+                        .is_ok_and(|param| is_data_at_exec(&param))
+                })
+            })
+            .collect(),
+        None => VecDeque::new(),
+    };
+    if pending.is_empty() {
+        return false;
+    }
+    *stmt.parameterized_query.write().unwrap() = Some(query.to_string());
+    *stmt.data_at_exec.write().unwrap() = Some(DataAtExecParams {
+        pending,
+        ..Default::default()
+    });
+    *stmt.state.write().unwrap() = StatementState::FunctionNeedsDataNoParam;
+    true
+}
+
+///
+/// sql_execute_data_at_exec prepares and executes a query deferred by sql_needs_data, now that
+/// every data-at-execution parameter has a value.
+///
+unsafe fn sql_execute_data_at_exec(stmt: &Statement, connection: &Connection) -> Result<bool> {
+    let query = stmt
+        .parameterized_query
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_default();
+    let query = sql_bind_parameters(stmt, &query)?;
+    let mongo_query = sql_bind_prepared_query(stmt, &query, connection)?;
+    *stmt.mongo_statement.write().unwrap() = Some(sql_cursor(stmt, connection, mongo_query));
+    sql_execute(stmt, connection)
+}
+
+///
+/// sql_prepare prepares `query` for execution. When the statement uses a static cursor, the
+/// result set is read through a MongoStaticCursor so it can be scrolled.
+///
+fn sql_prepare(
+    stmt: &Statement,
+    query: &str,
+    connection: &Connection,
+) -> Result<Box<dyn MongoStatement>> {
+    let mongo_query = sql_prepare_query(stmt, query, connection)?;
+    Ok(sql_cursor(stmt, connection, mongo_query))
+}
+
+///
+/// sql_bind_prepared_query returns the MongoQuery for `query`, the parameterized query of the
+/// statement with the values of one execution in place of its markers. The first execution
+/// prepares it, and later ones are bound to the query prepared then, so the result set metadata
+/// is only fetched once.
+///
+fn sql_bind_prepared_query(
+    stmt: &Statement,
+    query: &str,
+    connection: &Connection,
+) -> Result<MongoQuery> {
+    let mut prepared_query = stmt.prepared_query.write().unwrap();
+    match prepared_query.as_ref() {
+        Some(prepared) => Ok(prepared.rebind(&sql_rewrite_escapes(stmt, query)?)?),
+        None => {
+            let prepared = sql_prepare_query(stmt, query, connection)?;
+            let mongo_query = prepared.unexecuted_copy();
+            *prepared_query = Some(prepared);
+            Ok(mongo_query)
+        }
+    }
+}
+
+///
+/// sql_prepare_query prepares `query` as a MongoQuery. Its escape sequences are rewritten to
+/// MongoSQL unless SQL_ATTR_NOSCAN is on.
+///
+fn sql_prepare_query(stmt: &Statement, query: &str, connection: &Connection) -> Result<MongoQuery> {
+    let query = &sql_rewrite_escapes(stmt, query)?;
+    let type_mode = *connection.type_mode.read().unwrap();
+    let max_string_length = *connection.max_string_length.read().unwrap();
+    let attributes = connection.attributes.read().unwrap();
+    let timeout = attributes.connection_timeout;
+    let current_db = attributes.current_catalog.as_ref().cloned();
+    if let Some(mongo_connection) = connection.mongo_connection.read().unwrap().as_ref() {
+        MongoQuery::prepare(
+            mongo_connection,
+            current_db,
+            timeout,
+            query,
+            type_mode,
+            max_string_length,
+        )
+        .map_err(|e| e.into())
+    } else {
+        Err(ODBCError::InvalidCursorState)
+    }
+}
+
+fn sql_rewrite_escapes(stmt: &Statement, query: &str) -> Result<String> {
+    Ok(match stmt.attributes.read().unwrap().no_scan {
+        NoScan::SQL_NOSCAN_OFF => rewrite_escape_sequences(query)?,
+        NoScan::SQL_NOSCAN_ON => query.to_string(),
+    })
+}
+
+///
+/// sql_cursor returns the MongoStatement the result set of `mongo_query` is read through, which
+/// depends on the cursor type of the statement.
+///
+fn sql_cursor(
+    stmt: &Statement,
+    connection: &Connection,
+    mongo_query: MongoQuery,
+) -> Box<dyn MongoStatement> {
+    let mongo_statement: Box<dyn MongoStatement> = Box::new(mongo_query);
+    if matches!(
+        stmt.attributes.read().unwrap().cursor_type,
+        CursorType::SQL_CURSOR_STATIC
+    ) {
+        return Box::new(MongoStaticCursor::new(
+            mongo_statement,
+            *connection.max_string_length.read().unwrap(),
+            *connection.static_cursor_spill_threshold.read().unwrap(),
+        ));
+    }
+    mongo_statement
+}
+
+macro_rules! sql_primary_keys_impl {
+    (
+        $statement_handle:ident,
+        $catalog_name:ident,
+        $catalog_name_length:ident,
+        $table_name:ident,
+        $table_name_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let catalog_name = $catalog_name;
+        let catalog_name_length = $catalog_name_length;
+        let table_name = $table_name;
+        let table_name_length = $table_name_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let catalog = $input_text(catalog_name, catalog_name_length.into());
+                // ignore schema
+                let table = $input_text(table_name, table_name_length.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    let catalog = catalog_or_current_catalog(connection, catalog);
+                    let mongo_statement = match catalog.is_empty() {
+                        true => MongoPrimaryKeys::empty(),
+                        false => odbc_unwrap!(
+                            MongoPrimaryKeys::list_primary_keys(
+                                connection
+                                    .mongo_connection
+                                    .read()
+                                    .unwrap()
+                                    .as_ref()
+                                    .unwrap(),
+                                &catalog,
+                                &table,
+                                stmt.attributes.read().unwrap().max_rows as u64,
+                            ),
+                            mongo_handle
+                        ),
+                    };
+                    set_catalog_result_set(stmt, Box::new(mongo_statement));
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLPrimaryKeys`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLPrimaryKeys-function
+///
+/// This is the ANSI version of the SQLPrimaryKeys function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLPrimaryKeys(
+    statement_handle: HStmt,
+    catalog_name: *const Char,
+    catalog_name_length: SmallInt,
+    _schema_name: *const Char,
+    _schema_name_length: SmallInt,
+    table_name: *const Char,
+    table_name_length: SmallInt,
+) -> SqlReturn {
+    sql_primary_keys_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        input_text_to_string_a_allow_null,
+    )
+}
+
+///
+/// [`SQLPrimaryKeysW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLPrimaryKeys-function
+///
+/// This is the WideChar version of the SQLPrimaryKeys function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
 #[named]
-pub unsafe extern "C" fn SQLPrepareW(
+#[no_mangle]
+pub unsafe extern "C" fn SQLPrimaryKeysW(
     statement_handle: HStmt,
-    statement_text: *const WideChar,
-    text_length: Integer,
+    catalog_name: *const WideChar,
+    catalog_name_length: SmallInt,
+    _schema_name: *const WideChar,
+    _schema_name_length: SmallInt,
+    table_name: *const WideChar,
+    table_name_length: SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let stmt = must_be_valid!(mongo_handle.as_statement());
-            let connection = must_be_valid!((*stmt.connection).as_connection());
-            let mongo_statement = odbc_unwrap!(
-                sql_prepare(statement_text, text_length, connection,),
-                mongo_handle
-            );
-
-            *stmt.mongo_statement.write().unwrap() = Some(Box::new(mongo_statement));
-            SqlReturn::SUCCESS
-        },
-        statement_handle
-    );
-}
-
-fn sql_prepare(
-    statement_text: *const WideChar,
-    text_length: Integer,
-    connection: &Connection,
-) -> Result<MongoQuery> {
-    let mut query = unsafe {
-        input_text_to_string_w(
-            statement_text,
-            text_length
-                .try_into()
-                .expect("i32 exceeded max isize on this platform"),
-        )
-    };
-    query = query.strip_suffix(';').unwrap_or(&query).to_string();
-    let mongo_statement = {
-        let type_mode = *connection.type_mode.read().unwrap();
-        let max_string_length = *connection.max_string_length.read().unwrap();
-        let attributes = connection.attributes.read().unwrap();
-        let timeout = attributes.connection_timeout;
-        let current_db = attributes.current_catalog.as_ref().cloned();
-        if let Some(mongo_connection) = connection.mongo_connection.read().unwrap().as_ref() {
-            MongoQuery::prepare(
-                mongo_connection,
-                current_db,
-                timeout,
-                &query,
-                type_mode,
-                max_string_length,
-            )
-            .map_err(|e| e.into())
-        } else {
-            Err(ODBCError::InvalidCursorState)
-        }
-    };
-    mongo_statement
+    sql_primary_keys_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        input_text_to_string_w_allow_null,
+    )
 }
 
 ///
-/// [`SQLPrimaryKeysW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLPrimaryKeys-function
+/// [`SQLProcedureColumns`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLProcedureColumns-function
 ///
-/// This is the WideChar version of the SQLPrimaryKeys function
+/// This is the ANSI version of the SQLProcedureColumns function
 ///
 /// # Safety
 /// Because this is a C-interface, this is necessarily unsafe
 ///
-#[named]
 #[no_mangle]
-pub unsafe extern "C" fn SQLPrimaryKeysW(
+#[named]
+pub unsafe extern "C" fn SQLProcedureColumns(
     statement_handle: HStmt,
-    _catalog_name: *const WideChar,
+    _catalog_name: *const Char,
     _catalog_name_length: SmallInt,
-    _schema_name: *const WideChar,
+    _schema_name: *const Char,
     _schema_name_length: SmallInt,
-    _table_name: *const WideChar,
-    _table_name_length: SmallInt,
+    _proc_name: *const Char,
+    _proc_name_length: SmallInt,
+    _column_name: *const Char,
+    _column_name_length: SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let stmt = must_be_valid!((*mongo_handle).as_statement());
-            let max_string_length = stmt.get_max_string_length();
-            let mongo_statement = MongoPrimaryKeys::empty(max_string_length);
-            *stmt.mongo_statement.write().unwrap() = Some(Box::new(mongo_statement));
-            SqlReturn::SUCCESS
-        },
-        statement_handle
-    );
+    unsupported_function!(statement_handle)
 }
 
 ///
@@ -3449,6 +6304,28 @@ pub unsafe extern "C" fn SQLProcedureColumnsW(
     unsupported_function!(statement_handle)
 }
 
+///
+/// [`SQLProcedures`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLProcedures-function
+///
+/// This is the ANSI version of the SQLProcedures function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLProcedures(
+    statement_handle: HStmt,
+    _catalog_name: *const Char,
+    _catalog_name_length: SmallInt,
+    _schema_name: *const Char,
+    _schema_name_length: SmallInt,
+    _proc_name: *const Char,
+    _proc_name_length: SmallInt,
+) -> SqlReturn {
+    unsupported_function!(statement_handle)
+}
+
 ///
 /// [`SQLProceduresW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLProcedures-function
 ///
@@ -3481,10 +6358,78 @@ pub unsafe extern "C" fn SQLProceduresW(
 #[named]
 pub unsafe extern "C" fn SQLPutData(
     statement_handle: HStmt,
-    _data_ptr: Pointer,
-    _str_len_or_ind_ptr: Len,
+    data_ptr: Pointer,
+    str_len_or_ind_ptr: Len,
 ) -> SqlReturn {
-    unsupported_function!(statement_handle)
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(statement_handle);
+            let stmt = must_be_valid!((*mongo_handle).as_statement());
+
+            let first_piece = match *stmt.state.read().unwrap() {
+                StatementState::FunctionNeedsDataNoPut => true,
+                StatementState::FunctionNeedsDataPutCalled => false,
+                _ => {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::FunctionSequenceError(
+                            "SQLParamData has not requested a parameter"
+                        )
+                    );
+                    return SqlReturn::ERROR;
+                }
+            };
+
+            let mut data_at_exec = stmt.data_at_exec.write().unwrap();
+            let Some(dae) = data_at_exec.as_mut() else {
+                unreachable!("data_at_exec is set whenever the statement needs data")
+            };
+            let Some(param_number) = dae.current else {
+                unreachable!("SQLParamData sets the current parameter before SQLPutData")
+            };
+            let Some(param) = stmt
+                .bound_params
+                .read()
+                .unwrap()
+                .as_ref()
+                .and_then(|params| params.get(&param_number))
+                .copied()
+            else {
+                drop(data_at_exec);
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                add_diag_info!(mongo_handle, ODBCError::UnboundParameter(param_number));
+                return SqlReturn::ERROR;
+            };
+
+            let piece =
+                match put_data_bytes(&param, data_ptr.cast(), str_len_or_ind_ptr, first_piece) {
+                    Ok(piece) => piece,
+                    Err(e) => {
+                        drop(data_at_exec);
+                        let mongo_handle = try_mongo_handle!(statement_handle);
+                        add_diag_info!(mongo_handle, e);
+                        return SqlReturn::ERROR;
+                    }
+                };
+            // Pieces are appended to the data already sent. Sending SQL_NULL_DATA makes the
+            // parameter null regardless of what was sent before.
+            match (
+                dae.values.entry(param_number).or_insert(Some(vec![])),
+                piece,
+            ) {
+                (value, None) => *value = None,
+                (Some(data), Some(piece)) => data.extend(piece),
+                (value @ None, Some(piece)) => *value = Some(piece),
+            }
+            drop(data_at_exec);
+
+            *stmt.state.write().unwrap() = StatementState::FunctionNeedsDataPutCalled;
+            SqlReturn::SUCCESS
+        },
+        statement_handle
+    );
 }
 
 ///
@@ -3503,16 +6448,84 @@ pub unsafe extern "C" fn SQLRowCount(
         debug,
         || {
             let mongo_handle = try_mongo_handle!(statement_handle);
-            // even though we always return 0, we must still assert that the proper handle
-            // type is sent by the client.
-            let _ = must_be_valid!((*mongo_handle).as_statement());
-            *row_count_ptr = 0 as Len;
+            let stmt = must_be_valid!((*mongo_handle).as_statement());
+            let connection = must_be_valid!((*stmt.connection).as_connection());
+            // Unless the accurate_row_count DSN key is set, we always return 0. Otherwise, the
+            // row count is known once the result set was read entirely, and -1 until then.
+            *row_count_ptr = if *connection.accurate_row_count.read().unwrap() {
+                stmt.mongo_statement
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .and_then(|mongo_statement| mongo_statement.get_row_count())
+                    .map_or(-1, |row_count| Len::try_from(row_count).unwrap_or(Len::MAX))
+            } else {
+                0 as Len
+            };
             SqlReturn::SUCCESS
         },
         statement_handle
     );
 }
 
+macro_rules! sql_set_connect_attr_impl {
+    (
+        $connection_handle:ident,
+        $attribute:ident,
+        $value_ptr:ident,
+        $input_text:path,
+    ) => {{
+        let connection_handle = $connection_handle;
+        let attribute = $attribute;
+        let value_ptr = $value_ptr;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let conn_handle = try_mongo_handle!(connection_handle);
+
+                match FromPrimitive::from_i32(attribute) {
+                    Some(valid_attr) => set_connect_attr_helper(
+                        conn_handle,
+                        valid_attr,
+                        value_ptr,
+                        $input_text,
+                        function_name!(),
+                    ),
+                    None => {
+                        add_diag_info!(conn_handle, ODBCError::InvalidAttrIdentifier(attribute));
+                        SqlReturn::ERROR
+                    }
+                }
+            },
+            connection_handle
+        )
+    }};
+}
+
+///
+/// [`SQLSetConnectAttr`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetConnectAttr-function
+///
+/// This is the ANSI version of the SQLSetConnectAttr function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLSetConnectAttr(
+    connection_handle: HDbc,
+    attribute: Integer,
+    value_ptr: Pointer,
+    _str_length: Integer,
+) -> SqlReturn {
+    sql_set_connect_attr_impl!(
+        connection_handle,
+        attribute,
+        value_ptr,
+        input_text_to_string_a,
+    )
+}
+
 ///
 /// [`SQLSetConnectAttrW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetConnectAttr-function
 ///
@@ -3529,27 +6542,20 @@ pub unsafe extern "C" fn SQLSetConnectAttrW(
     value_ptr: Pointer,
     _str_length: Integer,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let conn_handle = try_mongo_handle!(connection_handle);
-
-            match FromPrimitive::from_i32(attribute) {
-                Some(valid_attr) => set_connect_attrw_helper(conn_handle, valid_attr, value_ptr),
-                None => {
-                    add_diag_info!(conn_handle, ODBCError::InvalidAttrIdentifier(attribute));
-                    SqlReturn::ERROR
-                }
-            }
-        },
-        connection_handle
+    sql_set_connect_attr_impl!(
+        connection_handle,
+        attribute,
+        value_ptr,
+        input_text_to_string_w,
     )
 }
 
-unsafe fn set_connect_attrw_helper(
+unsafe fn set_connect_attr_helper<C>(
     conn_handle: &mut MongoHandle,
     attribute: ConnectionAttribute,
     value_ptr: Pointer,
+    input_text: unsafe fn(*const C, isize) -> String,
+    function_name: &str,
 ) -> SqlReturn {
     let mut err = None;
 
@@ -3566,7 +6572,7 @@ unsafe fn set_connect_attrw_helper(
             }
             ConnectionAttribute::SQL_ATTR_APP_WCHAR_TYPE => SqlReturn::SUCCESS,
             ConnectionAttribute::SQL_ATTR_CURRENT_CATALOG => {
-                let current_db = input_text_to_string_w(
+                let current_db = input_text(
                     value_ptr as *const _,
                     SQL_NTS
                         .try_into()
@@ -3612,11 +6618,70 @@ unsafe fn set_connect_attrw_helper(
     };
 
     if let Some(error) = err {
-        add_diag_with_function!(conn_handle, error, "SQLSetConnectAttrW");
+        add_diag_with_function!(conn_handle, error, function_name);
     }
     sql_return
 }
 
+macro_rules! sql_set_cursor_name_impl {
+    (
+        $statement_handle:ident,
+        $cursor_name:ident,
+        $name_length:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let cursor_name = $cursor_name;
+        let name_length = $name_length;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if name_length < 0 && Integer::from(name_length) != SQL_NTS {
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::InvalidStringOrBufferLength(name_length.into())
+                    );
+                    return SqlReturn::ERROR;
+                }
+                let name = $input_text(cursor_name, name_length.into());
+                let stmt_handle: *mut MongoHandle = mongo_handle;
+                let stmt = must_be_valid!(mongo_handle.as_statement());
+                let connection = must_be_valid!((*stmt.connection).as_connection());
+                odbc_unwrap!(
+                    sql_set_cursor_name(stmt_handle, stmt, connection, name),
+                    mongo_handle
+                );
+                SqlReturn::SUCCESS
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLSetCursorName`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetCursorName-function
+///
+/// This is the ANSI version of the SQLSetCursorName function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLSetCursorName(
+    statement_handle: HStmt,
+    cursor_name: *const Char,
+    name_length: SmallInt,
+) -> SqlReturn {
+    sql_set_cursor_name_impl!(
+        statement_handle,
+        cursor_name,
+        name_length,
+        input_text_to_string_a,
+    )
+}
+
 ///
 /// [`SQLSetCursorNameW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetCursorName-function
 ///
@@ -3629,10 +6694,78 @@ unsafe fn set_connect_attrw_helper(
 #[no_mangle]
 pub unsafe extern "C" fn SQLSetCursorNameW(
     statement_handle: HStmt,
-    _cursor_name: *const WideChar,
-    _name_length: SmallInt,
+    cursor_name: *const WideChar,
+    name_length: SmallInt,
+) -> SqlReturn {
+    sql_set_cursor_name_impl!(
+        statement_handle,
+        cursor_name,
+        name_length,
+        input_text_to_string_w,
+    )
+}
+
+///
+/// sql_set_cursor_name validates `name` and assigns it to the cursor of `stmt`. The cursor
+/// must be closed, and no other statement of `connection` can have a cursor with the same name.
+///
+unsafe fn sql_set_cursor_name(
+    stmt_handle: *mut MongoHandle,
+    stmt: &Statement,
+    connection: &Connection,
+    name: String,
+) -> Result<()> {
+    if *stmt.cursor_open.read().unwrap() {
+        return Err(ODBCError::InvalidCursorState);
+    }
+    // Names starting with SQLCUR or SQL_CUR are reserved for the cursor names the driver generates.
+    let upper_name = name.to_uppercase();
+    if name.is_empty()
+        || name.chars().count() > usize::from(MAX_CURSOR_NAME_LEN)
+        || upper_name.starts_with(GENERATED_CURSOR_NAME_PREFIX)
+        || upper_name.starts_with(&GENERATED_CURSOR_NAME_PREFIX.replace('_', ""))
+    {
+        return Err(ODBCError::InvalidCursorName(name));
+    }
+    let duplicate = connection
+        .statements
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|&&handle| handle != stmt_handle)
+        .filter_map(|&handle| (*handle).as_statement())
+        .any(|other| other.cursor_name.read().unwrap().to_uppercase() == upper_name);
+    if duplicate {
+        return Err(ODBCError::DuplicateCursorName(name));
+    }
+    *stmt.cursor_name.write().unwrap() = name;
+    Ok(())
+}
+
+///
+/// [`SQLSetDescField`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetDescField-function
+///
+/// This is the ANSI version of the SQLSetDescField function. None of the descriptor
+/// fields that can be set are strings, so it is the same as SQLSetDescFieldW.
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+pub unsafe extern "C" fn SQLSetDescField(
+    desc_handle: HDesc,
+    rec_number: SmallInt,
+    field_identifier: SmallInt,
+    value_ptr: Pointer,
+    buffer_length: Integer,
 ) -> SqlReturn {
-    unimpl!(statement_handle);
+    SQLSetDescFieldW(
+        desc_handle,
+        rec_number,
+        field_identifier,
+        value_ptr,
+        buffer_length,
+    )
 }
 
 ///
@@ -3644,13 +6777,31 @@ pub unsafe extern "C" fn SQLSetCursorNameW(
 #[no_mangle]
 #[named]
 pub unsafe extern "C" fn SQLSetDescFieldW(
-    _desc_handle: HDesc,
-    _rec_number: SmallInt,
-    _field_identifier: SmallInt,
-    _value_ptr: Pointer,
+    desc_handle: HDesc,
+    rec_number: SmallInt,
+    field_identifier: SmallInt,
+    value_ptr: Pointer,
     _buffer_length: Integer,
 ) -> SqlReturn {
-    unsupported_function!(_desc_handle)
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(desc_handle);
+            let desc = must_be_desc!(mongo_handle);
+            let result = match FromPrimitive::from_i16(field_identifier) {
+                Some(field) => desc::set_desc_field(desc, rec_number, field, value_ptr),
+                None => Err(ODBCError::InvalidFieldDescriptor(field_identifier as u16)),
+            };
+            match result {
+                Ok(()) => SqlReturn::SUCCESS,
+                Err(error) => {
+                    add_diag_info!(mongo_handle, error);
+                    SqlReturn::ERROR
+                }
+            }
+        },
+        desc_handle
+    )
 }
 
 ///
@@ -3663,17 +6814,41 @@ pub unsafe extern "C" fn SQLSetDescFieldW(
 #[no_mangle]
 pub unsafe extern "C" fn SQLSetDescRec(
     desc_handle: HDesc,
-    _rec_number: SmallInt,
-    _desc_type: SmallInt,
+    rec_number: SmallInt,
+    desc_type: SmallInt,
     _desc_sub_type: SmallInt,
-    _length: Len,
-    _precision: SmallInt,
-    _scale: SmallInt,
-    _data_ptr: Pointer,
-    _string_length_ptr: *const Len,
-    _indicator_ptr: *const Len,
+    length: Len,
+    precision: SmallInt,
+    scale: SmallInt,
+    data_ptr: Pointer,
+    string_length_ptr: *const Len,
+    indicator_ptr: *const Len,
 ) -> SqlReturn {
-    unimpl!(desc_handle)
+    panic_safe_exec_clear_diagnostics!(
+        debug,
+        || {
+            let mongo_handle = try_mongo_handle!(desc_handle);
+            let desc = must_be_desc!(mongo_handle);
+            match desc::set_desc_rec(
+                desc,
+                rec_number,
+                desc_type,
+                length,
+                precision,
+                scale,
+                data_ptr,
+                string_length_ptr.cast_mut(),
+                indicator_ptr.cast_mut(),
+            ) {
+                Ok(()) => SqlReturn::SUCCESS,
+                Err(error) => {
+                    add_diag_info!(mongo_handle, error);
+                    SqlReturn::ERROR
+                }
+            }
+        },
+        desc_handle
+    )
 }
 
 ///
@@ -3802,6 +6977,25 @@ unsafe fn sql_set_env_attrw_helper(
     }
 }
 
+///
+/// [`SQLSetStmtAttr`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetStmtAttr-function
+///
+/// This is the ANSI version of the SQLSetStmtAttr function. None of the statement
+/// attributes are strings, so it is the same as SQLSetStmtAttrW.
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+pub unsafe extern "C" fn SQLSetStmtAttr(
+    hstmt: HStmt,
+    attr: Integer,
+    value: Pointer,
+    str_length: Integer,
+) -> SqlReturn {
+    SQLSetStmtAttrW(hstmt, attr, value, str_length)
+}
+
 ///
 /// [`SQLSetStmtAttrW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSetStmtAttr-function
 ///
@@ -3843,26 +7037,68 @@ unsafe fn sql_set_stmt_attrw_helper(
     let stmt = must_be_valid!(stmt_handle.as_statement());
     match attribute {
         StatementAttribute::SQL_ATTR_APP_ROW_DESC => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_APP_ROW_DESC"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            let desc_handle = value_ptr.cast::<MongoHandle>();
+            let conn = stmt.connection.as_ref().and_then(MongoHandle::as_connection);
+            let implicit_app_row_desc = stmt.attributes.read().unwrap().implicit_app_row_desc;
+            // Setting a null handle or the implicitly allocated descriptor reverts the statement
+            // to its implicitly allocated descriptor. Explicitly allocated descriptors can only be
+            // used by statements on the connection they were allocated on.
+            let error = if desc_handle.is_null() || desc_handle == implicit_app_row_desc {
+                stmt.attributes.write().unwrap().app_row_desc = implicit_app_row_desc;
+                None
+            } else if conn.is_some_and(|conn| conn.descriptors.read().unwrap().contains(&desc_handle)) {
+                stmt.attributes.write().unwrap().app_row_desc = desc_handle;
+                None
+            } else if conn.is_some_and(|conn| {
+                conn.statements.read().unwrap().iter().any(|&other| {
+                    (*other).as_statement().is_some_and(|other| {
+                        let other = other.attributes.read().unwrap();
+                        [
+                            other.implicit_app_row_desc,
+                            other.app_param_desc,
+                            other.imp_row_desc,
+                            other.imp_param_desc,
+                        ]
+                        .contains(&desc_handle)
+                    })
+                })
+            }) {
+                Some(ODBCError::InvalidUseOfAutomaticDescriptor)
+            } else {
+                Some(ODBCError::InvalidAttrValue("SQL_ATTR_APP_ROW_DESC"))
+            };
+            match error {
+                None => SqlReturn::SUCCESS,
+                Some(error) => {
+                    add_diag_with_function!(stmt_handle, error, "SQLSetStmtAttrW");
+                    SqlReturn::ERROR
+                }
+            }
         }
         StatementAttribute::SQL_ATTR_APP_PARAM_DESC => {
             add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_APP_PARAM_DESC"), "SQLSetStmtAttrW");
             SqlReturn::ERROR
         }
-        StatementAttribute::SQL_ATTR_IMP_ROW_DESC => {
-            // TODO: SQL_681, determine the correct SQL state
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_IMP_ROW_DESC"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
-        }
-        StatementAttribute::SQL_ATTR_IMP_PARAM_DESC => {
-            // TODO: SQL_681, determine the correct SQL state
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_IMP_PARAM_DESC"), "SQLSetStmtAttrW");
+        StatementAttribute::SQL_ATTR_IMP_ROW_DESC | StatementAttribute::SQL_ATTR_IMP_PARAM_DESC => {
+            // Implementation descriptors are always implicitly allocated and cannot be replaced.
+            add_diag_with_function!(stmt_handle, ODBCError::InvalidUseOfAutomaticDescriptor, "SQLSetStmtAttrW");
             SqlReturn::ERROR
         }
         StatementAttribute::SQL_ATTR_CURSOR_SCROLLABLE => {
+            // Scrollable cursors are static cursors, so the cursor type follows this attribute.
             match FromPrimitive::from_usize(value_ptr as usize) {
-                Some(CursorScrollable::SQL_NONSCROLLABLE) => SqlReturn::SUCCESS,
+                Some(CursorScrollable::SQL_NONSCROLLABLE) => {
+                    let mut attributes = stmt.attributes.write().unwrap();
+                    attributes.cursor_scrollable = CursorScrollable::SQL_NONSCROLLABLE;
+                    attributes.cursor_type = CursorType::SQL_CURSOR_FORWARD_ONLY;
+                    SqlReturn::SUCCESS
+                }
+                Some(CursorScrollable::SQL_SCROLLABLE) => {
+                    let mut attributes = stmt.attributes.write().unwrap();
+                    attributes.cursor_scrollable = CursorScrollable::SQL_SCROLLABLE;
+                    attributes.cursor_type = CursorType::SQL_CURSOR_STATIC;
+                    SqlReturn::SUCCESS
+                }
                 _ => {
                     stmt_handle
                         .add_diag_info(ODBCError::InvalidAttrValue("SQL_ATTR_CURSOR_SCROLLABLE"));
@@ -3880,10 +7116,16 @@ unsafe fn sql_set_stmt_attrw_helper(
                 }
             }
         }
-        StatementAttribute::SQL_ATTR_ASYNC_ENABLE => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_ASYNC_ENABLE"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
-        }
+        StatementAttribute::SQL_ATTR_ASYNC_ENABLE => match FromPrimitive::from_usize(value_ptr as usize) {
+            Some(async_enable) => {
+                stmt.attributes.write().unwrap().async_enable = async_enable;
+                SqlReturn::SUCCESS
+            }
+            None => {
+                add_diag_with_function!(stmt_handle, ODBCError::InvalidAttrValue("SQL_ATTR_ASYNC_ENABLE"), "SQLSetStmtAttrW");
+                SqlReturn::ERROR
+            }
+        },
         StatementAttribute::SQL_ATTR_CONCURRENCY => match FromPrimitive::from_i32(value_ptr as i32)
         {
             Some(Concurrency::SQL_CONCUR_READ_ONLY) => SqlReturn::SUCCESS,
@@ -3897,11 +7139,28 @@ unsafe fn sql_set_stmt_attrw_helper(
         },
         StatementAttribute::SQL_ATTR_CURSOR_TYPE => match FromPrimitive::from_i32(value_ptr as i32)
         {
-            Some(CursorType::SQL_CURSOR_FORWARD_ONLY) => SqlReturn::SUCCESS,
+            Some(CursorType::SQL_CURSOR_FORWARD_ONLY) => {
+                let mut attributes = stmt.attributes.write().unwrap();
+                attributes.cursor_type = CursorType::SQL_CURSOR_FORWARD_ONLY;
+                attributes.cursor_scrollable = CursorScrollable::SQL_NONSCROLLABLE;
+                SqlReturn::SUCCESS
+            }
+            Some(CursorType::SQL_CURSOR_STATIC) => {
+                let mut attributes = stmt.attributes.write().unwrap();
+                attributes.cursor_type = CursorType::SQL_CURSOR_STATIC;
+                attributes.cursor_scrollable = CursorScrollable::SQL_SCROLLABLE;
+                SqlReturn::SUCCESS
+            }
+            // Keyset-driven and dynamic cursors are not supported, so a static cursor is used instead.
             _ => {
+                {
+                    let mut attributes = stmt.attributes.write().unwrap();
+                    attributes.cursor_type = CursorType::SQL_CURSOR_STATIC;
+                    attributes.cursor_scrollable = CursorScrollable::SQL_SCROLLABLE;
+                }
                 stmt_handle.add_diag_info(ODBCError::OptionValueChanged(
                     "SQL_ATTR_CURSOR_TYPE",
-                    "SQL_CURSOR_FORWARD_ONLY",
+                    "SQL_CURSOR_STATIC",
                 ));
                 SqlReturn::SUCCESS_WITH_INFO
             }
@@ -3911,16 +7170,16 @@ unsafe fn sql_set_stmt_attrw_helper(
             SqlReturn::ERROR
         }
         StatementAttribute::SQL_ATTR_FETCH_BOOKMARK_PTR => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_FETCH_BOOKMARK_PTR"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            stmt.attributes.write().unwrap().fetch_bookmark_ptr = value_ptr.cast();
+            SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_KEYSET_SIZE => {
             add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_KEYSET_SIZE"), "SQLSetStmtAttrW");
             SqlReturn::ERROR
         }
         StatementAttribute::SQL_ATTR_MAX_LENGTH => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_MAX_LENGTH"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            stmt.attributes.write().unwrap().max_length = value_ptr as ULen;
+            SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_MAX_ROWS => {
             stmt.attributes.write().unwrap().max_rows = value_ptr as ULen;
@@ -3934,28 +7193,38 @@ unsafe fn sql_set_stmt_attrw_helper(
             SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_PARAM_BIND_OFFSET_PTR => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_PARAM_BIND_OFFSET_PTR"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            stmt.attributes.write().unwrap().param_bind_offset_ptr = value_ptr.cast::<ULen>();
+            SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_PARAM_BIND_TYPE => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_PARAM_BIND_TYPE"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            // Any value other than SQL_BIND_BY_COLUMN is the size of the structure the
+            // parameters are bound in, for row-wise binding.
+            stmt.attributes.write().unwrap().param_bind_type = value_ptr as ULen;
+            SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_PARAM_OPERATION_PTR => {
             add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_PARAM_OPERATION_PTR"), "SQLSetStmtAttrW");
             SqlReturn::ERROR
         }
         StatementAttribute::SQL_ATTR_PARAM_STATUS_PTR => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_PARAM_STATUS_PTR"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            stmt.attributes.write().unwrap().param_status_ptr = value_ptr.cast::<USmallInt>();
+            SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_PARAMS_PROCESSED_PTR => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_PARAMS_PROCESSED_PTR"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            stmt.attributes.write().unwrap().param_processed_ptr = value_ptr.cast::<ULen>();
+            SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_PARAMSET_SIZE => {
-            add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("SQL_ATTR_PARAMSET_SIZE"), "SQLSetStmtAttrW");
-            SqlReturn::ERROR
+            match value_ptr as ULen {
+                0 => {
+                    stmt_handle.add_diag_info(ODBCError::InvalidAttrValue("SQL_ATTR_PARAMSET_SIZE"));
+                    SqlReturn::ERROR
+                }
+                paramset_size => {
+                    stmt.attributes.write().unwrap().paramset_size = paramset_size;
+                    SqlReturn::SUCCESS
+                }
+            }
         }
         StatementAttribute::SQL_ATTR_QUERY_TIMEOUT => {
             stmt.attributes.write().unwrap().query_timeout = value_ptr as ULen;
@@ -3972,22 +7241,13 @@ unsafe fn sql_set_stmt_attrw_helper(
             }
         }
         StatementAttribute::SQL_ATTR_ROW_BIND_OFFSET_PTR => {
-            if !value_ptr.is_null(){
-                add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("`column binding with offsets`"), "SQLSetStmtAttrW");
-                return SqlReturn::ERROR;
-            }
-
-            stmt.attributes.write().unwrap().row_bind_offset_ptr = null_mut();
+            stmt.update_app_row_desc(|ard| ard.bind_offset_ptr = value_ptr.cast::<ULen>());
             SqlReturn::SUCCESS
-
         }
         StatementAttribute::SQL_ATTR_ROW_BIND_TYPE => {
-            if value_ptr as ULen != BindType::SQL_BIND_BY_COLUMN as ULen{
-                add_diag_with_function!(stmt_handle,ODBCError::Unimplemented("`row-wise column binding`"), "SQLSetStmtAttrW");
-                return SqlReturn::ERROR;
-            }
-
-            stmt.attributes.write().unwrap().row_bind_type = BindType::SQL_BIND_BY_COLUMN as ULen;
+            // Any value other than SQL_BIND_BY_COLUMN is the size of the structure the
+            // columns are bound in, for row-wise binding.
+            stmt.update_app_row_desc(|ard| ard.bind_type = value_ptr as ULen);
             SqlReturn::SUCCESS
         }
         StatementAttribute::SQL_ATTR_ROW_NUMBER => {
@@ -4009,11 +7269,11 @@ unsafe fn sql_set_stmt_attrw_helper(
         StatementAttribute::SQL_ATTR_ROW_ARRAY_SIZE | StatementAttribute::SQL_ROWSET_SIZE => {
             match u32::try_from(value_ptr as ULen){
                 Ok(ras) => {
-                    stmt.attributes.write().unwrap().row_array_size = ras as ULen;
+                    stmt.update_app_row_desc(|ard| ard.array_size = ras as ULen);
                     SqlReturn::SUCCESS
                 },
                 Err(_) => {
-                    stmt.attributes.write().unwrap().row_array_size = u32::MAX as ULen;
+                    stmt.update_app_row_desc(|ard| ard.array_size = u32::MAX as ULen);
                     add_diag_with_function!(stmt_handle, ODBCError::OptionValueChanged("SQL_ATTR_ROW_ARRAY_SIZE or SQL_ROWSET_SIZE", "4,294,967,295"), "SQLSetStmtAttrW");
                     SqlReturn::SUCCESS_WITH_INFO
                 },
@@ -4058,6 +7318,129 @@ unsafe fn sql_set_stmt_attrw_helper(
     }
 }
 
+macro_rules! sql_special_columns_impl {
+    (
+        $statement_handle:ident,
+        $identifier_type:ident,
+        $catalog_name:ident,
+        $catalog_name_length:ident,
+        $table_name:ident,
+        $table_name_length:ident,
+        $scope:ident,
+        $nullable:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let identifier_type = $identifier_type;
+        let catalog_name = $catalog_name;
+        let catalog_name_length = $catalog_name_length;
+        let table_name = $table_name;
+        let table_name_length = $table_name_length;
+        let scope = $scope;
+        let nullable = $nullable;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                if identifier_type != SQL_BEST_ROWID && identifier_type != SQL_ROWVER {
+                    add_diag_info!(
+                        mongo_handle,
+                        ODBCError::ColumnTypeOutOfRange(identifier_type)
+                    );
+                    return SqlReturn::ERROR;
+                }
+                // _id has a session scope, so it satisfies any requested scope.
+                if !(SQL_SCOPE_CURROW..=SQL_SCOPE_SESSION).contains(&scope) {
+                    add_diag_info!(mongo_handle, ODBCError::ScopeTypeOutOfRange(scope));
+                    return SqlReturn::ERROR;
+                }
+                let allow_nullable = match Nullability::from_i16(nullable) {
+                    Some(Nullability::SQL_NO_NULLS) => false,
+                    Some(Nullability::SQL_NULLABLE) => true,
+                    _ => {
+                        add_diag_info!(mongo_handle, ODBCError::NullableTypeOutOfRange(nullable));
+                        return SqlReturn::ERROR;
+                    }
+                };
+                let catalog = $input_text(catalog_name, catalog_name_length.into());
+                // ignore schema
+                let table = $input_text(table_name, table_name_length.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let odbc_3_data_types = has_odbc_3_behavior!(mongo_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    let catalog = catalog_or_current_catalog(connection, catalog);
+                    let mongo_statement = match identifier_type == SQL_ROWVER
+                        || catalog.is_empty()
+                        || table.is_empty()
+                    {
+                        true => MongoSpecialColumns::empty(),
+                        false => odbc_unwrap!(
+                            MongoSpecialColumns::best_row_id(
+                                connection
+                                    .mongo_connection
+                                    .read()
+                                    .unwrap()
+                                    .as_ref()
+                                    .unwrap(),
+                                &catalog,
+                                &table,
+                                allow_nullable,
+                                *connection.type_mode.read().unwrap(),
+                                *connection.max_string_length.read().unwrap(),
+                                odbc_3_data_types,
+                            ),
+                            mongo_handle
+                        ),
+                    };
+                    set_catalog_result_set(stmt, Box::new(mongo_statement));
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLSpecialColumns`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSpecialColumns-function
+///
+/// This is the ANSI version of the SQLSpecialColumns function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLSpecialColumns(
+    statement_handle: HStmt,
+    identifier_type: SmallInt,
+    catalog_name: *const Char,
+    catalog_name_length: SmallInt,
+    _schema_name: *const Char,
+    _schema_name_length: SmallInt,
+    table_name: *const Char,
+    table_name_length: SmallInt,
+    scope: SmallInt,
+    nullable: SmallInt,
+) -> SqlReturn {
+    sql_special_columns_impl!(
+        statement_handle,
+        identifier_type,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        scope,
+        nullable,
+        input_text_to_string_a_allow_null,
+    )
+}
+
 ///
 /// [`SQLSpecialColumnsW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLSpecialColumns-function
 ///
@@ -4070,17 +7453,134 @@ unsafe fn sql_set_stmt_attrw_helper(
 #[no_mangle]
 pub unsafe extern "C" fn SQLSpecialColumnsW(
     statement_handle: HStmt,
-    _identifier_type: SmallInt,
-    _catalog_name: *const WideChar,
-    _catalog_name_length: SmallInt,
+    identifier_type: SmallInt,
+    catalog_name: *const WideChar,
+    catalog_name_length: SmallInt,
     _schema_name: *const WideChar,
     _schema_name_length: SmallInt,
-    _table_name: *const WideChar,
-    _table_name_length: SmallInt,
-    _scope: SmallInt,
-    _nullable: SmallInt,
+    table_name: *const WideChar,
+    table_name_length: SmallInt,
+    scope: SmallInt,
+    nullable: SmallInt,
+) -> SqlReturn {
+    sql_special_columns_impl!(
+        statement_handle,
+        identifier_type,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        scope,
+        nullable,
+        input_text_to_string_w_allow_null,
+    )
+}
+
+macro_rules! sql_statistics_impl {
+    (
+        $statement_handle:ident,
+        $catalog_name:ident,
+        $catalog_name_length:ident,
+        $table_name:ident,
+        $table_name_length:ident,
+        $unique:ident,
+        $reserved:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let catalog_name = $catalog_name;
+        let catalog_name_length = $catalog_name_length;
+        let table_name = $table_name;
+        let table_name_length = $table_name_length;
+        let unique = $unique;
+        let reserved = $reserved;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let unique_only = match unique {
+                    SQL_INDEX_UNIQUE => true,
+                    SQL_INDEX_ALL => false,
+                    _ => {
+                        add_diag_info!(mongo_handle, ODBCError::UniquenessOptionOutOfRange(unique));
+                        return SqlReturn::ERROR;
+                    }
+                };
+                let ensure = match reserved {
+                    SQL_ENSURE => true,
+                    SQL_QUICK => false,
+                    _ => {
+                        add_diag_info!(mongo_handle, ODBCError::AccuracyOptionOutOfRange(reserved));
+                        return SqlReturn::ERROR;
+                    }
+                };
+                let catalog = $input_text(catalog_name, catalog_name_length.into());
+                // ignore schema
+                let table = $input_text(table_name, table_name_length.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    let catalog = catalog_or_current_catalog(connection, catalog);
+                    let mongo_statement = match catalog.is_empty() || table.is_empty() {
+                        true => MongoStatistics::empty(),
+                        false => MongoStatistics::list_indexes(
+                            connection
+                                .mongo_connection
+                                .read()
+                                .unwrap()
+                                .as_ref()
+                                .unwrap(),
+                            &catalog,
+                            &table,
+                            unique_only,
+                            ensure,
+                            stmt.attributes.read().unwrap().max_rows as u64,
+                        ),
+                    };
+                    set_catalog_result_set(stmt, Box::new(mongo_statement));
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLStatistics`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLStatistics-function
+///
+/// This is the ANSI version of the SQLStatistics function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLStatistics(
+    statement_handle: HStmt,
+    catalog_name: *const Char,
+    catalog_name_length: SmallInt,
+    _schema_name: *const Char,
+    _schema_name_length: SmallInt,
+    table_name: *const Char,
+    table_name_length: SmallInt,
+    unique: SmallInt,
+    reserved: SmallInt,
 ) -> SqlReturn {
-    unimpl!(statement_handle);
+    sql_statistics_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        unique,
+        reserved,
+        input_text_to_string_a_allow_null,
+    )
 }
 
 ///
@@ -4093,16 +7593,51 @@ pub unsafe extern "C" fn SQLSpecialColumnsW(
 #[no_mangle]
 pub unsafe extern "C" fn SQLStatisticsW(
     statement_handle: HStmt,
-    _catalog_name: *const WideChar,
-    _catalog_name_length: SmallInt,
+    catalog_name: *const WideChar,
+    catalog_name_length: SmallInt,
     _schema_name: *const WideChar,
     _schema_name_length: SmallInt,
-    _table_name: *const WideChar,
-    _table_name_length: SmallInt,
-    _unique: SmallInt,
-    _reserved: SmallInt,
+    table_name: *const WideChar,
+    table_name_length: SmallInt,
+    unique: SmallInt,
+    reserved: SmallInt,
 ) -> SqlReturn {
-    unsupported_function!(statement_handle)
+    sql_statistics_impl!(
+        statement_handle,
+        catalog_name,
+        catalog_name_length,
+        table_name,
+        table_name_length,
+        unique,
+        reserved,
+        input_text_to_string_w_allow_null,
+    )
+}
+
+///
+/// set_catalog_result_set makes the result set of a catalog function the result set of the
+/// statement. Its cursor is open, like the cursor of an executed query, until it is closed.
+///
+fn set_catalog_result_set(stmt: &Statement, mongo_statement: Box<dyn MongoStatement>) {
+    *stmt.mongo_statement.write().unwrap() = Some(mongo_statement);
+    *stmt.cursor_open.write().unwrap() = true;
+}
+
+///
+/// catalog_or_current_catalog returns the catalog given to a catalog function, or the current
+/// database of the connection if the catalog is empty.
+///
+fn catalog_or_current_catalog(connection: &Connection, catalog: String) -> String {
+    match catalog.is_empty() {
+        true => connection
+            .attributes
+            .read()
+            .unwrap()
+            .current_catalog
+            .clone()
+            .unwrap_or_default(),
+        false => catalog,
+    }
 }
 
 ///
@@ -4110,6 +7645,10 @@ pub unsafe extern "C" fn SQLStatisticsW(
 /// properly when a connection is disconnected or a statement is freed.
 ///
 fn sql_stmt_close_cursor_helper(stmt: &Statement) {
+    let _ = stmt
+        .cursor_open
+        .write()
+        .map(|mut cursor_open| *cursor_open = false);
     let _ = stmt.mongo_statement.write().map(|mut stmt| {
         stmt.as_mut().map(|stmt| {
             stmt.close_cursor();
@@ -4117,6 +7656,90 @@ fn sql_stmt_close_cursor_helper(stmt: &Statement) {
     });
 }
 
+macro_rules! sql_table_privileges_impl {
+    (
+        $statement_handle:ident,
+        $catalog_name:ident,
+        $name_length_1:ident,
+        $table_name:ident,
+        $name_length_3:ident,
+        $input_text:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let catalog_name = $catalog_name;
+        let name_length_1 = $name_length_1;
+        let table_name = $table_name;
+        let name_length_3 = $name_length_3;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let catalog = $input_text(catalog_name, name_length_1.into());
+                // ignore schema
+                let table = $input_text(table_name, name_length_3.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = must_be_valid!((*stmt.connection).as_connection());
+                    let catalog = catalog_or_current_catalog(connection, catalog);
+                    let mongo_statement = match catalog.is_empty() {
+                        true => MongoPrivileges::empty(false),
+                        false => odbc_unwrap!(
+                            MongoPrivileges::list_table_privileges(
+                                connection
+                                    .mongo_connection
+                                    .read()
+                                    .unwrap()
+                                    .as_ref()
+                                    .unwrap(),
+                                &catalog,
+                                &table,
+                                stmt.attributes.read().unwrap().max_rows as u64,
+                            ),
+                            mongo_handle
+                        ),
+                    };
+                    set_catalog_result_set(stmt, Box::new(mongo_statement));
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLTablePrivileges`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function
+///
+/// This is the ANSI version of the SQLTablePrivileges function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[named]
+#[no_mangle]
+pub unsafe extern "C" fn SQLTablePrivileges(
+    statement_handle: HStmt,
+    catalog_name: *const Char,
+    name_length_1: SmallInt,
+    _schema_name: *const Char,
+    _name_length_2: SmallInt,
+    table_name: *const Char,
+    name_length_3: SmallInt,
+) -> SqlReturn {
+    sql_table_privileges_impl!(
+        statement_handle,
+        catalog_name,
+        name_length_1,
+        table_name,
+        name_length_3,
+        input_text_to_string_a_allow_null,
+    )
+}
+
 ///
 /// [`SQLTablePrivilegesW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/sqltableprivileges-function
 ///
@@ -4129,14 +7752,21 @@ fn sql_stmt_close_cursor_helper(stmt: &Statement) {
 #[no_mangle]
 pub unsafe extern "C" fn SQLTablePrivilegesW(
     statement_handle: HStmt,
-    _catalog_name: *const WideChar,
-    _name_length_1: SmallInt,
+    catalog_name: *const WideChar,
+    name_length_1: SmallInt,
     _schema_name: *const WideChar,
     _name_length_2: SmallInt,
-    _table_name: *const WideChar,
-    _name_length_3: SmallInt,
+    table_name: *const WideChar,
+    name_length_3: SmallInt,
 ) -> SqlReturn {
-    unimpl!(statement_handle);
+    sql_table_privileges_impl!(
+        statement_handle,
+        catalog_name,
+        name_length_1,
+        table_name,
+        name_length_3,
+        input_text_to_string_w_allow_null,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -4149,16 +7779,20 @@ fn sql_tables(
     table_t: &str,
     odbc_3_behavior: bool,
     max_string_length: Option<u16>,
+    max_rows: u64,
 ) -> Result<Box<dyn MongoStatement>> {
     match (catalog, schema, table, table_t) {
         (SQL_ALL_CATALOGS, "", "", "") => Ok(Box::new(MongoDatabases::list_all_catalogs(
             mongo_connection,
             Some(query_timeout),
+            max_rows,
         ))),
         ("", SQL_ALL_SCHEMAS, "", "") => {
             Ok(Box::new(MongoCollections::all_schemas(max_string_length)))
         }
-        ("", "", "", SQL_ALL_TABLE_TYPES) => Ok(Box::new(MongoTableTypes::all_table_types())),
+        ("", "", "", SQL_ALL_TABLE_TYPES) => {
+            Ok(Box::new(MongoTableTypes::all_table_types(max_rows)))
+        }
         _ => Ok(Box::new(MongoCollections::list_tables(
             mongo_connection,
             Some(query_timeout),
@@ -4166,10 +7800,118 @@ fn sql_tables(
             table,
             table_t,
             odbc_3_behavior,
+            max_rows,
         ))),
     }
 }
 
+macro_rules! sql_tables_impl {
+    (
+        $statement_handle:ident,
+        $catalog_name:ident,
+        $name_length_1:ident,
+        $schema_name:ident,
+        $name_length_2:ident,
+        $table_name:ident,
+        $name_length_3:ident,
+        $table_type:ident,
+        $name_length_4:ident,
+        $input_text:path,
+        $input_text_allow_null:path,
+    ) => {{
+        let statement_handle = $statement_handle;
+        let catalog_name = $catalog_name;
+        let name_length_1 = $name_length_1;
+        let schema_name = $schema_name;
+        let name_length_2 = $name_length_2;
+        let table_name = $table_name;
+        let name_length_3 = $name_length_3;
+        let table_type = $table_type;
+        let name_length_4 = $name_length_4;
+        panic_safe_exec_clear_diagnostics!(
+            debug,
+            || {
+                let mongo_handle = try_mongo_handle!(statement_handle);
+                if let Some(sql_return) = poll_async(mongo_handle, function_name!()) {
+                    return sql_return;
+                }
+                let catalog = $input_text(catalog_name, name_length_1.into());
+                let schema = $input_text_allow_null(schema_name, name_length_2.into());
+                let table = $input_text_allow_null(table_name, name_length_3.into());
+                let table_t = $input_text(table_type, name_length_4.into());
+                exec_async(mongo_handle, function_name!(), move |statement_handle| {
+                    let mongo_handle = try_mongo_handle!(statement_handle);
+                    let odbc_behavior = has_odbc_3_behavior!(mongo_handle);
+                    let stmt = must_be_valid!((*mongo_handle).as_statement());
+                    let connection = (*stmt.connection).as_connection().unwrap();
+                    let max_string_length = *connection.max_string_length.read().unwrap();
+                    let mongo_statement = sql_tables(
+                        connection
+                            .mongo_connection
+                            .read()
+                            .unwrap()
+                            .as_ref()
+                            .unwrap(),
+                        stmt.attributes
+                            .read()
+                            .unwrap()
+                            .query_timeout
+                            .try_into()
+                            .expect("Query timeout exceeds {i32::MAX}"),
+                        &catalog,
+                        &schema,
+                        &table,
+                        &table_t,
+                        odbc_behavior,
+                        max_string_length,
+                        stmt.attributes.read().unwrap().max_rows as u64,
+                    );
+                    let mongo_statement = odbc_unwrap!(mongo_statement, mongo_handle);
+                    set_catalog_result_set(stmt, mongo_statement);
+                    SqlReturn::SUCCESS
+                })
+            },
+            statement_handle
+        );
+    }};
+}
+
+///
+/// [`SQLTables`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLTables-function
+///
+/// This is the ANSI version of the SQLTables function
+///
+/// # Safety
+/// Because this is a C-interface, this is necessarily unsafe
+///
+#[no_mangle]
+#[named]
+pub unsafe extern "C" fn SQLTables(
+    statement_handle: HStmt,
+    catalog_name: *const Char,
+    name_length_1: SmallInt,
+    schema_name: *const Char,
+    name_length_2: SmallInt,
+    table_name: *const Char,
+    name_length_3: SmallInt,
+    table_type: *const Char,
+    name_length_4: SmallInt,
+) -> SqlReturn {
+    sql_tables_impl!(
+        statement_handle,
+        catalog_name,
+        name_length_1,
+        schema_name,
+        name_length_2,
+        table_name,
+        name_length_3,
+        table_type,
+        name_length_4,
+        input_text_to_string_a,
+        input_text_to_string_a_allow_null,
+    )
+}
+
 ///
 /// [`SQLTablesW`]: https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/SQLTables-function
 ///
@@ -4191,42 +7933,17 @@ pub unsafe extern "C" fn SQLTablesW(
     table_type: *const WideChar,
     name_length_4: SmallInt,
 ) -> SqlReturn {
-    panic_safe_exec_clear_diagnostics!(
-        debug,
-        || {
-            let mongo_handle = try_mongo_handle!(statement_handle);
-            let odbc_behavior = has_odbc_3_behavior!(mongo_handle);
-            let stmt = must_be_valid!((*mongo_handle).as_statement());
-            let catalog = input_text_to_string_w(catalog_name, name_length_1.into());
-            let schema = input_text_to_string_w_allow_null(schema_name, name_length_2.into());
-            let table = input_text_to_string_w_allow_null(table_name, name_length_3.into());
-            let table_t = input_text_to_string_w(table_type, name_length_4.into());
-            let connection = (*stmt.connection).as_connection().unwrap();
-            let max_string_length = *connection.max_string_length.read().unwrap();
-            let mongo_statement = sql_tables(
-                connection
-                    .mongo_connection
-                    .read()
-                    .unwrap()
-                    .as_ref()
-                    .unwrap(),
-                stmt.attributes
-                    .read()
-                    .unwrap()
-                    .query_timeout
-                    .try_into()
-                    .expect("Query timeout exceeds {i32::MAX}"),
-                &catalog,
-                &schema,
-                &table,
-                &table_t,
-                odbc_behavior,
-                max_string_length,
-            );
-            let mongo_statement = odbc_unwrap!(mongo_statement, mongo_handle);
-            *stmt.mongo_statement.write().unwrap() = Some(mongo_statement);
-            SqlReturn::SUCCESS
-        },
-        statement_handle
-    );
+    sql_tables_impl!(
+        statement_handle,
+        catalog_name,
+        name_length_1,
+        schema_name,
+        name_length_2,
+        table_name,
+        name_length_3,
+        table_type,
+        name_length_4,
+        input_text_to_string_w,
+        input_text_to_string_w_allow_null,
+    )
 }
@@ -377,7 +377,7 @@ mod unit {
         info_type = InfoType::SQL_STATIC_CURSOR_ATTRIBUTES1 as u16,
         expected_sql_return = SqlReturn::SUCCESS,
         expected_length = std::mem::size_of::<u32>() as i16,
-        expected_value = SQL_CA1_NEXT,
+        expected_value = MONGO_STATIC_CA1_SUPPORT,
         actual_value_modifier = modify_u32_value,
     );
 
@@ -386,7 +386,8 @@ mod unit {
         info_type = InfoType::SQL_STATIC_CURSOR_ATTRIBUTES2 as u16,
         expected_sql_return = SqlReturn::SUCCESS,
         expected_length = std::mem::size_of::<u32>() as i16,
-        expected_value = MONGO_CA2_SUPPORT,
+        expected_value =
+            SQL_CA2_READ_ONLY_CONCURRENCY | SQL_CA2_MAX_ROWS_SELECT | SQL_CA2_MAX_ROWS_CATALOG,
         actual_value_modifier = modify_u32_value,
     );
 
@@ -404,7 +405,7 @@ mod unit {
         info_type = InfoType::SQL_BOOKMARK_PERSISTENCE as u16,
         expected_sql_return = SqlReturn::SUCCESS,
         expected_length = std::mem::size_of::<u32>() as i16,
-        expected_value = 0,
+        expected_value = SQL_BP_SCROLL,
         actual_value_modifier = modify_u32_value,
     );
 
@@ -780,4 +781,40 @@ mod unit {
         expected_value = u16::MAX,
         actual_value_modifier = modify_u16_value,
     );
+
+    test_get_info!(
+        param_array_selects,
+        info_type = InfoType::SQL_PARAM_ARRAY_SELECTS as u16,
+        expected_sql_return = SqlReturn::SUCCESS,
+        expected_length = std::mem::size_of::<u32>() as i16,
+        expected_value = SQL_PAS_BATCH,
+        actual_value_modifier = modify_u32_value,
+    );
+
+    test_get_info!(
+        param_array_row_counts,
+        info_type = InfoType::SQL_PARAM_ARRAY_ROW_COUNTS as u16,
+        expected_sql_return = SqlReturn::SUCCESS,
+        expected_length = std::mem::size_of::<u32>() as i16,
+        expected_value = SQL_PARC_NO_BATCH,
+        actual_value_modifier = modify_u32_value,
+    );
+
+    test_get_info!(
+        async_mode,
+        info_type = InfoType::SQL_ASYNC_MODE as u16,
+        expected_sql_return = SqlReturn::SUCCESS,
+        expected_length = std::mem::size_of::<u32>() as i16,
+        expected_value = SQL_AM_STATEMENT,
+        actual_value_modifier = modify_u32_value,
+    );
+
+    test_get_info!(
+        max_async_concurrent_statements,
+        info_type = InfoType::SQL_MAX_ASYNC_CONCURRENT_STATEMENTS as u16,
+        expected_sql_return = SqlReturn::SUCCESS,
+        expected_length = std::mem::size_of::<u32>() as i16,
+        expected_value = MAX_COLUMNS_U32_ZERO,
+        actual_value_modifier = modify_u32_value,
+    );
 }
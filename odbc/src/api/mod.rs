@@ -1,21 +1,43 @@
+#[cfg(test)]
+mod ansi_tests;
+pub(crate) mod async_exec;
+#[cfg(test)]
+mod async_tests;
 pub(crate) mod diag;
 pub(crate) mod errors;
 mod functions;
 pub use functions::*;
 #[cfg(test)]
+mod batch_tests;
+#[cfg(test)]
 mod bind_col_tests;
 #[cfg(test)]
+mod bind_param_tests;
+#[cfg(test)]
+mod browse_connect_tests;
+#[cfg(test)]
 mod cancel_tests;
 #[cfg(test)]
 mod col_attr_describe_tests;
 #[cfg(test)]
 mod connect_attr_tests;
+#[cfg(test)]
+mod connect_tests;
+#[cfg(test)]
+mod cursor_name_tests;
 pub(crate) mod data;
 #[cfg(test)]
+mod data_sources_tests;
+#[cfg(test)]
 mod data_tests;
+pub(crate) mod desc;
+#[cfg(test)]
+mod desc_tests;
 #[cfg(test)]
 mod env_attr_tests;
 #[cfg(test)]
+mod fetch_scroll_tests;
+#[cfg(test)]
 mod free_stmt_tests;
 #[cfg(test)]
 mod get_diag_field_tests;
@@ -28,6 +50,11 @@ mod get_type_info_tests;
 #[cfg(test)]
 mod panic_safe_exec_tests;
 #[cfg(test)]
+mod param_array_tests;
+pub(crate) mod params;
+#[cfg(test)]
+mod put_data_tests;
+#[cfg(test)]
 mod stmt_attr_tests;
 pub(crate) mod util;
 
@@ -0,0 +1,236 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        api::{
+            errors::ODBCError,
+            params::{param_set_binding, param_value_to_bson},
+        },
+        handles::definitions::{
+            BoundParamInfo, Connection, ConnectionState, Env, EnvState, MongoHandle, PendingResult,
+            Statement, StatementState,
+        },
+        SQLBindParameter, SQLExecute, SQLFreeStmt, SQLMoreResults, SQLSetStmtAttrW,
+    };
+    use bson::{doc, Bson};
+    use definitions::{
+        BindType, CDataType, FreeStmtOption, Len, ParamStatus, ParamType, Pointer, SmallInt,
+        SqlDataType, SqlReturn, StatementAttribute, ULen, USmallInt, SQL_NTS_ISIZE,
+    };
+    use mongo_odbc_core::mock_query::MongoQuery;
+    use std::{mem::size_of, ptr::null};
+
+    fn param_info(
+        value_type: CDataType,
+        value_ptr: Pointer,
+        buffer_length: Len,
+        indicator: *mut Len,
+    ) -> BoundParamInfo {
+        BoundParamInfo {
+            input_output_type: ParamType::SQL_PARAM_INPUT as SmallInt,
+            value_type: value_type as SmallInt,
+            parameter_type: SqlDataType::SQL_UNKNOWN_TYPE as SmallInt,
+            column_size: 0,
+            decimal_digits: 0,
+            parameter_value_ptr: value_ptr,
+            buffer_length,
+            str_len_or_ind_ptr: indicator,
+        }
+    }
+
+    #[test]
+    fn test_column_wise_param_set_binding() {
+        unsafe {
+            let mut ints = [1i32, 2, 3];
+            let param = param_info(
+                CDataType::SQL_C_SLONG,
+                ints.as_mut_ptr() as Pointer,
+                0,
+                std::ptr::null_mut(),
+            );
+            for (set, expected) in ints.iter().enumerate() {
+                let binding =
+                    param_set_binding(&param, set, BindType::SQL_BIND_BY_COLUMN as ULen, null())
+                        .unwrap();
+                assert_eq!(
+                    Bson::Int32(*expected),
+                    param_value_to_bson(&binding).unwrap()
+                );
+            }
+
+            // Character buffers are buffer_length bytes apart, and so are their lengths.
+            let mut strings = *b"foo\0\0bar\0\0";
+            let mut lengths: [Len; 2] = [3, SQL_NTS_ISIZE];
+            let param = param_info(
+                CDataType::SQL_C_CHAR,
+                strings.as_mut_ptr() as Pointer,
+                5,
+                lengths.as_mut_ptr(),
+            );
+            let binding =
+                param_set_binding(&param, 1, BindType::SQL_BIND_BY_COLUMN as ULen, null()).unwrap();
+            assert_eq!(
+                Bson::String("bar".to_string()),
+                param_value_to_bson(&binding).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_row_wise_param_set_binding_with_offset() {
+        #[repr(C)]
+        struct Row {
+            value: i64,
+            indicator: Len,
+        }
+
+        unsafe {
+            let mut rows = [
+                Row {
+                    value: 10,
+                    indicator: 0,
+                },
+                Row {
+                    value: 20,
+                    indicator: 0,
+                },
+                Row {
+                    value: 30,
+                    indicator: 0,
+                },
+            ];
+            // The parameter is bound to the first row, and the offset moves the binding
+            // one row down.
+            let param = param_info(
+                CDataType::SQL_C_SBIGINT,
+                &mut rows[0].value as *mut i64 as Pointer,
+                0,
+                &mut rows[0].indicator,
+            );
+            let offset: ULen = size_of::<Row>();
+            let binding = param_set_binding(&param, 1, size_of::<Row>(), &offset).unwrap();
+            assert_eq!(Bson::Int64(30), param_value_to_bson(&binding).unwrap());
+            assert_eq!(
+                &mut rows[2].indicator as *mut Len,
+                binding.str_len_or_ind_ptr
+            );
+        }
+    }
+
+    #[test]
+    fn test_execute_param_array_statuses() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            *s.parameterized_query.write().unwrap() =
+                Some("select * from foo where a = ?".to_string());
+
+            let mut values = [1i32, 2, 3];
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLBindParameter(
+                    stmt as *mut _,
+                    1,
+                    ParamType::SQL_PARAM_INPUT as SmallInt,
+                    CDataType::SQL_C_SLONG as SmallInt,
+                    SqlDataType::SQL_INTEGER as SmallInt,
+                    0,
+                    0,
+                    values.as_mut_ptr() as Pointer,
+                    0,
+                    std::ptr::null_mut(),
+                )
+            );
+
+            let mut statuses = [USmallInt::MAX; 3];
+            let mut processed: ULen = 0;
+            for (attribute, value) in [
+                (StatementAttribute::SQL_ATTR_PARAMSET_SIZE, 3 as Pointer),
+                (
+                    StatementAttribute::SQL_ATTR_PARAM_STATUS_PTR,
+                    statuses.as_mut_ptr() as Pointer,
+                ),
+                (
+                    StatementAttribute::SQL_ATTR_PARAMS_PROCESSED_PTR,
+                    &mut processed as *mut ULen as Pointer,
+                ),
+            ] {
+                assert_eq!(
+                    SqlReturn::SUCCESS,
+                    SQLSetStmtAttrW(stmt as *mut _, attribute as i32, value, 0)
+                );
+            }
+
+            // There is no connection, so every parameter set fails, but each one is processed
+            // and has its status set.
+            assert_eq!(SqlReturn::ERROR, SQLExecute(stmt as *mut _));
+            assert_eq!(3, processed);
+            assert_eq!([ParamStatus::SQL_PARAM_ERROR as USmallInt; 3], statuses);
+            let errors = s.errors.read().unwrap();
+            assert_eq!(3, errors.len());
+            assert!(errors[1].to_string().ends_with("(parameter set 2)"));
+            assert_eq!("24000", errors[1].get_sql_state().odbc_3_state);
+            assert_eq!(StatementState::Allocated, *s.state.read().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_more_results() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            assert_eq!(SqlReturn::NO_DATA, SQLMoreResults(stmt as *mut _));
+
+            *s.mongo_statement.write().unwrap() = Some(Box::new(MongoQuery::new(
+                vec![doc! {"foo": {"a": 1}}],
+                vec![],
+            )));
+            s.more_results.write().unwrap().extend([
+                PendingResult::ParamSet(1, Err(ODBCError::UnboundParameter(1))),
+                PendingResult::ParamSet(2, Err(ODBCError::UnboundParameter(1))),
+                PendingResult::ParamSet(3, Ok("select * from foo where a = 4".to_string())),
+            ]);
+
+            // SQLMoreResults executes the pending parameter sets until one succeeds. None can,
+            // since the first two have no values and there is no connection to run the third.
+            assert_eq!(SqlReturn::ERROR, SQLMoreResults(stmt as *mut _));
+            assert!(s.mongo_statement.read().unwrap().is_none());
+            assert_eq!(3, s.errors.read().unwrap().len());
+            assert_eq!(
+                "07002",
+                s.errors.read().unwrap()[0].get_sql_state().odbc_3_state
+            );
+            assert!(s.more_results.read().unwrap().is_empty());
+            assert_eq!(SqlReturn::NO_DATA, SQLMoreResults(stmt as *mut _));
+
+            s.more_results
+                .write()
+                .unwrap()
+                .push_back(PendingResult::ParamSet(
+                    1,
+                    Err(ODBCError::UnboundParameter(1)),
+                ));
+            // Closing the cursor drops the pending result sets.
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLFreeStmt(stmt as *mut _, FreeStmtOption::SQL_CLOSE as SmallInt)
+            );
+            assert!(s.more_results.read().unwrap().is_empty());
+            assert_eq!(SqlReturn::NO_DATA, SQLMoreResults(stmt as *mut _));
+        }
+    }
+}
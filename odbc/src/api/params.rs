@@ -0,0 +1,340 @@
+use crate::{api::errors::ODBCError, handles::definitions::BoundParamInfo};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use cstr::{input_text_to_string_w, WideChar};
+use definitions::{
+    len_data_at_exec, BindType, CDataType, Date, Len, SqlDataType, Time, Timestamp, ULen,
+    SQL_DATA_AT_EXEC, SQL_NTS_ISIZE, SQL_NULL_DATA,
+};
+use mongodb::bson::{spec::BinarySubtype, Binary, Bson, DateTime};
+use num_traits::FromPrimitive;
+use std::{ffi::CStr, mem::size_of, slice};
+
+type Result<T> = std::result::Result<T, ODBCError>;
+
+///
+/// param_value_to_bson reads the value bound to a parameter from the application's
+/// buffer and converts it to a BSON value. The BSON type is determined by the C type
+/// the parameter was bound with, so an SQL_C_SLONG parameter is always an int and an
+/// SQL_C_CHAR parameter is always a string, regardless of the SQL type.
+///
+/// # Safety
+/// The parameter value and length/indicator pointers are read as raw C buffers.
+///
+pub(crate) unsafe fn param_value_to_bson(param: &BoundParamInfo) -> Result<Bson> {
+    // A null indicator pointer means character data is null-terminated.
+    let indicator = if param.str_len_or_ind_ptr.is_null() {
+        SQL_NTS_ISIZE
+    } else {
+        *param.str_len_or_ind_ptr
+    };
+    if indicator == SQL_NULL_DATA || param.parameter_value_ptr.is_null() {
+        return Ok(Bson::Null);
+    }
+    let value_ptr = param.parameter_value_ptr;
+    // Values are read unaligned because data sent with SQLPutData is buffered as bytes.
+    Ok(match param_c_type(param)? {
+        CDataType::SQL_C_CHAR => Bson::String(char_data_to_string(value_ptr.cast(), indicator)),
+        CDataType::SQL_C_WCHAR => Bson::String(wchar_data_to_string(value_ptr.cast(), indicator)),
+        CDataType::SQL_C_BIT => Bson::Boolean(value_ptr.cast::<u8>().read_unaligned() != 0),
+        CDataType::SQL_C_STINYINT | CDataType::SQL_C_TINYINT => {
+            Bson::Int32(i32::from(value_ptr.cast::<i8>().read_unaligned()))
+        }
+        CDataType::SQL_C_UTINYINT => {
+            Bson::Int32(i32::from(value_ptr.cast::<u8>().read_unaligned()))
+        }
+        CDataType::SQL_C_SSHORT | CDataType::SQL_C_SHORT => {
+            Bson::Int32(i32::from(value_ptr.cast::<i16>().read_unaligned()))
+        }
+        CDataType::SQL_C_USHORT => Bson::Int32(i32::from(value_ptr.cast::<u16>().read_unaligned())),
+        CDataType::SQL_C_SLONG | CDataType::SQL_C_LONG => {
+            Bson::Int32(value_ptr.cast::<i32>().read_unaligned())
+        }
+        CDataType::SQL_C_ULONG => Bson::Int64(i64::from(value_ptr.cast::<u32>().read_unaligned())),
+        CDataType::SQL_C_SBIGINT => Bson::Int64(value_ptr.cast::<i64>().read_unaligned()),
+        CDataType::SQL_C_UBIGINT => {
+            let value = value_ptr.cast::<u64>().read_unaligned();
+            Bson::Int64(
+                i64::try_from(value)
+                    .map_err(|_| ODBCError::IntegralTruncation(value.to_string()))?,
+            )
+        }
+        CDataType::SQL_C_FLOAT => Bson::Double(f64::from(value_ptr.cast::<f32>().read_unaligned())),
+        CDataType::SQL_C_DOUBLE => Bson::Double(value_ptr.cast::<f64>().read_unaligned()),
+        CDataType::SQL_C_TYPE_DATE | CDataType::SQL_C_DATE => {
+            let date = value_ptr.cast::<Date>().read_unaligned();
+            datetime_to_bson(date_from_struct(&date)?.and_time(NaiveTime::MIN))
+        }
+        CDataType::SQL_C_TYPE_TIME | CDataType::SQL_C_TIME => {
+            // There is no time-only type, so times are sent as timestamps on the epoch date.
+            let time = value_ptr.cast::<Time>().read_unaligned();
+            datetime_to_bson(
+                NaiveDateTime::UNIX_EPOCH.date().and_time(
+                    NaiveTime::from_hms_opt(
+                        u32::from(time.hour),
+                        u32::from(time.minute),
+                        u32::from(time.second),
+                    )
+                    .ok_or(ODBCError::InvalidDatetimeFormat)?,
+                ),
+            )
+        }
+        CDataType::SQL_C_TYPE_TIMESTAMP | CDataType::SQL_C_TIMESTAMP => {
+            let ts = value_ptr.cast::<Timestamp>().read_unaligned();
+            let time = NaiveTime::from_hms_nano_opt(
+                u32::from(ts.hour),
+                u32::from(ts.minute),
+                u32::from(ts.second),
+                ts.fraction,
+            )
+            .ok_or(ODBCError::InvalidDatetimeFormat)?;
+            let date = date_from_struct(&Date {
+                year: ts.year,
+                month: ts.month,
+                day: ts.day,
+            })?;
+            datetime_to_bson(date.and_time(time))
+        }
+        CDataType::SQL_C_BINARY => {
+            let len = if indicator >= 0 {
+                indicator
+            } else {
+                param.buffer_length
+            };
+            Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: slice::from_raw_parts(value_ptr.cast::<u8>(), len.unsigned_abs()).to_vec(),
+            })
+        }
+        c_type => return Err(ODBCError::UnimplementedDataType(format!("{c_type:?}"))),
+    })
+}
+
+///
+/// data_at_exec_value_to_bson converts the data sent with SQLPutData for a data-at-execution
+/// parameter to a BSON value, the same way param_value_to_bson converts a bound buffer.
+///
+/// # Safety
+/// The parameter must have been bound with a valid C type.
+///
+pub(crate) unsafe fn data_at_exec_value_to_bson(
+    param: &BoundParamInfo,
+    data: Option<&[u8]>,
+) -> Result<Bson> {
+    let Some(data) = data else {
+        return Ok(Bson::Null);
+    };
+    let mut len = Len::try_from(data.len()).expect("data length exceeded {isize::MAX}");
+    param_value_to_bson(&BoundParamInfo {
+        parameter_value_ptr: data.as_ptr().cast_mut().cast(),
+        buffer_length: len,
+        str_len_or_ind_ptr: &mut len,
+        ..*param
+    })
+}
+
+///
+/// is_data_at_exec returns whether the value of a parameter is sent with SQLPutData at
+/// execution time rather than read from its bound buffer.
+///
+/// # Safety
+/// The length/indicator pointer is read as a raw C buffer.
+///
+pub(crate) unsafe fn is_data_at_exec(param: &BoundParamInfo) -> bool {
+    if param.str_len_or_ind_ptr.is_null() {
+        return false;
+    }
+    let indicator = *param.str_len_or_ind_ptr;
+    indicator == SQL_DATA_AT_EXEC || indicator <= len_data_at_exec(0)
+}
+
+///
+/// put_data_bytes copies one piece of the data passed to SQLPutData for a parameter. Character
+/// and binary data may be sent in several pieces; any other type must be sent in a single call,
+/// so `first_piece` must be true. Returns None when the piece is SQL_NULL_DATA.
+///
+/// # Safety
+/// The data pointer is read as a raw C buffer.
+///
+pub(crate) unsafe fn put_data_bytes(
+    param: &BoundParamInfo,
+    data_ptr: *const u8,
+    str_len_or_ind: Len,
+    first_piece: bool,
+) -> Result<Option<Vec<u8>>> {
+    if str_len_or_ind == SQL_NULL_DATA {
+        return Ok(None);
+    }
+    let c_type = param_c_type(param)?;
+    let len = match c_type {
+        CDataType::SQL_C_CHAR if str_len_or_ind == SQL_NTS_ISIZE => {
+            CStr::from_ptr(data_ptr.cast()).to_bytes().len()
+        }
+        CDataType::SQL_C_WCHAR if str_len_or_ind == SQL_NTS_ISIZE => {
+            let data = data_ptr.cast::<WideChar>();
+            let mut chars = 0;
+            while *data.add(chars) != 0 {
+                chars += 1;
+            }
+            chars * size_of::<WideChar>()
+        }
+        CDataType::SQL_C_CHAR | CDataType::SQL_C_WCHAR | CDataType::SQL_C_BINARY => {
+            usize::try_from(str_len_or_ind)
+                .map_err(|_| ODBCError::InvalidStringOrBufferLength(str_len_or_ind))?
+        }
+        _ if !first_piece => return Err(ODBCError::NonCharacterDataInPieces),
+        // The length of fixed-size data is ignored.
+        c_type => fixed_c_type_size(c_type)?,
+    };
+    if len == 0 {
+        return Ok(Some(vec![]));
+    }
+    Ok(Some(slice::from_raw_parts(data_ptr, len).to_vec()))
+}
+
+///
+/// param_set_binding returns the binding of a parameter for one parameter set of a parameter
+/// array. With column-wise binding, the buffers of a parameter are arrays of values and
+/// length/indicators. With row-wise binding, `bind_type` is the size of the structure each
+/// parameter set is bound in. In both cases, the bind offset is added to every address.
+///
+/// # Safety
+/// The bind offset pointer is read as a raw C buffer.
+///
+pub(crate) unsafe fn param_set_binding(
+    param: &BoundParamInfo,
+    set: usize,
+    bind_type: ULen,
+    bind_offset_ptr: *const ULen,
+) -> Result<BoundParamInfo> {
+    let (value_stride, indicator_stride) = if bind_type == BindType::SQL_BIND_BY_COLUMN as ULen {
+        let value_size = match param_c_type(param)? {
+            CDataType::SQL_C_CHAR | CDataType::SQL_C_WCHAR | CDataType::SQL_C_BINARY => {
+                usize::try_from(param.buffer_length)
+                    .map_err(|_| ODBCError::InvalidStringOrBufferLength(param.buffer_length))?
+            }
+            c_type => fixed_c_type_size(c_type)?,
+        };
+        (value_size, size_of::<Len>())
+    } else {
+        (bind_type, bind_type)
+    };
+    let offset = if bind_offset_ptr.is_null() {
+        0
+    } else {
+        *bind_offset_ptr
+    };
+    let address = |ptr: *mut u8, stride: usize| {
+        if ptr.is_null() {
+            ptr
+        } else {
+            ptr.wrapping_add(offset + set * stride)
+        }
+    };
+    Ok(BoundParamInfo {
+        parameter_value_ptr: address(param.parameter_value_ptr.cast(), value_stride).cast(),
+        str_len_or_ind_ptr: address(param.str_len_or_ind_ptr.cast(), indicator_stride).cast(),
+        ..*param
+    })
+}
+
+///
+/// param_c_type returns the C type a parameter's value is read as, resolving SQL_C_DEFAULT to
+/// the default C type of its SQL type.
+///
+pub(crate) fn param_c_type(param: &BoundParamInfo) -> Result<CDataType> {
+    match FromPrimitive::from_i16(param.value_type) {
+        Some(CDataType::SQL_C_DEFAULT) => default_c_type(param.parameter_type),
+        Some(c_type) => Ok(c_type),
+        None => Err(ODBCError::InvalidTargetType(param.value_type)),
+    }
+}
+
+fn fixed_c_type_size(c_type: CDataType) -> Result<usize> {
+    Ok(match c_type {
+        CDataType::SQL_C_BIT
+        | CDataType::SQL_C_STINYINT
+        | CDataType::SQL_C_TINYINT
+        | CDataType::SQL_C_UTINYINT => size_of::<u8>(),
+        CDataType::SQL_C_SSHORT | CDataType::SQL_C_SHORT | CDataType::SQL_C_USHORT => {
+            size_of::<u16>()
+        }
+        CDataType::SQL_C_SLONG | CDataType::SQL_C_LONG | CDataType::SQL_C_ULONG => size_of::<u32>(),
+        CDataType::SQL_C_SBIGINT | CDataType::SQL_C_UBIGINT => size_of::<u64>(),
+        CDataType::SQL_C_FLOAT => size_of::<f32>(),
+        CDataType::SQL_C_DOUBLE => size_of::<f64>(),
+        CDataType::SQL_C_TYPE_DATE | CDataType::SQL_C_DATE => size_of::<Date>(),
+        CDataType::SQL_C_TYPE_TIME | CDataType::SQL_C_TIME => size_of::<Time>(),
+        CDataType::SQL_C_TYPE_TIMESTAMP | CDataType::SQL_C_TIMESTAMP => size_of::<Timestamp>(),
+        c_type => return Err(ODBCError::UnimplementedDataType(format!("{c_type:?}"))),
+    })
+}
+
+///
+/// default_c_type returns the C type used for SQL_C_DEFAULT, which depends on the SQL
+/// type of the parameter.
+///
+fn default_c_type(sql_type: i16) -> Result<CDataType> {
+    Ok(match FromPrimitive::from_i16(sql_type) {
+        Some(
+            SqlDataType::SQL_CHAR
+            | SqlDataType::SQL_VARCHAR
+            | SqlDataType::SQL_LONGVARCHAR
+            | SqlDataType::SQL_DECIMAL
+            | SqlDataType::SQL_NUMERIC,
+        ) => CDataType::SQL_C_CHAR,
+        Some(
+            SqlDataType::SQL_WCHAR | SqlDataType::SQL_WVARCHAR | SqlDataType::SQL_WLONGVARCHAR,
+        ) => CDataType::SQL_C_WCHAR,
+        Some(SqlDataType::SQL_BIT) => CDataType::SQL_C_BIT,
+        Some(SqlDataType::SQL_TINYINT) => CDataType::SQL_C_STINYINT,
+        Some(SqlDataType::SQL_SMALLINT) => CDataType::SQL_C_SSHORT,
+        Some(SqlDataType::SQL_INTEGER) => CDataType::SQL_C_SLONG,
+        Some(SqlDataType::SQL_BIGINT) => CDataType::SQL_C_SBIGINT,
+        Some(SqlDataType::SQL_REAL) => CDataType::SQL_C_FLOAT,
+        Some(SqlDataType::SQL_FLOAT | SqlDataType::SQL_DOUBLE) => CDataType::SQL_C_DOUBLE,
+        Some(
+            SqlDataType::SQL_BINARY | SqlDataType::SQL_VARBINARY | SqlDataType::SQL_LONGVARBINARY,
+        ) => CDataType::SQL_C_BINARY,
+        Some(SqlDataType::SQL_TYPE_DATE) => CDataType::SQL_C_TYPE_DATE,
+        Some(SqlDataType::SQL_TYPE_TIME) => CDataType::SQL_C_TYPE_TIME,
+        Some(SqlDataType::SQL_TYPE_TIMESTAMP | SqlDataType::SQL_TIMESTAMP) => {
+            CDataType::SQL_C_TYPE_TIMESTAMP
+        }
+        _ => return Err(ODBCError::InvalidSqlType(sql_type.to_string())),
+    })
+}
+
+unsafe fn char_data_to_string(data: *const u8, len: Len) -> String {
+    if len < 0 {
+        CStr::from_ptr(data.cast()).to_string_lossy().into_owned()
+    } else {
+        String::from_utf8_lossy(slice::from_raw_parts(data, len.unsigned_abs())).into_owned()
+    }
+}
+
+unsafe fn wchar_data_to_string(data: *const WideChar, len: Len) -> String {
+    // The length of wide character data is given in bytes.
+    let len = if len < 0 {
+        len
+    } else {
+        len / Len::try_from(size_of::<WideChar>()).unwrap()
+    };
+    if len == 0 {
+        String::new()
+    } else {
+        input_text_to_string_w(data, len)
+    }
+}
+
+fn date_from_struct(date: &Date) -> Result<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        i32::from(date.year),
+        u32::from(date.month),
+        u32::from(date.day),
+    )
+    .ok_or(ODBCError::InvalidDatetimeFormat)
+}
+
+fn datetime_to_bson(datetime: NaiveDateTime) -> Bson {
+    Bson::DateTime(DateTime::from_chrono(datetime.and_utc()))
+}
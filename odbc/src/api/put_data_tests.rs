@@ -0,0 +1,185 @@
+#![allow(
+    clippy::ptr_as_ptr,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap
+)]
+
+mod unit {
+    use crate::{
+        handles::definitions::{
+            Connection, ConnectionState, Env, EnvState, MongoHandle, Statement, StatementState,
+        },
+        SQLBindParameter, SQLCancel, SQLExecute, SQLParamData, SQLPutData,
+    };
+    use definitions::{
+        len_data_at_exec, CDataType, Len, ParamType, Pointer, SmallInt, SqlDataType, SqlReturn,
+        SQL_DATA_AT_EXEC, SQL_NTS_ISIZE, SQL_NULL_DATA,
+    };
+    use std::ptr::null_mut;
+
+    unsafe fn bind_data_at_exec_param(
+        stmt: *mut MongoHandle,
+        parameter_number: u16,
+        value_type: CDataType,
+        token: Pointer,
+        indicator: *mut Len,
+    ) {
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLBindParameter(
+                stmt as *mut _,
+                parameter_number,
+                ParamType::SQL_PARAM_INPUT as SmallInt,
+                value_type as SmallInt,
+                SqlDataType::SQL_VARCHAR as SmallInt,
+                0,
+                0,
+                token,
+                0,
+                indicator,
+            )
+        );
+    }
+
+    #[test]
+    fn test_put_data_in_pieces() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            *s.parameterized_query.write().unwrap() =
+                Some("select * from foo where a = ? and b = ?".to_string());
+
+            let first_token = 1 as Pointer;
+            let first_indicator = &mut { SQL_DATA_AT_EXEC };
+            bind_data_at_exec_param(stmt, 1, CDataType::SQL_C_CHAR, first_token, first_indicator);
+            let second_token = 2 as Pointer;
+            let second_indicator = &mut len_data_at_exec(5);
+            bind_data_at_exec_param(
+                stmt,
+                2,
+                CDataType::SQL_C_BINARY,
+                second_token,
+                second_indicator,
+            );
+
+            // SQLPutData cannot be called before SQLParamData requests a parameter
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLPutData(stmt as *mut _, b"x".as_ptr() as Pointer, 1)
+            );
+
+            assert_eq!(SqlReturn::NEED_DATA, SQLExecute(stmt as *mut _));
+            assert_eq!(
+                StatementState::FunctionNeedsDataNoParam,
+                *s.state.read().unwrap()
+            );
+
+            let token = &mut null_mut();
+            assert_eq!(SqlReturn::NEED_DATA, SQLParamData(stmt as *mut _, token));
+            assert_eq!(first_token, *token);
+            // SQLParamData cannot move on before data is put for the current parameter
+            assert_eq!(SqlReturn::ERROR, SQLParamData(stmt as *mut _, token));
+
+            let hello = b"hello ";
+            let world = b"world\0";
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLPutData(stmt as *mut _, hello.as_ptr() as Pointer, 6)
+            );
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLPutData(stmt as *mut _, world.as_ptr() as Pointer, SQL_NTS_ISIZE)
+            );
+
+            assert_eq!(SqlReturn::NEED_DATA, SQLParamData(stmt as *mut _, token));
+            assert_eq!(second_token, *token);
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLPutData(stmt as *mut _, null_mut(), SQL_NULL_DATA)
+            );
+
+            {
+                let data_at_exec = s.data_at_exec.read().unwrap();
+                let values = &data_at_exec.as_ref().unwrap().values;
+                assert_eq!(Some(b"hello world".to_vec()), values[&1]);
+                assert_eq!(None, values[&2]);
+            }
+
+            // All data is sent, so the query is executed. There is no connection, so the
+            // execution fails, but the statement must no longer need data.
+            assert_eq!(SqlReturn::ERROR, SQLParamData(stmt as *mut _, token));
+            assert_eq!(StatementState::Allocated, *s.state.read().unwrap());
+            assert!(s.data_at_exec.read().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_non_character_data_in_pieces() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            *s.parameterized_query.write().unwrap() =
+                Some("select * from foo where a = ?".to_string());
+            let indicator = &mut { SQL_DATA_AT_EXEC };
+            bind_data_at_exec_param(stmt, 1, CDataType::SQL_C_SLONG, 1 as Pointer, indicator);
+
+            assert_eq!(SqlReturn::NEED_DATA, SQLExecute(stmt as *mut _));
+            assert_eq!(
+                SqlReturn::NEED_DATA,
+                SQLParamData(stmt as *mut _, &mut null_mut())
+            );
+            let value = &mut 42i32;
+            assert_eq!(
+                SqlReturn::SUCCESS,
+                SQLPutData(stmt as *mut _, value as *mut i32 as Pointer, 4)
+            );
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLPutData(stmt as *mut _, value as *mut i32 as Pointer, 4)
+            );
+            assert_eq!(
+                Some(42i32.to_ne_bytes().to_vec()),
+                s.data_at_exec.read().unwrap().as_ref().unwrap().values[&1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_cancel_need_data() {
+        let env = &mut MongoHandle::Env(Env::with_state(EnvState::Allocated));
+        let conn =
+            &mut MongoHandle::Connection(Connection::with_state(env, ConnectionState::Allocated));
+        let stmt: *mut _ =
+            &mut MongoHandle::Statement(Statement::with_state(conn, StatementState::Allocated));
+
+        unsafe {
+            let s = (*stmt).as_statement().unwrap();
+            *s.parameterized_query.write().unwrap() =
+                Some("select * from foo where a = ?".to_string());
+            let indicator = &mut { SQL_DATA_AT_EXEC };
+            bind_data_at_exec_param(stmt, 1, CDataType::SQL_C_CHAR, 1 as Pointer, indicator);
+
+            assert_eq!(SqlReturn::NEED_DATA, SQLExecute(stmt as *mut _));
+            // The statement cannot be executed again while it needs data
+            assert_eq!(SqlReturn::ERROR, SQLExecute(stmt as *mut _));
+
+            assert_eq!(SqlReturn::SUCCESS, SQLCancel(stmt as *mut _));
+            assert_eq!(StatementState::Allocated, *s.state.read().unwrap());
+            assert!(s.data_at_exec.read().unwrap().is_none());
+            assert_eq!(
+                SqlReturn::ERROR,
+                SQLParamData(stmt as *mut _, &mut null_mut())
+            );
+        }
+    }
+}
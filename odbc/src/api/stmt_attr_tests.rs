@@ -139,7 +139,6 @@ fn get_set_ptr(
 
 mod unit {
     use super::*;
-    use std::ptr::null_mut;
     // test_supported_attributes tests SQLGetStmtAttr and SQLSetStmtAttr with every
     // supported statement attribute value.
     #[test]
@@ -180,12 +179,12 @@ mod unit {
         );
         get_set_stmt_attr(
             stmt_handle,
-            StatementAttribute::SQL_ATTR_CURSOR_SCROLLABLE,
+            StatementAttribute::SQL_ATTR_ASYNC_ENABLE,
             map! {
-                CursorScrollable::SQL_NONSCROLLABLE as i32 => SqlReturn::SUCCESS,
-                CursorScrollable::SQL_SCROLLABLE as i32 => SqlReturn::ERROR,
+                AsyncEnable::SQL_ASYNC_ENABLE_OFF as i32 => SqlReturn::SUCCESS,
+                AsyncEnable::SQL_ASYNC_ENABLE_ON as i32 => SqlReturn::SUCCESS,
             },
-            CursorScrollable::SQL_NONSCROLLABLE as usize,
+            AsyncEnable::SQL_ASYNC_ENABLE_OFF as usize,
         );
         get_set_stmt_attr(
             stmt_handle,
@@ -197,22 +196,11 @@ mod unit {
             },
             CursorSensitivity::SQL_INSENSITIVE as usize,
         );
-        get_set_stmt_attr(
-            stmt_handle,
-            StatementAttribute::SQL_ATTR_CURSOR_TYPE,
-            map! {
-                CursorType::SQL_CURSOR_FORWARD_ONLY as i32 => SqlReturn::SUCCESS,
-                CursorType::SQL_CURSOR_DYNAMIC as i32 => SqlReturn::SUCCESS_WITH_INFO,
-                CursorType::SQL_CURSOR_KEYSET_DRIVEN as i32 => SqlReturn::SUCCESS_WITH_INFO,
-                CursorType::SQL_CURSOR_STATIC as i32 => SqlReturn::SUCCESS_WITH_INFO,
-            },
-            CursorType::SQL_CURSOR_FORWARD_ONLY as usize,
-        );
         get_set_stmt_attr(
             stmt_handle,
             StatementAttribute::SQL_ATTR_MAX_LENGTH,
             map! {
-                10 => SqlReturn::ERROR, // Any number
+                10 => SqlReturn::SUCCESS, // Any number
             },
             0,
         );
@@ -234,6 +222,45 @@ mod unit {
             },
             NoScan::SQL_NOSCAN_OFF as usize,
         );
+        get_set_stmt_attr(
+            stmt_handle,
+            StatementAttribute::SQL_ATTR_PARAM_BIND_TYPE,
+            map! {
+                BindType::SQL_BIND_BY_COLUMN as i32 => SqlReturn::SUCCESS,
+                16 => SqlReturn::SUCCESS // The size of a structure for row-wise binding
+            },
+            BindType::SQL_BIND_BY_COLUMN as usize,
+        );
+        get_set_ptr(
+            stmt_handle,
+            StatementAttribute::SQL_ATTR_PARAM_BIND_OFFSET_PTR,
+            true,
+            true,
+            size_of::<*mut ULen>(),
+        );
+        get_set_ptr(
+            stmt_handle,
+            StatementAttribute::SQL_ATTR_PARAM_STATUS_PTR,
+            true,
+            true,
+            size_of::<*mut USmallInt>(),
+        );
+        get_set_ptr(
+            stmt_handle,
+            StatementAttribute::SQL_ATTR_PARAMS_PROCESSED_PTR,
+            true,
+            true,
+            size_of::<*mut ULen>(),
+        );
+        get_set_stmt_attr(
+            stmt_handle,
+            StatementAttribute::SQL_ATTR_PARAMSET_SIZE,
+            map! {
+                0 => SqlReturn::ERROR,
+                10 => SqlReturn::SUCCESS, // Any non-zero number
+            },
+            1,
+        );
         get_set_stmt_attr(
             stmt_handle,
             StatementAttribute::SQL_ATTR_QUERY_TIMEOUT,
@@ -256,18 +283,16 @@ mod unit {
             StatementAttribute::SQL_ATTR_ROW_BIND_TYPE,
             map! {
                 BindType::SQL_BIND_BY_COLUMN as i32 => SqlReturn::SUCCESS,
-                10 => SqlReturn::ERROR // Any number besides 0
+                16 => SqlReturn::SUCCESS // The size of a structure for row-wise binding
             },
             BindType::SQL_BIND_BY_COLUMN as usize,
         );
-        get_set_stmt_attr(
+        get_set_ptr(
             stmt_handle,
             StatementAttribute::SQL_ATTR_ROW_BIND_OFFSET_PTR,
-            map! {
-                null_mut::<ULen>() as i32 => SqlReturn::SUCCESS,
-                10 => SqlReturn::ERROR // Any number besides 0
-            },
-            null_mut::<ULen>() as usize,
+            true,
+            true,
+            size_of::<*mut ULen>(),
         );
         get_set_stmt_attr(
             stmt_handle,
@@ -291,6 +316,13 @@ mod unit {
             true,
             size_of::<*mut ULen>(),
         );
+        get_set_ptr(
+            stmt_handle,
+            StatementAttribute::SQL_ATTR_FETCH_BOOKMARK_PTR,
+            true,
+            true,
+            size_of::<*mut ULen>(),
+        );
         get_set_stmt_attr(
             stmt_handle,
             StatementAttribute::SQL_ATTR_ROW_ARRAY_SIZE,
@@ -310,10 +342,10 @@ mod unit {
         );
     }
 
-    // test_unsupported_attributes tests SQLGetStmtAttr and SQLSetStmtAttr with every
-    // unsupported statement attribute value.
+    // test_cursor_type tests SQL_ATTR_CURSOR_TYPE and SQL_ATTR_CURSOR_SCROLLABLE, which are
+    // kept consistent with each other since scrollable cursors are static cursors.
     #[test]
-    fn test_unsupported_attributes() {
+    fn test_cursor_type() {
         use crate::map;
         let stmt_handle: *mut _ = &mut MongoHandle::Statement(Statement::with_state(
             std::ptr::null_mut(),
@@ -322,33 +354,96 @@ mod unit {
 
         get_set_stmt_attr(
             stmt_handle,
-            StatementAttribute::SQL_ATTR_ASYNC_ENABLE,
+            StatementAttribute::SQL_ATTR_CURSOR_TYPE,
             map! {
-                AsyncEnable::SQL_ASYNC_ENABLE_OFF as i32 => SqlReturn::ERROR,
-                AsyncEnable::SQL_ASYNC_ENABLE_ON as i32 => SqlReturn::ERROR,
+                CursorType::SQL_CURSOR_FORWARD_ONLY as i32 => SqlReturn::SUCCESS,
+                CursorType::SQL_CURSOR_STATIC as i32 => SqlReturn::SUCCESS,
             },
-            AsyncEnable::SQL_ASYNC_ENABLE_OFF as usize,
+            CursorType::SQL_CURSOR_FORWARD_ONLY as usize,
         );
+
+        let stmt_handle: *mut _ = &mut MongoHandle::Statement(Statement::with_state(
+            std::ptr::null_mut(),
+            StatementState::Allocated,
+        ));
         get_set_stmt_attr(
             stmt_handle,
-            StatementAttribute::SQL_ATTR_ENABLE_AUTO_IPD,
+            StatementAttribute::SQL_ATTR_CURSOR_SCROLLABLE,
             map! {
-                SqlBool::SQL_FALSE as i32 => SqlReturn::ERROR,
-                SqlBool::SQL_TRUE as i32 => SqlReturn::ERROR,
+                CursorScrollable::SQL_NONSCROLLABLE as i32 => SqlReturn::SUCCESS,
+                CursorScrollable::SQL_SCROLLABLE as i32 => SqlReturn::SUCCESS,
             },
-            SqlBool::SQL_FALSE as usize,
+            CursorScrollable::SQL_NONSCROLLABLE as usize,
         );
+
+        // Keyset-driven and dynamic cursors are changed to static cursors.
+        for cursor_type in [
+            CursorType::SQL_CURSOR_KEYSET_DRIVEN,
+            CursorType::SQL_CURSOR_DYNAMIC,
+        ] {
+            let stmt_handle: *mut _ = &mut MongoHandle::Statement(Statement::with_state(
+                std::ptr::null_mut(),
+                StatementState::Allocated,
+            ));
+            unsafe {
+                assert_eq!(
+                    SqlReturn::SUCCESS_WITH_INFO,
+                    SQLSetStmtAttrW(
+                        stmt_handle as HStmt,
+                        StatementAttribute::SQL_ATTR_CURSOR_TYPE as i32,
+                        cursor_type as usize as Pointer,
+                        0
+                    )
+                );
+                for (attribute, expected) in [
+                    (
+                        StatementAttribute::SQL_ATTR_CURSOR_TYPE,
+                        CursorType::SQL_CURSOR_STATIC as usize,
+                    ),
+                    (
+                        StatementAttribute::SQL_ATTR_CURSOR_SCROLLABLE,
+                        CursorScrollable::SQL_SCROLLABLE as usize,
+                    ),
+                ] {
+                    let attr_buffer = &mut 0_usize;
+                    assert_eq!(
+                        SqlReturn::SUCCESS,
+                        SQLGetStmtAttrW(
+                            stmt_handle as *mut _,
+                            attribute as i32,
+                            attr_buffer as *mut usize as Pointer,
+                            0,
+                            &mut 0
+                        )
+                    );
+                    assert_eq!(expected, *attr_buffer);
+                }
+            }
+        }
+    }
+
+    // test_unsupported_attributes tests SQLGetStmtAttr and SQLSetStmtAttr with every
+    // unsupported statement attribute value.
+    #[test]
+    fn test_unsupported_attributes() {
+        use crate::map;
+        let stmt_handle: *mut _ = &mut MongoHandle::Statement(Statement::with_state(
+            std::ptr::null_mut(),
+            StatementState::Allocated,
+        ));
+
         get_set_stmt_attr(
             stmt_handle,
-            StatementAttribute::SQL_ATTR_KEYSET_SIZE,
+            StatementAttribute::SQL_ATTR_ENABLE_AUTO_IPD,
             map! {
-                0 => SqlReturn::ERROR, // Any number
+                SqlBool::SQL_FALSE as i32 => SqlReturn::ERROR,
+                SqlBool::SQL_TRUE as i32 => SqlReturn::ERROR,
             },
-            0,
+            SqlBool::SQL_FALSE as usize,
         );
         get_set_stmt_attr(
             stmt_handle,
-            StatementAttribute::SQL_ATTR_PARAM_BIND_TYPE,
+            StatementAttribute::SQL_ATTR_KEYSET_SIZE,
             map! {
                 0 => SqlReturn::ERROR, // Any number
             },
@@ -369,20 +464,6 @@ mod unit {
             false,
             size_of::<Pointer>(),
         );
-        get_set_ptr(
-            stmt_handle,
-            StatementAttribute::SQL_ATTR_FETCH_BOOKMARK_PTR,
-            true,
-            false,
-            size_of::<Pointer>(),
-        );
-        get_set_ptr(
-            stmt_handle,
-            StatementAttribute::SQL_ATTR_PARAM_BIND_OFFSET_PTR,
-            true,
-            false,
-            size_of::<*mut ULen>(),
-        );
         get_set_ptr(
             stmt_handle,
             StatementAttribute::SQL_ATTR_PARAM_OPERATION_PTR,
@@ -390,20 +471,6 @@ mod unit {
             false,
             size_of::<*mut USmallInt>(),
         );
-        get_set_ptr(
-            stmt_handle,
-            StatementAttribute::SQL_ATTR_PARAM_STATUS_PTR,
-            true,
-            false,
-            size_of::<*mut USmallInt>(),
-        );
-        get_set_ptr(
-            stmt_handle,
-            StatementAttribute::SQL_ATTR_PARAMS_PROCESSED_PTR,
-            true,
-            false,
-            size_of::<*mut ULen>(),
-        );
         get_set_ptr(
             stmt_handle,
             StatementAttribute::SQL_ATTR_ROW_OPERATION_PTR,
@@ -1,20 +1,31 @@
 use crate::api::errors::ODBCError;
 
+use constants::GENERATED_CURSOR_NAME_PREFIX;
 use cstr::{Charset, WideChar};
 use definitions::{
     AsyncEnable, AttrConnectionPooling, AttrCpMatch, AttrOdbcVersion, BindType, Concurrency,
     CursorScrollable, CursorSensitivity, CursorType, HDbc, HDesc, HEnv, HStmt, Handle, Len, NoScan,
-    Pointer, RetrieveData, SimulateCursor, SmallInt, SqlBool, ULen, USmallInt, UseBookmarks,
+    Pointer, RetrieveData, SimulateCursor, SmallInt, SqlBool, SqlReturn, ULen, USmallInt,
+    UseBookmarks,
 };
-use mongo_odbc_core::TypeMode;
+use mongo_odbc_core::{Cancellation, MongoQuery, TypeMode};
 use mongodb::bson::{Bson, Uuid};
+use shared_sql_utils::odbcinst::ODBC_BOTH_DSN;
 use std::{
     borrow::BorrowMut,
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
+    ops::Deref,
     ptr::null_mut,
-    sync::RwLock,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+    thread::JoinHandle,
 };
 
+// The number appended to the next generated cursor name, so that generated names are unique.
+static NEXT_CURSOR_ID: AtomicUsize = AtomicUsize::new(1);
+
 #[derive(Debug)]
 #[repr(C)]
 pub enum MongoHandle {
@@ -90,8 +101,12 @@ impl MongoHandle {
             MongoHandle::Connection(c) => {
                 c.errors.write().unwrap().clear();
             }
+            // The diagnostics of an asynchronous operation are kept until the call that completes
+            // it returns, since the application polls it by calling the function again.
             MongoHandle::Statement(s) => {
-                s.errors.write().unwrap().clear();
+                if s.async_operation.read().unwrap().is_none() {
+                    s.errors.write().unwrap().clear();
+                }
             }
             MongoHandle::Descriptor(d) => {
                 d.errors.write().unwrap().clear();
@@ -148,10 +163,15 @@ impl MongoHandle {
             MongoHandle::Connection(conn) => conn.env,
             MongoHandle::Descriptor(Descriptor {
                 connection: conn, ..
-            })
-            | MongoHandle::Statement(Statement {
-                connection: conn, ..
             }) => unsafe { conn.as_ref().unwrap().as_connection().unwrap().env },
+            MongoHandle::Statement(stmt) => unsafe {
+                stmt.connection
+                    .as_ref()
+                    .unwrap()
+                    .as_connection()
+                    .unwrap()
+                    .env
+            },
         };
         unsafe {
             env.as_ref()
@@ -233,6 +253,12 @@ pub struct Env {
     pub state: RwLock<EnvState>,
     pub connections: RwLock<HashSet<*mut MongoHandle>>,
     pub errors: RwLock<Vec<ODBCError>>,
+    // The index of the next DSN SQLDataSources returns with SQL_FETCH_NEXT.
+    pub next_data_source: RwLock<usize>,
+    // The config mode of the DSNs SQLDataSources returns, set by its last SQL_FETCH_FIRST call.
+    pub data_source_mode: RwLock<u32>,
+    // The index of the next driver SQLDrivers returns with SQL_FETCH_NEXT.
+    pub next_driver: RwLock<usize>,
 }
 
 impl Env {
@@ -242,6 +268,9 @@ impl Env {
             state: RwLock::new(state),
             connections: RwLock::new(HashSet::new()),
             errors: RwLock::new(vec![]),
+            next_data_source: RwLock::new(0),
+            data_source_mode: RwLock::new(ODBC_BOTH_DSN),
+            next_driver: RwLock::new(0),
         }
     }
 }
@@ -293,12 +322,22 @@ pub struct Connection {
     // pub client: Option<MongoClient>,
     // all Statements allocated from this Connection
     pub statements: RwLock<HashSet<*mut MongoHandle>>,
+    // all Descriptors explicitly allocated from this Connection
+    pub descriptors: RwLock<HashSet<*mut MongoHandle>>,
     pub errors: RwLock<Vec<ODBCError>>,
     // type_mode indicates if BsonTypeInfo.simple_type_info will be
     // utilized in place of standard BsonTypeInfo fields
     pub type_mode: RwLock<TypeMode>,
     // max_string_length is the maximum character length of string data.
     pub max_string_length: RwLock<Option<u16>>,
+    // static_cursor_spill_threshold is the number of rows a static cursor keeps in memory
+    // before writing the rest of the result set to a temporary file.
+    pub static_cursor_spill_threshold: RwLock<usize>,
+    // accurate_row_count indicates if SQLRowCount reports the number of rows in the result set
+    // instead of 0.
+    pub accurate_row_count: RwLock<bool>,
+    // The attributes supplied by the previous calls of an unfinished SQLBrowseConnect.
+    pub browse_connect_attributes: RwLock<Option<String>>,
 }
 
 #[derive(Debug, Default)]
@@ -334,9 +373,15 @@ impl Connection {
             attributes: RwLock::new(ConnectionAttributes::default()),
             state: RwLock::new(state),
             statements: RwLock::new(HashSet::new()),
+            descriptors: RwLock::new(HashSet::new()),
             errors: RwLock::new(vec![]),
             type_mode: RwLock::new(TypeMode::Simple),
             max_string_length: RwLock::new(None),
+            static_cursor_spill_threshold: RwLock::new(
+                constants::DEFAULT_STATIC_CURSOR_SPILL_THRESHOLD,
+            ),
+            accurate_row_count: RwLock::new(false),
+            browse_connect_attributes: RwLock::new(None),
         }
     }
 }
@@ -360,9 +405,22 @@ pub enum CachedData {
     WChar(usize, Vec<WideChar>),
 }
 
+// Statement is the state of a statement handle. The state is shared with the worker thread of an
+// asynchronous function, which keeps it alive until the worker finishes.
+#[derive(Debug, Clone)]
+pub struct Statement(Arc<StatementData>);
+
+impl Deref for Statement {
+    type Target = StatementData;
+
+    fn deref(&self) -> &StatementData {
+        &self.0
+    }
+}
+
 #[derive(Debug)]
 #[repr(C)]
-pub struct Statement {
+pub struct StatementData {
     pub connection: *mut MongoHandle,
     pub mongo_statement: RwLock<Option<Box<dyn mongo_odbc_core::MongoStatement>>>,
     pub var_data_cache: RwLock<Option<HashMap<USmallInt, CachedData>>>,
@@ -372,6 +430,38 @@ pub struct Statement {
     // pub cursor: RwLock<Option<Box<Peekable<Cursor>>>>,
     pub errors: RwLock<Vec<ODBCError>>,
     pub bound_cols: RwLock<Option<HashMap<USmallInt, BoundColInfo>>>,
+    pub bound_params: RwLock<Option<HashMap<USmallInt, BoundParamInfo>>>,
+    // The text of the last prepared query if it contains parameter markers. The
+    // markers are substituted with the bound parameter values on each execution.
+    pub parameterized_query: RwLock<Option<String>>,
+    // The data-at-execution parameters of an execution that is waiting for
+    // SQLParamData/SQLPutData to supply their values.
+    pub data_at_exec: RwLock<Option<DataAtExecParams>>,
+    // The results that SQLMoreResults executes next, in order.
+    pub more_results: RwLock<VecDeque<PendingResult>>,
+    // The query prepared for the first execution of the parameterized query. Later executions,
+    // and the other parameter sets of a parameter array, are bound to it instead of being
+    // prepared again.
+    pub prepared_query: RwLock<Option<MongoQuery>>,
+    // The function running asynchronously on the statement, if there is one. It stays pending
+    // until the application calls the function again after the worker finishes.
+    pub async_operation: RwLock<Option<AsyncOperation>>,
+    // Set by SQLCancel to interrupt the statement while it executes or fetches.
+    pub cancellation: Cancellation,
+    // The name of the statement's cursor, either generated by the driver or set with
+    // SQLSetCursorName.
+    pub cursor_name: RwLock<String>,
+    // Whether the statement has been executed or fetched from since its cursor was last
+    // closed. The cursor name cannot change while the cursor is open.
+    pub cursor_open: RwLock<bool>,
+}
+
+#[derive(Debug)]
+pub struct AsyncOperation {
+    // The name of the function that started the operation. Only this function can poll it.
+    pub function_name: &'static str,
+    // The driver-owned thread executing the function, which returns its SqlReturn.
+    pub worker: JoinHandle<SqlReturn>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -383,10 +473,46 @@ pub struct BoundColInfo {
     pub length_or_indicator: *mut Len,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+pub struct BoundParamInfo {
+    pub input_output_type: SmallInt,
+    pub value_type: SmallInt,
+    pub parameter_type: SmallInt,
+    pub column_size: ULen,
+    pub decimal_digits: SmallInt,
+    pub parameter_value_ptr: Pointer,
+    pub buffer_length: Len,
+    pub str_len_or_ind_ptr: *mut Len,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[repr(C)]
+pub struct DataAtExecParams {
+    // Parameters still to be requested by SQLParamData, in parameter order.
+    pub pending: VecDeque<USmallInt>,
+    // The parameter SQLPutData currently sends data for.
+    pub current: Option<USmallInt>,
+    // The data sent for each parameter. None means SQL_NULL_DATA was sent.
+    pub values: HashMap<USmallInt, Option<Vec<u8>>>,
+}
+
+#[derive(Debug)]
+pub enum PendingResult {
+    // A parameter set of a parameter array, by its index, with the query its values are
+    // substituted in, or the error reading them.
+    ParamSet(usize, Result<String, ODBCError>),
+    // A statement of a batch of statements.
+    BatchStatement(String),
+}
+
 #[derive(Debug)]
 #[repr(C)]
 pub struct StatementAttributes {
+    // The application row descriptor the statement uses, which is either the implicitly
+    // allocated one or an explicitly allocated descriptor set with SQL_ATTR_APP_ROW_DESC.
     pub app_row_desc: *mut MongoHandle,
+    pub implicit_app_row_desc: *mut MongoHandle,
     pub app_param_desc: *mut MongoHandle,
     pub async_enable: AsyncEnable,
     pub async_stmt_event: Pointer,
@@ -424,7 +550,7 @@ pub struct StatementAttributes {
 impl Drop for StatementAttributes {
     fn drop(&mut self) {
         unsafe {
-            let _ = Box::from_raw(self.app_row_desc);
+            let _ = Box::from_raw(self.implicit_app_row_desc);
             let _ = Box::from_raw(self.app_param_desc);
             let _ = Box::from_raw(self.imp_row_desc);
             let _ = Box::from_raw(self.imp_param_desc);
@@ -442,37 +568,40 @@ pub enum StatementState {
     _ExecutedHasResultSet,
     _CursorFetchSet,
     _CursorExtendedFetchSet,
-    _FunctionNeedsDataNoParam,
-    _FunctionNeedsDataNoPut,
-    _FunctionNeedsDataPutCalled,
+    FunctionNeedsDataNoParam,
+    FunctionNeedsDataNoPut,
+    FunctionNeedsDataPutCalled,
     _Executing,
     SynchronousQueryExecuting,
     _AsyncCancelled,
 }
 
+// The worker thread of an asynchronous function shares the state of its statement. The
+// application can only poll, cancel, or read the diagnostics of the statement while the worker
+// runs, and the handles the state points to, its connection and descriptors, outlive it.
+unsafe impl Send for StatementData {}
+unsafe impl Sync for StatementData {}
+
 impl Statement {
     pub fn with_state(connection: *mut MongoHandle, state: StatementState) -> Self {
-        let implicit_app_row_desc =
-            Descriptor::with_state(connection, DescriptorState::ImplicitlyAllocated);
+        let implicit_app_row_desc: *mut _ = Box::into_raw(Box::new(MongoHandle::Descriptor(
+            Descriptor::implicit(connection, DescriptorType::AppRow),
+        )));
 
-        let implicit_param_row_desc =
-            Descriptor::with_state(connection, DescriptorState::ImplicitlyAllocated);
+        let implicit_param_row_desc = Descriptor::implicit(connection, DescriptorType::AppParam);
 
-        let implicit_app_imp_desc =
-            Descriptor::with_state(connection, DescriptorState::ImplicitlyAllocated);
+        let implicit_app_imp_desc = Descriptor::implicit(connection, DescriptorType::ImpRow);
 
-        let implicit_param_imp_desc =
-            Descriptor::with_state(connection, DescriptorState::ImplicitlyAllocated);
+        let implicit_param_imp_desc = Descriptor::implicit(connection, DescriptorType::ImpParam);
 
-        Self {
+        Self(Arc::new(StatementData {
             connection,
             state: RwLock::new(state),
             statement_id: RwLock::new(Uuid::new().into()),
             var_data_cache: RwLock::new(None),
             attributes: RwLock::new(StatementAttributes {
-                app_row_desc: Box::into_raw(Box::new(MongoHandle::Descriptor(
-                    implicit_app_row_desc,
-                ))),
+                app_row_desc: implicit_app_row_desc,
+                implicit_app_row_desc,
                 app_param_desc: Box::into_raw(Box::new(MongoHandle::Descriptor(
                     implicit_param_row_desc,
                 ))),
@@ -498,7 +627,7 @@ impl Statement {
                 param_operation_ptr: null_mut(),
                 param_processed_ptr: null_mut(),
                 param_status_ptr: null_mut(),
-                paramset_size: 0,
+                paramset_size: 1,
                 query_timeout: 0,
                 retrieve_data: RetrieveData::Off,
                 row_array_size: 1,
@@ -515,7 +644,19 @@ impl Statement {
             errors: RwLock::new(vec![]),
             mongo_statement: RwLock::new(None),
             bound_cols: RwLock::new(None),
-        }
+            bound_params: RwLock::new(None),
+            parameterized_query: RwLock::new(None),
+            data_at_exec: RwLock::new(None),
+            more_results: RwLock::new(VecDeque::new()),
+            prepared_query: RwLock::new(None),
+            async_operation: RwLock::new(None),
+            cancellation: Cancellation::default(),
+            cursor_name: RwLock::new(format!(
+                "{GENERATED_CURSOR_NAME_PREFIX}{}",
+                NEXT_CURSOR_ID.fetch_add(1, Ordering::Relaxed)
+            )),
+            cursor_open: RwLock::new(false),
+        }))
     }
 
     pub(crate) fn get_max_string_length(&self) -> Option<u16> {
@@ -532,6 +673,83 @@ impl Statement {
         }
     }
 
+    ///
+    /// set_descriptor_statement records `handle`, the handle of this statement, on its implicitly
+    /// allocated descriptors, whose fields are read from and written to the statement.
+    ///
+    pub(crate) fn set_descriptor_statement(&self, handle: *mut MongoHandle) {
+        let attributes = self.attributes.read().unwrap();
+        for desc in [
+            attributes.implicit_app_row_desc,
+            attributes.app_param_desc,
+            attributes.imp_row_desc,
+            attributes.imp_param_desc,
+        ] {
+            if let Some(desc) = unsafe { (*desc).as_descriptor() } {
+                desc.attributes.write().unwrap().statement = handle;
+            }
+        }
+    }
+
+    // explicit_app_row_desc returns the explicitly allocated descriptor set as the application row
+    // descriptor of the statement, if there is one.
+    fn explicit_app_row_desc(&self) -> Option<&Descriptor> {
+        let attributes = self.attributes.read().unwrap();
+        if attributes.app_row_desc == attributes.implicit_app_row_desc {
+            return None;
+        }
+        unsafe { (*attributes.app_row_desc).as_descriptor() }
+    }
+
+    ///
+    /// app_row_desc returns the fields of the application row descriptor the statement uses to
+    /// return column data.
+    ///
+    pub(crate) fn app_row_desc(&self) -> AppRowDesc {
+        match self.explicit_app_row_desc() {
+            Some(desc) => desc.attributes.read().unwrap().app_row_desc.clone(),
+            None => self.implicit_app_row_desc(),
+        }
+    }
+
+    ///
+    /// update_app_row_desc changes the fields of the application row descriptor the statement
+    /// uses to return column data.
+    ///
+    pub(crate) fn update_app_row_desc<T>(&self, f: impl FnOnce(&mut AppRowDesc) -> T) -> T {
+        match self.explicit_app_row_desc() {
+            Some(desc) => f(&mut desc.attributes.write().unwrap().app_row_desc),
+            None => {
+                let mut fields = self.implicit_app_row_desc();
+                let ret = f(&mut fields);
+                self.set_implicit_app_row_desc(fields);
+                ret
+            }
+        }
+    }
+
+    ///
+    /// implicit_app_row_desc returns the fields of the implicitly allocated application row
+    /// descriptor, which are the bound columns and row attributes of the statement.
+    ///
+    pub(crate) fn implicit_app_row_desc(&self) -> AppRowDesc {
+        let attributes = self.attributes.read().unwrap();
+        AppRowDesc {
+            bound_cols: self.bound_cols.read().unwrap().clone(),
+            array_size: attributes.row_array_size,
+            bind_offset_ptr: attributes.row_bind_offset_ptr,
+            bind_type: attributes.row_bind_type,
+        }
+    }
+
+    pub(crate) fn set_implicit_app_row_desc(&self, fields: AppRowDesc) {
+        let mut attributes = self.attributes.write().unwrap();
+        attributes.row_array_size = fields.array_size;
+        attributes.row_bind_offset_ptr = fields.bind_offset_ptr;
+        attributes.row_bind_type = fields.bind_type;
+        *self.bound_cols.write().unwrap() = fields.bound_cols;
+    }
+
     pub(crate) fn insert_var_data_cache(&self, col: u16, data: CachedData) {
         self.var_data_cache
             .write()
@@ -560,17 +778,68 @@ pub enum DescriptorState {
     ExplicitlyAllocated, // D1e
 }
 
-#[derive(Debug, Default)]
+/// See https://learn.microsoft.com/en-us/sql/odbc/reference/develop-app/types-of-descriptors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub enum DescriptorType {
+    AppRow,
+    AppParam,
+    ImpRow,
+    ImpParam,
+}
+
+/// The header fields and records of an application row descriptor. The records are the columns
+/// bound with SQLBindCol or SQLSetDescField.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct AppRowDesc {
+    pub bound_cols: Option<HashMap<USmallInt, BoundColInfo>>,
+    pub array_size: ULen,
+    pub bind_offset_ptr: *mut ULen,
+    pub bind_type: ULen,
+}
+
+impl Default for AppRowDesc {
+    fn default() -> Self {
+        Self {
+            bound_cols: None,
+            array_size: 1,
+            bind_offset_ptr: null_mut(),
+            bind_type: BindType::SQL_BIND_BY_COLUMN as usize,
+        }
+    }
+}
+
+#[derive(Debug)]
 #[repr(C)]
-pub struct DescriptorAttributes {}
+pub struct DescriptorAttributes {
+    pub desc_type: DescriptorType,
+    // The statement an implicitly allocated descriptor belongs to. The fields of implicit
+    // descriptors are the bound columns, bound parameters and result set metadata of the
+    // statement, so they are read from and written to the statement.
+    pub statement: *mut MongoHandle,
+    // The fields of an explicitly allocated descriptor, which can only be used as an
+    // application row descriptor.
+    pub app_row_desc: AppRowDesc,
+}
 
 impl Descriptor {
     pub fn with_state(connection: *mut MongoHandle, state: DescriptorState) -> Self {
         Self {
             connection,
-            attributes: RwLock::new(DescriptorAttributes::default()),
+            attributes: RwLock::new(DescriptorAttributes {
+                desc_type: DescriptorType::AppRow,
+                statement: null_mut(),
+                app_row_desc: AppRowDesc::default(),
+            }),
             state: RwLock::new(state),
             errors: RwLock::new(vec![]),
         }
     }
+
+    pub fn implicit(connection: *mut MongoHandle, desc_type: DescriptorType) -> Self {
+        let desc = Descriptor::with_state(connection, DescriptorState::ImplicitlyAllocated);
+        desc.attributes.write().unwrap().desc_type = desc_type;
+        desc
+    }
 }
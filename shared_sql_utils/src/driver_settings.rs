@@ -1,5 +1,7 @@
 use crate::driver_settings::SettingError::NotFound;
-use crate::odbcinst::{SQLGetPrivateProfileString, SQLGetPrivateProfileStringW};
+use crate::odbcinst::{
+    get_private_profile_strings, SQLGetPrivateProfileString, SQLGetPrivateProfileStringW,
+};
 use constants::DRIVER_NAME;
 use cstr::{parse_attribute_string_a, parse_attribute_string_w, to_char_ptr, to_widechar_ptr};
 use thiserror::Error;
@@ -8,6 +10,8 @@ use thiserror::Error;
 // Considering the keys and potential values for them, 1024 seems realistically enough.
 pub(crate) const MAX_VALUE_LENGTH: usize = 1024;
 pub const ODBCINSTINI: &str = "ODBCINST.INI";
+// The odbcinst.ini sections that do not describe a driver.
+const NON_DRIVER_SECTIONS: &[&str] = &["ODBC Drivers", "ODBC"];
 
 #[derive(Error, Debug, Clone)]
 pub enum SettingError {
@@ -144,6 +148,42 @@ impl DriverSettings {
         Ok(driver_settings)
     }
 
+    // list_drivers returns the description of every driver in odbcinst.ini, in the order of the
+    // file, with the key=value attributes of its section.
+    pub fn list_drivers() -> Vec<(String, Vec<String>)> {
+        unsafe {
+            get_private_profile_strings(None, None, ODBCINSTINI, MAX_VALUE_LENGTH)
+                .into_iter()
+                .filter(|section| {
+                    !NON_DRIVER_SECTIONS
+                        .iter()
+                        .any(|non_driver| section.eq_ignore_ascii_case(non_driver))
+                })
+                .map(|driver| {
+                    let attributes = get_private_profile_strings(
+                        Some(&driver),
+                        None,
+                        ODBCINSTINI,
+                        MAX_VALUE_LENGTH,
+                    )
+                    .into_iter()
+                    .map(|key| {
+                        let value = get_private_profile_strings(
+                            Some(&driver),
+                            Some(&key),
+                            ODBCINSTINI,
+                            MAX_VALUE_LENGTH,
+                        )
+                        .join("");
+                        format!("{key}={value}")
+                    })
+                    .collect();
+                    (driver, attributes)
+                })
+                .collect()
+        }
+    }
+
     // Helper function used to populate the DriverSettings struct fields.
     fn set_field(&mut self, key: &str, value: &str) {
         match key.to_lowercase().as_str() {
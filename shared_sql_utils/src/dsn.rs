@@ -19,6 +19,10 @@ const ENABLE_MAX_STRING_LENGTH: &str = "enable_max_string_length";
 // const LOGPATH: &str = "LOGPATH";
 
 const ODBCINI: &str = "ODBC.INI";
+// The odbc.ini section mapping each DSN to the name of its driver.
+const ODBC_DATA_SOURCES: &str = "ODBC Data Sources";
+// The odbc.ini section holding the driver manager settings rather than a DSN.
+const ODBC: &str = "ODBC";
 // The maximum length of a registry value is 16383 characters.
 const MAX_VALUE_LENGTH: usize = 16383;
 
@@ -238,13 +242,50 @@ impl Dsn {
         value.len() < MAX_VALUE_LENGTH
     }
 
+    // list_data_sources returns the name and driver description of every DSN in the odbc.ini files
+    // of `config_mode`, in the order of the files. The driver description comes from the ODBC Data
+    // Sources section, or the Driver key of the DSN when it is not listed there.
+    pub fn list_data_sources(config_mode: u32) -> Vec<(String, String)> {
+        unsafe {
+            // The install API reads the files of the current config mode, which is restored
+            // afterwards.
+            let mut previous_mode = ODBC_BOTH_DSN;
+            SQLGetConfigMode(&mut previous_mode);
+            SQLSetConfigMode(config_mode);
+            let data_sources = get_private_profile_strings(None, None, ODBCINI, MAX_VALUE_LENGTH)
+                .into_iter()
+                .filter(|section| {
+                    !section.eq_ignore_ascii_case(ODBC_DATA_SOURCES)
+                        && !section.eq_ignore_ascii_case(ODBC)
+                })
+                .map(|dsn| {
+                    let description = [(ODBC_DATA_SOURCES, dsn.as_str()), (dsn.as_str(), "Driver")]
+                        .into_iter()
+                        .find_map(|(section, entry)| {
+                            get_private_profile_strings(
+                                Some(section),
+                                Some(entry),
+                                ODBCINI,
+                                MAX_VALUE_LENGTH,
+                            )
+                            .into_iter()
+                            .next()
+                        })
+                        .unwrap_or_default();
+                    (dsn, description)
+                })
+                .collect();
+            SQLSetConfigMode(previous_mode);
+            data_sources
+        }
+    }
+
     pub fn to_connection_string(&self) -> String {
-        self.iter().fold(String::new(), |acc, (key, value)| {
-            if value.is_empty() {
-                return String::new();
-            }
-            format!("{acc};{key}={value};")
-        })
+        self.iter()
+            .filter(|(_, value)| !value.is_empty())
+            .fold(String::new(), |acc, (key, value)| {
+                format!("{acc};{key}={value};")
+            })
     }
 }
 
@@ -285,6 +326,19 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn connection_string_skips_empty_values() {
+        let dsn_opts = Dsn {
+            database: "mydb".to_string(),
+            uri: "mongodb://localhost".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            ";Uri=mongodb://localhost;;Database=mydb;",
+            dsn_opts.to_connection_string()
+        );
+    }
+
     #[test]
     fn invalid_dsn_name() {
         let dsn_opts = Dsn::new(DsnArgs {
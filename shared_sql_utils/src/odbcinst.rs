@@ -1,4 +1,10 @@
-use cstr::{Char, WideChar};
+use cstr::{from_widechar_vec_lossy, to_char_ptr, to_widechar_ptr, Char, WideChar};
+use std::ptr::null;
+
+// The configuration modes of the install API, which select the odbc.ini files DSNs are read from.
+pub const ODBC_BOTH_DSN: u32 = 0;
+pub const ODBC_USER_DSN: u32 = 1;
+pub const ODBC_SYSTEM_DSN: u32 = 2;
 
 // The install API functions needed to read and write driver and DSN settings.
 // See https://learn.microsoft.com/en-us/sql/odbc/reference/syntax/installer-dll-api-reference-function for more details
@@ -38,4 +44,81 @@ extern "C" {
         filename: *const Char,
     ) -> i32;
     pub fn SQLGetConfigMode(buffer: *mut u32) -> i32;
+    pub fn SQLSetConfigMode(mode: u32) -> i32;
+}
+
+///
+/// get_private_profile_strings reads `entry` of `section` in `filename`. With a NULL entry it reads
+/// the keys of the section, and with a NULL section the sections of the file. The values of these
+/// lists are returned separately. The buffer starts at `buffer_length` and is doubled until the
+/// value fits, since a full buffer means the value was truncated.
+///
+/// # Safety
+/// This calls the install API, which requires unsafe operations
+///
+pub unsafe fn get_private_profile_strings(
+    section: Option<&str>,
+    entry: Option<&str>,
+    filename: &str,
+    mut buffer_length: usize,
+) -> Vec<String> {
+    let values = loop {
+        let (values, len) = get_private_profile_string(section, entry, filename, buffer_length);
+        // Lists of keys or sections end with two nul characters, so a truncated value fills all
+        // but one or two characters of the buffer depending on the implementation.
+        if len + 2 < buffer_length || buffer_length >= i32::MAX as usize {
+            break values;
+        }
+        buffer_length = buffer_length.saturating_mul(2).min(i32::MAX as usize);
+    };
+    values
+        .split('\0')
+        .filter(|value| !value.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+///
+/// get_private_profile_string reads `entry` of `section` in `filename` into a buffer of
+/// `buffer_length` characters, and returns the value with the number of characters read.
+///
+unsafe fn get_private_profile_string(
+    section: Option<&str>,
+    entry: Option<&str>,
+    filename: &str,
+    buffer_length: usize,
+) -> (String, usize) {
+    // SQLGetPrivateProfileStringW is hopelessly broken in unixodbc. As a workaround,
+    // we must use SQLGetPrivateProfileString until if/when unixodbc is fixed.
+    if cfg!(not(target_os = "linux")) {
+        let section = section.map(to_widechar_ptr);
+        let entry = entry.map(to_widechar_ptr);
+        let mut buffer = vec![0; buffer_length];
+        let len = SQLGetPrivateProfileStringW(
+            section.as_ref().map_or(null(), |section| section.0),
+            entry.as_ref().map_or(null(), |entry| entry.0),
+            to_widechar_ptr("").0,
+            buffer.as_mut_ptr(),
+            i32::try_from(buffer_length).unwrap_or(i32::MAX),
+            to_widechar_ptr(filename).0,
+        );
+        let len = usize::try_from(len).unwrap_or(0).min(buffer_length);
+        buffer.truncate(len);
+        (from_widechar_vec_lossy(buffer), len)
+    } else {
+        let section = section.map(to_char_ptr);
+        let entry = entry.map(to_char_ptr);
+        let mut buffer = vec![0; buffer_length];
+        let len = SQLGetPrivateProfileString(
+            section.as_ref().map_or(null(), |section| section.0),
+            entry.as_ref().map_or(null(), |entry| entry.0),
+            to_char_ptr("").0,
+            buffer.as_mut_ptr(),
+            i32::try_from(buffer_length).unwrap_or(i32::MAX),
+            to_char_ptr(filename).0,
+        );
+        let len = usize::try_from(len).unwrap_or(0).min(buffer_length);
+        buffer.truncate(len);
+        (String::from_utf8_lossy(&buffer).into_owned(), len)
+    }
 }